@@ -1,4 +1,4 @@
-use crate::error::ParseError;
+use crate::error::{unwrap_or_saturate, ArithmeticError, ParseError};
 use crate::util::{pow10, StackBuf};
 use super::AncDec;
 use core::convert::TryFrom;
@@ -21,14 +21,25 @@ impl AncDec {
         }
     }
 
-    /// Converts to `i64`, truncating the fractional part. Panics on overflow.
+    /// Converts to `i64`, truncating the fractional part. Panics on overflow
+    /// (clamps to `i64::MIN`/`i64::MAX` with `panic-free`).
     pub fn to_i64(&self) -> i64 {
+        unwrap_or_saturate(self.try_to_i64(), || if self.neg { i64::MIN } else { i64::MAX })
+    }
+
+    /// Fallible conversion to `i64`, truncating the fractional part.
+    /// Returns `ArithmeticError::Overflow` if the integer part is out of range.
+    pub fn try_to_i64(&self) -> Result<i64, ArithmeticError> {
         if self.neg {
-            assert!(self.int <= i64::MAX as u64 + 1, "integer overflow in to_i64");
-            -(self.int as i64)
+            if self.int > i64::MAX as u64 + 1 {
+                return Err(ArithmeticError::Overflow);
+            }
+            Ok((self.int as i64).wrapping_neg())
         } else {
-            assert!(self.int <= i64::MAX as u64, "integer overflow in to_i64");
-            self.int as i64
+            if self.int > i64::MAX as u64 {
+                return Err(ArithmeticError::Overflow);
+            }
+            Ok(self.int as i64)
         }
     }
 
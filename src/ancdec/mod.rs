@@ -3,6 +3,7 @@ mod basic;
 mod cmp;
 mod convert;
 mod fmt_impl;
+mod num;
 mod ops;
 mod rounding;
 
@@ -12,7 +13,8 @@ mod serde_impl;
 #[cfg(feature = "sqlx")]
 mod sqlx_impl;
 
-use crate::error::ParseError;
+use crate::context::{Signal, Signals};
+use crate::error::{unwrap_or_saturate, ArithmeticError, ParseError};
 use crate::util::{pow10, pow10_128, StackBuf, TARGET_SCALE};
 use core::fmt::{Display, Write};
 
@@ -35,6 +37,10 @@ pub struct AncDec {
     /// Fractional part (0 to `10^scale - 1`).
     pub frac: u64,
     /// Number of fractional digits (0-19).
+    ///
+    /// Not validated: the first power-of-ten lookup on a hand-built value with `scale > 19`
+    /// panics with "scale overflow". With `panic-free` that lookup saturates at `10^19`
+    /// instead, so nothing panics, but results computed from such a value are unspecified.
     pub scale: u8,
     /// Sign flag: `true` if negative.
     pub neg: bool,
@@ -77,6 +83,69 @@ impl AncDec {
         scale: 19,
         neg: false,
     };
+    /// The minimum representable value (`-MAX`).
+    pub const MIN: AncDec = AncDec {
+        int: u64::MAX,
+        frac: 9_999_999_999_999_999_999,
+        scale: 19,
+        neg: true,
+    };
+    /// The smallest positive value (`1e-19`).
+    pub const MIN_POSITIVE: AncDec = AncDec {
+        int: 0,
+        frac: 1,
+        scale: 19,
+        neg: false,
+    };
+    /// The difference between `1` and the next larger representable value. Values are
+    /// evenly spaced at the maximum scale, so this equals [`MIN_POSITIVE`](Self::MIN_POSITIVE).
+    pub const EPSILON: AncDec = Self::MIN_POSITIVE;
+
+    // Mathematical constants mirroring `core::f64::consts`, correctly rounded
+    // (half-even) to the maximum scale of 19 places.
+
+    /// Archimedes' constant (π): `3.1415926535897932385`.
+    pub const PI: AncDec = AncDec {
+        int: 3,
+        frac: 1_415_926_535_897_932_385,
+        scale: 19,
+        neg: false,
+    };
+    /// Euler's number (e): `2.7182818284590452354`.
+    pub const E: AncDec = AncDec {
+        int: 2,
+        frac: 7_182_818_284_590_452_354,
+        scale: 19,
+        neg: false,
+    };
+    /// ln(2): `0.6931471805599453094`.
+    pub const LN_2: AncDec = AncDec {
+        int: 0,
+        frac: 6_931_471_805_599_453_094,
+        scale: 19,
+        neg: false,
+    };
+    /// ln(10): `2.3025850929940456840`.
+    pub const LN_10: AncDec = AncDec {
+        int: 2,
+        frac: 3_025_850_929_940_456_840,
+        scale: 19,
+        neg: false,
+    };
+    /// sqrt(2): `1.4142135623730950488`.
+    pub const SQRT_2: AncDec = AncDec {
+        int: 1,
+        frac: 4_142_135_623_730_950_488,
+        scale: 19,
+        neg: false,
+    };
+    /// 1/sqrt(2): `0.7071067811865475244`.
+    pub const FRAC_1_SQRT_2: AncDec = AncDec {
+        int: 0,
+        frac: 7_071_067_811_865_475_244,
+        scale: 19,
+        neg: false,
+    };
 }
 
 // ============ Core Methods ============
@@ -88,8 +157,25 @@ impl AncDec {
         Self::parse_str(buf.as_str())
     }
 
+    /// Parses like `from_str`, but fails with `ParseError::Inexact` instead of dropping
+    /// nonzero fractional digits beyond scale 19. Dropped trailing zeros are accepted.
+    #[inline(always)]
+    pub fn parse_exact(s: &str) -> Result<Self, ParseError> {
+        match Self::parse_str_signals(s)? {
+            (_, signals) if signals.contains(Signal::Inexact) => Err(ParseError::Inexact),
+            (v, _) => Ok(v),
+        }
+    }
+
     /// Byte-level string parsing with validation
+    #[inline(always)]
     pub(crate) fn parse_str(s: &str) -> Result<Self, ParseError> {
+        Self::parse_str_signals(s).map(|(v, _)| v)
+    }
+
+    /// `parse_str`, also reporting dropped fractional digits as `Clamped` (plus `Inexact`
+    /// if any of them was nonzero)
+    pub(crate) fn parse_str_signals(s: &str) -> Result<(Self, Signals), ParseError> {
         let b = s.as_bytes();
         let len = b.len();
         if len == 0 {
@@ -127,6 +213,7 @@ impl AncDec {
         // parse fractional part (truncate at 19 digits)
         let mut frac: u64 = 0;
         let mut frac_digits: u8 = 0;
+        let mut signals = Signals::EMPTY;
         while i < len {
             let d = unsafe { *b.get_unchecked(i) }.wrapping_sub(b'0');
             if d > 9 {
@@ -135,6 +222,11 @@ impl AncDec {
             if frac_digits < TARGET_SCALE {
                 frac = frac * 10 + d as u64;
                 frac_digits += 1;
+            } else {
+                signals.insert(Signal::Clamped);
+                if d != 0 {
+                    signals.insert(Signal::Inexact);
+                }
             }
             i += 1;
         }
@@ -147,12 +239,15 @@ impl AncDec {
             return Err(ParseError::TrailingChars);
         }
 
-        Ok(Self {
-            int,
-            frac,
-            scale: frac_digits,
-            neg,
-        })
+        Ok((
+            Self {
+                int,
+                frac,
+                scale: frac_digits,
+                neg,
+            },
+            signals,
+        ))
     }
 
     /// Align fractional parts to same scale, returns (self_frac, other_frac, scale, limit)
@@ -180,7 +275,7 @@ impl AncDec {
         }
     }
 
-    /// Add aligned values (same sign), handles frac overflow
+    /// Add aligned values (same sign), handles frac overflow. Returns None on integer overflow
     #[inline(always)]
     pub(crate) fn add_aligned(
         a_int: u64,
@@ -189,15 +284,13 @@ impl AncDec {
         b_frac: u64,
         scale: u8,
         limit: u64,
-    ) -> (u64, u64, u8) {
+    ) -> Option<(u64, u64, u8)> {
         // Use u128 for frac sum since u64 max frac (10^19-1) * 2 can overflow u64
         let frac_wide = a_frac as u128 + b_frac as u128;
         let overflow = (frac_wide >= limit as u128) as u64;
         let frac = (frac_wide - overflow as u128 * limit as u128) as u64;
-        let int = a_int.checked_add(b_int)
-            .and_then(|v| v.checked_add(overflow))
-            .expect("integer overflow in addition");
-        (int, frac, scale)
+        let int = a_int.checked_add(b_int)?.checked_add(overflow)?;
+        Some((int, frac, scale))
     }
 
     /// Subtract with magnitude comparison, returns result with correct sign
@@ -232,26 +325,39 @@ impl AncDec {
         }
     }
 
-    /// Split combined u128 back to int/frac
+    /// Split combined u128 back to int/frac. Returns None if the integer part overflows
     #[inline(always)]
-    pub(crate) fn from_combined(n: u128, scale: u8, neg: bool) -> Self {
+    pub(crate) fn checked_from_combined(n: u128, scale: u8, neg: bool) -> Option<Self> {
         if scale == 0 {
-            assert!(n <= u64::MAX as u128, "integer overflow in from_combined");
-            return Self {
+            if n > u64::MAX as u128 {
+                return None;
+            }
+            return Some(Self {
                 int: n as u64,
                 frac: 0,
                 scale: 0,
                 neg,
-            };
+            });
         }
         let divisor = pow10_128(scale);
         let int_part = n / divisor;
-        assert!(int_part <= u64::MAX as u128, "integer overflow in from_combined");
-        Self {
+        if int_part > u64::MAX as u128 {
+            return None;
+        }
+        Some(Self {
             int: int_part as u64,
             frac: (n % divisor) as u64,
             scale,
             neg,
-        }
+        })
+    }
+
+    /// Like `checked_from_combined`, panics on overflow (saturates with `panic-free`)
+    #[inline(always)]
+    pub(crate) fn from_combined(n: u128, scale: u8, neg: bool) -> Self {
+        unwrap_or_saturate(
+            Self::checked_from_combined(n, scale, neg).ok_or(ArithmeticError::Overflow),
+            || Self::saturated(neg),
+        )
     }
 }
@@ -0,0 +1,111 @@
+use crate::error::{unwrap_or_saturate, ArithmeticError};
+use crate::wide::{approximate_fraction, exact_fraction, gcd_u128};
+use crate::util::{pow10, signed_i128};
+use super::AncDec;
+
+// ============ GCD / LCM ============
+impl AncDec {
+    /// Both magnitudes as combined integers at their common scale (see `align_frac`)
+    #[inline(always)]
+    fn aligned_combined(&self, other: &Self) -> (u128, u128, u8) {
+        let (a_frac, b_frac, scale, limit) = self.align_frac(other);
+        let limit = limit as u128;
+        ((self.int as u128) * limit + a_frac as u128, (other.int as u128) * limit + b_frac as u128, scale)
+    }
+
+    /// Greatest common divisor of the magnitudes, computed on the combined integers at the
+    /// common scale: the largest decimal of that scale dividing both (`gcd(0.25, 0.10)` is
+    /// `0.05`). Never negative; `gcd(x, 0) = |x|`.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (a, b, scale) = self.aligned_combined(other);
+        // the gcd is at most max(a, b), so it always fits
+        Self::checked_from_combined(gcd_u128(a, b), scale, false).unwrap_or(Self::ZERO)
+    }
+
+    /// Least common multiple of the magnitudes at the common scale (`lcm(0.25, 0.10)` is
+    /// `0.50`). Never negative; zero if either operand is zero.
+    ///
+    /// Panics if the result overflows (returns `MAX` with `panic-free`).
+    #[inline(always)]
+    pub fn lcm(&self, other: &Self) -> Self {
+        unwrap_or_saturate(self.try_lcm(other), || Self::MAX)
+    }
+
+    /// Fallible least common multiple. Returns `ArithmeticError::Overflow` if the result overflows.
+    #[inline(always)]
+    pub fn try_lcm(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        self.checked_lcm(other).ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Checked least common multiple. Returns `None` on overflow.
+    pub fn checked_lcm(&self, other: &Self) -> Option<Self> {
+        let (a, b, scale) = self.aligned_combined(other);
+        if a == 0 || b == 0 {
+            return Self::checked_from_combined(0, scale, false);
+        }
+        let g = gcd_u128(a, b);
+        Self::checked_from_combined(a.checked_div(g)?.checked_mul(b)?, scale, false)
+    }
+}
+
+// ============ Fractions ============
+impl AncDec {
+    /// The exact value as `(numerator, denominator)` in lowest terms, with the sign on the
+    /// numerator. The denominator divides `10^scale`; zero is `(0, 1)`.
+    ///
+    /// Panics if the numerator overflows `i128` (returns `(i128::MIN, 1)` or `(i128::MAX, 1)`
+    /// with `panic-free`).
+    ///
+    /// ```
+    /// use ancdec::AncDec;
+    ///
+    /// let x: AncDec = "-1.25".parse().unwrap();
+    /// assert_eq!(x.to_fraction(), (-5, 4));
+    /// ```
+    #[inline(always)]
+    pub fn to_fraction(&self) -> (i128, u128) {
+        unwrap_or_saturate(self.try_to_fraction(), || self.saturated_fraction())
+    }
+
+    /// Fallible exact fraction. Returns `ArithmeticError::Overflow` if the numerator overflows
+    /// `i128`.
+    pub fn try_to_fraction(&self) -> Result<(i128, u128), ArithmeticError> {
+        exact_fraction(self.int as u128, self.frac as u128, pow10(self.scale) as u128)
+            .and_then(|(n, d)| Some((signed_i128(n, self.neg)?, d)))
+            .ok_or(ArithmeticError::Overflow)
+    }
+
+    /// The closest fraction with a denominator of at most `max_denominator` (0 is treated as
+    /// 1), found from the continued fraction expansion, e.g. bond prices in 32nds or gear
+    /// ratios. The exact fraction is returned when it already fits. Matches Python's
+    /// `Fraction.limit_denominator`, ties included: a negative value is expanded from its
+    /// floor, so `-0.5` with a bound of 1 gives `-1`.
+    ///
+    /// Panics if the numerator overflows `i128` (returns `(i128::MIN, 1)` or `(i128::MAX, 1)`
+    /// with `panic-free`).
+    ///
+    /// ```
+    /// use ancdec::AncDec;
+    ///
+    /// let x: AncDec = "3.14159".parse().unwrap();
+    /// assert_eq!(x.approximate_fraction(1000), (355, 113));
+    /// ```
+    #[inline(always)]
+    pub fn approximate_fraction(&self, max_denominator: u128) -> (i128, u128) {
+        unwrap_or_saturate(self.try_approximate_fraction(max_denominator), || self.saturated_fraction())
+    }
+
+    /// Fallible best rational approximation. Returns `ArithmeticError::Overflow` if the
+    /// numerator overflows `i128`.
+    pub fn try_approximate_fraction(&self, max_denominator: u128) -> Result<(i128, u128), ArithmeticError> {
+        approximate_fraction(self.int as u128, self.frac as u128, pow10(self.scale) as u128, self.neg, max_denominator)
+            .and_then(|(n, d)| Some((signed_i128(n, self.neg)?, d)))
+            .ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Overflow fallback for the fraction conversions: the integer bound on the value's side
+    #[inline(always)]
+    fn saturated_fraction(&self) -> (i128, u128) {
+        (if self.neg { i128::MIN } else { i128::MAX }, 1)
+    }
+}
@@ -1,4 +1,4 @@
-use crate::util::pow10_128;
+use crate::error::{unwrap_or_saturate, ArithmeticError};
 use super::AncDec;
 use core::hash::{Hash, Hasher};
 use core::iter::{Product, Sum};
@@ -239,22 +239,14 @@ impl Default for AncDec {
 
 // ============ Hash ============
 /// Hash trait: enables use in HashMap/HashSet
-/// Normalizes trailing zeros so 1.0 == 1.00 have same hash
+/// Hashes the normalized form, so equal values (1.0 and 1.00, 0 and -0) hash the same
 impl Hash for AncDec {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        let mut combined = (self.int as u128) * pow10_128(self.scale) + (self.frac as u128);
-        // Binary search: strip trailing zeros in O(log scale) instead of O(scale)
-        if combined > 0 {
-            if combined % 10_000_000_000_000_000 == 0 { combined /= 10_000_000_000_000_000; }
-            if combined % 100_000_000 == 0 { combined /= 100_000_000; }
-            if combined % 10_000 == 0 { combined /= 10_000; }
-            if combined % 100 == 0 { combined /= 100; }
-            if combined % 10 == 0 { combined /= 10; }
-        }
-        combined.hash(state);
-        if combined != 0 {
-            self.neg.hash(state);
-        } // 0 == -0
+        let n = self.normalize();
+        n.int.hash(state);
+        n.frac.hash(state);
+        n.scale.hash(state);
+        n.neg.hash(state);
     }
 }
 
@@ -293,20 +285,23 @@ macro_rules! impl_from_unsigned { ($($t:ty),*) => {$( impl From<$t> for AncDec {
 impl_from_signed!(i8, i16, i32, i64, isize);
 impl_from_unsigned!(u8, u16, u32, u64, usize);
 
+/// From<u128>: panics above `u64::MAX` (saturates with `panic-free`)
 impl From<u128> for AncDec {
     #[inline(always)]
     fn from(n: u128) -> Self {
-        assert!(n <= u64::MAX as u128, "u128 value exceeds AncDec range");
-        Self { int: n as u64, frac: 0, scale: 0, neg: false }
+        let int = u64::try_from(n).map_err(|_| ArithmeticError::Overflow);
+        let int = unwrap_or_saturate(int, || u64::MAX);
+        Self { int, frac: 0, scale: 0, neg: false }
     }
 }
 
+/// From<i128>: panics if the magnitude exceeds `u64::MAX` (saturates with `panic-free`)
 impl From<i128> for AncDec {
     #[inline(always)]
     fn from(n: i128) -> Self {
-        let abs = n.unsigned_abs();
-        assert!(abs <= u64::MAX as u128, "i128 value exceeds AncDec range");
-        Self { int: abs as u64, frac: 0, scale: 0, neg: n < 0 }
+        let int = u64::try_from(n.unsigned_abs()).map_err(|_| ArithmeticError::Overflow);
+        let int = unwrap_or_saturate(int, || u64::MAX);
+        Self { int, frac: 0, scale: 0, neg: n < 0 }
     }
 }
 
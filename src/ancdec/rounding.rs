@@ -1,6 +1,14 @@
-use crate::util::pow10_128;
+use crate::error::{unwrap_or_saturate, ArithmeticError};
+use crate::util::{digits_64, pow10, pow10_128, signed_i128, TARGET_SCALE};
+use crate::wide::{div_wide, divmod_u256, mul_wide};
 use super::AncDec;
+use core::num::NonZeroU128;
+use crate::round_mode::Residue;
 use crate::RoundMode;
+#[cfg(feature = "rand")]
+use crate::round_mode::stochastic_rounds_up;
+#[cfg(feature = "rand")]
+use rand_core::RngCore;
 
 impl AncDec {
     /// Rounds to the given number of decimal places using the specified mode.
@@ -13,8 +21,65 @@ impl AncDec {
                 neg: self.neg,
             };
         }
+        self.round_by(decimal_places, |r, d, last_digit| {
+            mode.rounds_up(Residue::new(r, d), self.neg, last_digit)
+        })
+        .0
+    }
+
+    /// Returns the largest integer less than or equal to `self`.
+    #[inline(always)]
+    pub fn floor(&self) -> Self {
+        self.round(0, RoundMode::Floor)
+    }
+    /// Returns the smallest integer greater than or equal to `self`.
+    #[inline(always)]
+    pub fn ceil(&self) -> Self {
+        self.round(0, RoundMode::Ceil)
+    }
+    /// Returns the integer part, truncating toward zero.
+    #[inline(always)]
+    pub fn trunc(&self) -> Self {
+        self.round(0, RoundMode::Truncate)
+    }
+    /// Returns the fractional part only.
+    #[inline(always)]
+    pub fn fract(&self) -> Self {
+        self.round(0, RoundMode::Fract)
+    }
+
+    /// Rounds like [`round`](Self::round) and also returns the exact residual removed, so
+    /// `rounded + residual == self`: round each period's amount and carry the residual into
+    /// the next. The residual keeps `self`'s scale. `RoundMode::Fract` truncates.
+    ///
+    /// Panics if rounding overflows (saturates with `panic-free`).
+    pub fn round_with_residual(&self, decimal_places: u8, mode: RoundMode) -> (Self, Self) {
+        self.round_by(decimal_places, |r, d, last_digit| {
+            mode.rounds_up(Residue::new(r, d), self.neg, last_digit)
+        })
+    }
+
+    /// Stochastic rounding to `decimal_places`: the magnitude rounds up with probability
+    /// equal to the discarded part over one unit in the last kept place (`1.25` to one
+    /// place gives `1.3` half of the time), so the result is unbiased on average.
+    ///
+    /// Panics if rounding up overflows (saturates with `panic-free`).
+    #[cfg(feature = "rand")]
+    pub fn round_stochastic(&self, decimal_places: u8, rng: &mut impl RngCore) -> Self {
+        self.round_by(decimal_places, |r, d, _| stochastic_rounds_up(r, d, rng)).0
+    }
+
+    /// Cuts to `decimal_places`, adding one unit in the last kept place when
+    /// `rounds_up(remainder, divisor, last_digit)` says so. Also returns the residual
+    /// `self - rounded` at `self`'s scale.
+    #[inline(always)]
+    fn round_by(
+        &self,
+        decimal_places: u8,
+        rounds_up: impl FnOnce(u128, u128, u8) -> bool,
+    ) -> (Self, Self) {
         if self.scale <= decimal_places {
-            return *self;
+            return (*self, Self::ZERO);
         }
 
         let combined = (self.int as u128) * pow10_128(self.scale) + (self.frac as u128);
@@ -23,53 +88,401 @@ impl AncDec {
         let remainder = combined % divisor;
         let mut truncated = combined / divisor;
 
-        if self.should_round_up(truncated, remainder, divisor, mode) {
+        let up = rounds_up(remainder, divisor, (truncated % 10) as u8);
+        if up {
             truncated += 1;
         }
-        Self::from_combined(truncated, decimal_places, self.neg)
+        // remainder < divisor <= 10^scale, so the residual is purely fractional; rounding up
+        // leaves `divisor - remainder` with the opposite sign
+        let residual = if up { divisor - remainder } else { remainder };
+        let residual = Self {
+            int: 0,
+            frac: residual as u64,
+            scale: self.scale,
+            neg: (self.neg ^ up) && residual != 0,
+        };
+        (Self::from_combined(truncated, decimal_places, self.neg), residual)
     }
+}
 
-    fn should_round_up(
-        &self,
-        truncated: u128,
-        remainder: u128,
-        divisor: u128,
-        mode: RoundMode,
-    ) -> bool {
-        if remainder == 0 {
-            return false;
+// ============ Rounded Arithmetic ============
+impl AncDec {
+    /// Multiplies and rounds the exact product once to `scale` decimal places (capped at 19).
+    /// Products that already fit in `scale` digits keep their exact scale.
+    ///
+    /// Panics on overflow (saturates with `panic-free`). `RoundMode::Fract` truncates.
+    #[inline(always)]
+    pub fn mul_round(&self, other: &Self, scale: u8, mode: RoundMode) -> Self {
+        unwrap_or_saturate(
+            self.checked_mul_round(other, scale, mode).ok_or(ArithmeticError::Overflow),
+            || Self::saturated(self.neg ^ other.neg),
+        )
+    }
+
+    /// Divides and rounds the exact quotient once to `scale` decimal places (capped at 19).
+    ///
+    /// Panics on division by zero or overflow (saturates with `panic-free`, see
+    /// [`saturating_div`](Self::saturating_div)). `RoundMode::Fract` truncates.
+    #[inline(always)]
+    pub fn div_round(&self, other: &Self, scale: u8, mode: RoundMode) -> Self {
+        let result = if other.is_zero() {
+            Err(ArithmeticError::DivisionByZero)
+        } else {
+            self.checked_div_round(other, scale, mode).ok_or(ArithmeticError::Overflow)
+        };
+        unwrap_or_saturate(result, || self.saturating_div(other))
+    }
+
+    /// Checked [`mul_round`](Self::mul_round). Returns `None` if the rounded result overflows `u64`.
+    pub fn checked_mul_round(&self, other: &Self, scale: u8, mode: RoundMode) -> Option<Self> {
+        self.mul_round_exact(other, scale, mode).map(|(p, _)| p)
+    }
+
+    /// Checked [`div_round`](Self::div_round). Returns `None` if `other` is zero or the
+    /// rounded quotient overflows `u64`.
+    pub fn checked_div_round(&self, other: &Self, scale: u8, mode: RoundMode) -> Option<Self> {
+        self.div_round_exact(other, scale, mode).map(|(q, _)| q)
+    }
+
+    /// Divides, keeping the minimal exact scale when the quotient terminates within 19 places
+    /// (`1 / 2` gives `0.5` at scale 1 rather than a scale-19 value). Quotients that do not
+    /// terminate are rounded to `default_scale` places (capped at 19) with `mode`.
+    ///
+    /// Panics on division by zero or overflow (saturates with `panic-free`, see
+    /// [`saturating_div`](Self::saturating_div)).
+    #[inline(always)]
+    pub fn div_min_scale(&self, other: &Self, default_scale: u8, mode: RoundMode) -> Self {
+        let result = if other.is_zero() {
+            Err(ArithmeticError::DivisionByZero)
+        } else {
+            self.checked_div_min_scale(other, default_scale, mode).ok_or(ArithmeticError::Overflow)
+        };
+        unwrap_or_saturate(result, || self.saturating_div(other))
+    }
+
+    /// Checked [`div_min_scale`](Self::div_min_scale). Returns `None` if `other` is zero or the
+    /// quotient overflows `u64`.
+    pub fn checked_div_min_scale(&self, other: &Self, default_scale: u8, mode: RoundMode) -> Option<Self> {
+        match self.div_round_exact(other, TARGET_SCALE, RoundMode::Truncate) {
+            Some((q, true)) => Some(q.normalize()),
+            _ => self.checked_div_round(other, default_scale, mode),
         }
-        let half = divisor / 2;
+    }
 
-        match mode {
-            RoundMode::Floor => self.neg,
-            RoundMode::Ceil => !self.neg,
-            RoundMode::Truncate => false,
-            RoundMode::HalfUp => remainder >= half,
-            RoundMode::HalfDown => remainder > half,
-            RoundMode::HalfEven => remainder > half || (remainder == half && truncated % 2 == 1),
-            RoundMode::Fract => false,
+    /// `checked_mul_round`, also reporting whether the product was exact (nothing was rounded off)
+    pub(crate) fn mul_round_exact(&self, other: &Self, scale: u8, mode: RoundMode) -> Option<(Self, bool)> {
+        let neg = self.neg ^ other.neg;
+        let a = (self.int as u128) * pow10_128(self.scale) + (self.frac as u128);
+        let b = (other.int as u128) * pow10_128(other.scale) + (other.frac as u128);
+        let total_scale = self.scale + other.scale;
+        let scale = scale.min(TARGET_SCALE);
+
+        // u256 product, divided once by 10^(total_scale - scale)
+        let (high, low) = mul_wide(a, b);
+        if total_scale <= scale {
+            if high != 0 {
+                return None;
+            }
+            return Self::checked_from_combined(low, total_scale, neg).map(|p| (p, true));
         }
+        let (q, exact) = Self::div_round_wide(high, low, pow10_128(total_scale - scale), neg, mode)?;
+        Some((Self::checked_from_combined(q, scale, neg)?, exact))
     }
 
-    /// Returns the largest integer less than or equal to `self`.
+    /// `checked_div_round`, also reporting whether the quotient was exact (nothing was rounded off)
+    pub(crate) fn div_round_exact(&self, other: &Self, scale: u8, mode: RoundMode) -> Option<(Self, bool)> {
+        let neg = self.neg ^ other.neg;
+        let a = (self.int as u128) * pow10_128(self.scale) + (self.frac as u128);
+        let b = NonZeroU128::new((other.int as u128) * pow10_128(other.scale) + (other.frac as u128))?;
+        let scale = scale.min(TARGET_SCALE);
+
+        // quotient at `scale` digits: a * 10^(scale + b.scale - a.scale) / b
+        let shift = scale + other.scale;
+        let (q, exact) = if shift >= self.scale {
+            let (high, low) = mul_wide(a, pow10_128(shift - self.scale));
+            Self::div_round_wide(high, low, b.get(), neg, mode)?
+        } else {
+            // a / (b * 10^k): the divisor may exceed u128, so classify the remainder in u256
+            let k = pow10_128(self.scale - shift);
+            let q = a / b / k;
+            let d = mul_wide(b.get(), k);
+            let r = a - q.wrapping_mul(d.1);
+            let up = mode.rounds_up(Residue::new_wide((0, r), d), neg, (q % 10) as u8);
+            (q + up as u128, r == 0)
+        };
+        Some((Self::checked_from_combined(q, scale, neg)?, exact))
+    }
+
+    /// Computes `self * b / c` from the exact u256 product, rounded once to 19 decimal places.
+    ///
+    /// Returns `None` if `c` is zero or the final quotient overflows `u64` integer range;
+    /// the intermediate product itself never overflows. `RoundMode::Fract` truncates.
+    pub fn mul_div(&self, b: &Self, c: &Self, mode: RoundMode) -> Option<Self> {
+        let neg = self.neg ^ b.neg ^ c.neg;
+        let x = (self.int as u128) * pow10_128(self.scale) + (self.frac as u128);
+        let y = (b.int as u128) * pow10_128(b.scale) + (b.frac as u128);
+        let z = (c.int as u128) * pow10_128(c.scale) + (c.frac as u128);
+        if z == 0 {
+            return None;
+        }
+
+        // quotient at 19 places: x * y * 10^(19 + c.scale - self.scale - b.scale) / z
+        let (high, low) = mul_wide(x, y);
+        let (up, down) = (TARGET_SCALE + c.scale, self.scale + b.scale);
+        let (q1, r1) = divmod_u256(high, low, z);
+        let (q, residue) = if up >= down {
+            // q1 * 10^e + r1 * 10^e / z, where r1 < z keeps the second quotient below 10^e
+            let k = pow10_128(up - down);
+            if q1.0 != 0 {
+                return None;
+            }
+            let (h, l) = mul_wide(r1, k);
+            let ((_, q2), r2) = divmod_u256(h, l, z);
+            (q1.1.checked_mul(k)?.checked_add(q2)?, Residue::new(r2, z))
+        } else {
+            let k = pow10_128(down - up);
+            let ((q_hi, q), r2) = divmod_u256(q1.0, q1.1, k);
+            if q_hi != 0 {
+                return None;
+            }
+            (q, Residue::new(r2, k).with_sticky(r1 != 0))
+        };
+        let q = q.checked_add(mode.rounds_up(residue, neg, (q % 10) as u8) as u128)?;
+        Self::checked_from_combined(q, TARGET_SCALE, neg)
+    }
+
+    /// Rounded u256 / u128 quotient and whether it was exact. `None` if it does not fit in u128.
     #[inline(always)]
-    pub fn floor(&self) -> Self {
-        self.round(0, RoundMode::Floor)
+    fn div_round_wide(high: u128, low: u128, d: u128, neg: bool, mode: RoundMode) -> Option<(u128, bool)> {
+        // div_wide requires high < d for the quotient to fit in u128
+        if high >= d {
+            return None;
+        }
+        let q = div_wide(high, low, d);
+        let r = low.wrapping_sub(q.wrapping_mul(d));
+        let up = mode.rounds_up(Residue::new(r, d), neg, (q % 10) as u8);
+        Some((q.checked_add(up as u128)?, r == 0))
     }
-    /// Returns the smallest integer greater than or equal to `self`.
+}
+
+// ============ Scale Management ============
+impl AncDec {
+    /// Number of trailing zeros among the fractional digits (`1.2300` has 2, `1.000` has 3):
+    /// how far [`normalize`](Self::normalize) lowers the scale.
+    pub fn trailing_zeros(&self) -> u8 {
+        if self.frac == 0 {
+            return self.scale;
+        }
+        // Binary search: strip trailing zeros in O(log scale) instead of O(scale)
+        let (mut frac, mut n) = (self.frac, 0);
+        if frac % 10_000_000_000_000_000 == 0 {
+            frac /= 10_000_000_000_000_000;
+            n += 16;
+        }
+        if frac % 100_000_000 == 0 {
+            frac /= 100_000_000;
+            n += 8;
+        }
+        if frac % 10_000 == 0 {
+            frac /= 10_000;
+            n += 4;
+        }
+        if frac % 100 == 0 {
+            frac /= 100;
+            n += 2;
+        }
+        n + (frac % 10 == 0) as u8
+    }
+
+    /// Strips trailing fractional zeros (`1.2300` becomes `1.23` at scale 2). Zero becomes
+    /// [`ZERO`](Self::ZERO), so equal values normalize to the same representation.
     #[inline(always)]
-    pub fn ceil(&self) -> Self {
-        self.round(0, RoundMode::Ceil)
+    pub fn normalize(&self) -> Self {
+        if self.is_zero() {
+            return Self::ZERO;
+        }
+        let tz = self.trailing_zeros();
+        Self {
+            int: self.int,
+            frac: self.frac / pow10(tz),
+            scale: self.scale - tz,
+            neg: self.neg,
+        }
     }
-    /// Returns the integer part, truncating toward zero.
+
+    /// Changes the scale to exactly `scale`: widens with zeros, or narrows by rounding with
+    /// `mode` (`RoundMode::Fract` truncates). Unlike [`round`](Self::round), the result
+    /// always has the requested scale.
+    ///
+    /// Panics if `scale` exceeds 19 or rounding overflows (saturates with `panic-free`).
     #[inline(always)]
-    pub fn trunc(&self) -> Self {
-        self.round(0, RoundMode::Truncate)
+    pub fn rescale(&self, scale: u8, mode: RoundMode) -> Self {
+        let result = if scale > TARGET_SCALE {
+            Err(ArithmeticError::ScaleOverflow)
+        } else {
+            self.checked_rescale(scale, mode).ok_or(ArithmeticError::Overflow)
+        };
+        unwrap_or_saturate(result, || Self::saturated(self.neg))
     }
-    /// Returns the fractional part only.
+
+    /// Checked [`rescale`](Self::rescale). Returns `None` if `scale` exceeds 19 or the
+    /// rounded value overflows `u64`.
+    pub fn checked_rescale(&self, scale: u8, mode: RoundMode) -> Option<Self> {
+        if scale > TARGET_SCALE {
+            return None;
+        }
+        if scale >= self.scale {
+            // SAFETY: frac < 10^self.scale, so the widened frac stays below 10^19
+            return Some(Self {
+                frac: self.frac * pow10(scale - self.scale),
+                scale,
+                ..*self
+            });
+        }
+        // times ONE (scale 0) rounds the exact value once to `scale`
+        self.mul_round_exact(&Self::ONE, scale, mode).map(|(v, _)| v)
+    }
+
+    /// Changes the scale to exactly `scale` without altering the value. Returns `None` if
+    /// narrowing would drop nonzero digits or `scale` exceeds 19.
+    pub fn with_scale(&self, scale: u8) -> Option<Self> {
+        if scale < self.scale && self.trailing_zeros() < self.scale - scale {
+            return None;
+        }
+        self.checked_rescale(scale, RoundMode::Truncate)
+    }
+}
+
+// ============ Increment Rounding ============
+impl AncDec {
+    /// Rounds to a multiple of `increment` using `mode`: tick sizes like `0.25`, or cash
+    /// rounding to `0.05`. The quotient is rounded exactly on the combined integers and the
+    /// result takes `increment`'s scale; the sign of `increment` is ignored
+    /// (`RoundMode::Fract` truncates).
+    ///
+    /// Panics if `increment` is zero or the result overflows `u64` (saturates with
+    /// `panic-free`, where a zero increment returns `self` unchanged).
+    pub fn round_to_increment(&self, increment: &Self, mode: RoundMode) -> Self {
+        let result = if increment.is_zero() {
+            Err(ArithmeticError::DivisionByZero)
+        } else {
+            self.checked_round_to_increment(increment, mode).ok_or(ArithmeticError::Overflow)
+        };
+        unwrap_or_saturate(result, || {
+            if increment.is_zero() {
+                *self
+            } else {
+                Self::saturated(self.neg)
+            }
+        })
+    }
+
+    /// Checked [`round_to_increment`](Self::round_to_increment). Returns `None` if
+    /// `increment` is zero or the result overflows `u64`.
+    pub fn checked_round_to_increment(&self, increment: &Self, mode: RoundMode) -> Option<Self> {
+        let step = increment.abs();
+        let (q, neg, _) = self.int_divmod(&step, mode)?;
+        // step at its own scale, no larger than the aligned divisor `int_divmod` used
+        let m = (step.int as u128) * pow10_128(step.scale) + step.frac as u128;
+        Self::checked_from_combined(q.checked_mul(m)?, step.scale, neg)
+    }
+
+    /// Whether `self` is an exact multiple of `increment` (`1.25` of `0.05`, but not `1.26`).
+    /// Only zero is a multiple of zero.
+    pub fn is_multiple_of(&self, increment: &Self) -> bool {
+        match self.int_divmod(increment, RoundMode::Truncate) {
+            Some((_, _, r)) => r.is_zero(),
+            None => self.is_zero(),
+        }
+    }
+}
+
+// ============ Significant Digits ============
+impl AncDec {
+    /// Number of digits in the integer part (`123.45` has 3); 0 when `|self| < 1`.
     #[inline(always)]
-    pub fn fract(&self) -> Self {
-        self.round(0, RoundMode::Fract)
+    pub fn int_digits(&self) -> u8 {
+        digits_64(self.int)
+    }
+
+    /// Number of zeros between the decimal point and the first nonzero fractional digit
+    /// (`0.0012` has 2); all `scale` digits when the fractional part is zero.
+    #[inline(always)]
+    pub fn leading_frac_zeros(&self) -> u8 {
+        self.scale - digits_64(self.frac)
+    }
+
+    /// Number of significant digits, from the first nonzero digit through the last stored
+    /// fractional digit (`0.0120` has 3, `100` has 3). Zero has none.
+    pub fn precision(&self) -> u8 {
+        if self.int != 0 {
+            self.int_digits() + self.scale
+        } else {
+            digits_64(self.frac)
+        }
+    }
+
+    /// Rounds to `digits` significant digits (at least 1) using `mode`: `1234.5` to 2 digits gives
+    /// `1200`, `0.012345` to 3 gives `0.0123`. Integer digits past `digits` become zeros, and a carry
+    /// into a new leading digit drops the extra fractional zero (`9.96` to 2 digits gives `10`).
+    /// `RoundMode::Fract` truncates.
+    ///
+    /// Panics if rounding overflows (saturates with `panic-free`).
+    pub fn round_sig(&self, digits: u8, mode: RoundMode) -> Self {
+        let digits = digits.max(1);
+        let mode = if mode == RoundMode::Fract { RoundMode::Truncate } else { mode };
+        let int_digits = self.int_digits();
+        if int_digits > digits {
+            // 10^(int_digits - digits) is below `int`, so it is representable
+            let step = Self {
+                int: pow10(int_digits - digits),
+                frac: 0,
+                scale: 0,
+                neg: false,
+            };
+            return self.round_to_increment(&step, mode);
+        }
+        let places = if int_digits > 0 {
+            digits - int_digits
+        } else {
+            digits.saturating_add(self.leading_frac_zeros())
+        };
+        if places >= self.scale {
+            return *self;
+        }
+        let rounded = self.round(places, mode);
+        if rounded.precision() > digits && rounded.scale > 0 {
+            // the carry left a trailing zero (9.96 -> 10.0)
+            return Self {
+                frac: rounded.frac / 10,
+                scale: rounded.scale - 1,
+                ..rounded
+            };
+        }
+        rounded
+    }
+
+    /// Decomposes into `(mantissa, exponent)` with `self == mantissa * 10^exponent` and no
+    /// trailing zeros in the mantissa: `12.50` gives `(125, -1)`, `1200` gives `(12, 2)` and
+    /// zero gives `(0, 0)`. Panics if the mantissa is outside `i128` range (clamps to
+    /// `i128::MIN`/`i128::MAX` with `panic-free`).
+    pub fn mantissa_exponent(&self) -> (i128, i32) {
+        unwrap_or_saturate(self.try_mantissa_exponent(), || {
+            (if self.neg { i128::MIN } else { i128::MAX }, 0)
+        })
+    }
+
+    /// Fallible [`mantissa_exponent`](Self::mantissa_exponent). Returns
+    /// `ArithmeticError::Overflow` if the mantissa is outside `i128` range.
+    pub fn try_mantissa_exponent(&self) -> Result<(i128, i32), ArithmeticError> {
+        let v = self.normalize();
+        let mut m = (v.int as u128) * pow10_128(v.scale) + v.frac as u128;
+        let mut exponent = -(v.scale as i32);
+        // only a normalized integer can still end in zeros
+        while m != 0 && m % 10 == 0 {
+            m /= 10;
+            exponent += 1;
+        }
+        Ok((signed_i128(m, v.neg).ok_or(ArithmeticError::Overflow)?, exponent))
     }
 }
@@ -1,66 +1,123 @@
+use crate::error::{unwrap_or_saturate, ArithmeticError};
 use super::AncDec128;
-use crate::util::{pow10, pow10_128, pow10_256, SCALE38, TARGET_SCALE_128};
-use crate::wide::{div_u512_by_u128, div_u512_by_u256, div_wide, divmod_u256, mul_u256, mul_wide};
+use core::num::{NonZeroU128, NonZeroU64};
+use crate::round_mode::Residue;
+use crate::util::{pow10, pow10_128, pow10_256, signed_i128_wide, SCALE38, TARGET_SCALE_128};
+use crate::wide::{
+    div_u512_by_u128, div_u512_by_u256, div_wide, divmod_u256, divmod_u512, mod10_u256, mul_u256,
+    mul_wide,
+};
+use crate::RoundMode;
 
 impl AncDec128 {
     /// Combine int and frac into a single u256 = int * 10^scale + frac
     #[inline(always)]
-    fn combine(int: u128, frac: u128, scale: u8) -> (u128, u128) {
+    pub(crate) fn combine(int: u128, frac: u128, scale: u8) -> (u128, u128) {
         let (hi, lo) = mul_wide(int, pow10_128(scale));
         let (lo2, carry) = lo.overflowing_add(frac);
         (hi + carry as u128, lo2)
     }
 
-    /// Adds two decimals, panics on integer overflow.
+    /// Adds two decimals, panics on integer overflow (saturates with `panic-free`).
     #[inline(always)]
     pub fn add(&self, other: &Self) -> Self {
-        let (a_frac, b_frac, scale, limit) = self.align_frac(other);
+        unwrap_or_saturate(self.try_add(other), || Self::saturated(self.neg))
+    }
 
-        if self.neg == other.neg {
-            let (int, frac, scale) =
-                Self::add_aligned(self.int, a_frac, other.int, b_frac, scale, limit);
-            Self {
-                int,
-                frac,
-                scale,
-                neg: self.neg,
-            }
-        } else {
-            Self::sub_with_cmp(
-                self.int, a_frac, self.neg, other.int, b_frac, other.neg, scale, limit,
-            )
+    /// Subtracts `other` from `self`, panics on integer overflow (saturates with `panic-free`).
+    #[inline(always)]
+    pub fn sub(&self, other: &Self) -> Self {
+        unwrap_or_saturate(self.try_sub(other), || Self::saturated(self.neg))
+    }
+
+    /// Multiplies two decimals, panics on overflow (saturates with `panic-free`).
+    /// Uses u256/u512 wide arithmetic when needed.
+    #[inline(always)]
+    pub fn mul(&self, other: &Self) -> Self {
+        unwrap_or_saturate(self.try_mul(other), || Self::saturated(self.neg ^ other.neg))
+    }
+
+    /// Divides `self` by `other`, panics on division by zero or if the quotient overflows `u128`
+    /// (saturates with `panic-free`, see [`saturating_div`](Self::saturating_div)).
+    #[inline(always)]
+    pub fn div(&self, other: &Self) -> Self {
+        unwrap_or_saturate(self.try_div(other), || self.saturating_div(other))
+    }
+
+    /// Fallible addition. Returns `ArithmeticError::Overflow` if the integer part overflows `u128`.
+    #[inline(always)]
+    pub fn try_add(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        self.checked_add(other).ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Fallible subtraction. Returns `ArithmeticError::Overflow` if the integer part overflows `u128`.
+    #[inline(always)]
+    pub fn try_sub(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        self.checked_sub(other).ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Fallible multiplication. Returns `ArithmeticError::Overflow` if the result overflows `u128` integer range.
+    #[inline(always)]
+    pub fn try_mul(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        self.checked_mul(other).ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Fallible division. Returns `ArithmeticError::DivisionByZero` if `other` is zero,
+    /// `ArithmeticError::Overflow` if the quotient overflows `u128` integer range.
+    #[inline(always)]
+    pub fn try_div(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        if other.is_zero() {
+            return Err(ArithmeticError::DivisionByZero);
         }
+        self.checked_div(other).ok_or(ArithmeticError::Overflow)
     }
 
-    /// Subtracts `other` from `self`, panics on integer overflow.
+    /// Fallible remainder. Returns `ArithmeticError::DivisionByZero` if `other` is zero.
     #[inline(always)]
-    pub fn sub(&self, other: &Self) -> Self {
+    pub fn try_rem(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        self.checked_rem(other).ok_or(ArithmeticError::DivisionByZero)
+    }
+
+    /// Checked addition. Returns `None` if the integer part overflows `u128`.
+    #[inline(always)]
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
         let (a_frac, b_frac, scale, limit) = self.align_frac(other);
-        let other_neg = !other.neg;
 
-        if self.neg == other_neg {
+        if self.neg == other.neg {
             let (int, frac, scale) =
-                Self::add_aligned(self.int, a_frac, other.int, b_frac, scale, limit);
-            Self {
+                Self::add_aligned(self.int, a_frac, other.int, b_frac, scale, limit)?;
+            Some(Self {
                 int,
                 frac,
                 scale,
                 neg: self.neg,
-            }
+            })
         } else {
-            Self::sub_with_cmp(
-                self.int, a_frac, self.neg, other.int, b_frac, other_neg, scale, limit,
-            )
+            Some(Self::sub_with_cmp(
+                self.int, a_frac, self.neg, other.int, b_frac, other.neg, scale, limit,
+            ))
         }
     }
 
-    /// Multiplies two decimals, panics on overflow. Uses u256/u512 wide arithmetic when needed.
+    /// Checked subtraction. Returns `None` if the integer part overflows `u128`.
+    ///
+    /// Operands of equal sign go through magnitude comparison and cannot overflow;
+    /// opposite signs add magnitudes, which can.
     #[inline(always)]
-    pub fn mul(&self, other: &Self) -> Self {
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        self.checked_add(&Self {
+            neg: !other.neg,
+            ..*other
+        })
+    }
+
+    /// Checked multiplication. Returns `None` if the result overflows `u128` integer range.
+    #[inline(always)]
+    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
         let neg = self.neg ^ other.neg;
         let total_scale = self.scale + other.scale;
 
-        // Ultra-fast path: both fit in u64 → u64×u64=u128, zero wide arithmetic
+        // Ultra-fast path: both fit in u64
         if let (Some(a), Some(b)) = (
             Self::try_combine_u64(self.int, self.frac, self.scale),
             Self::try_combine_u64(other.int, other.frac, other.scale),
@@ -69,17 +126,17 @@ impl AncDec128 {
             if total_scale <= 19 && product <= u64::MAX as u128 {
                 let p = product as u64;
                 let d = pow10(total_scale);
-                return Self { int: (p / d) as u128, frac: (p % d) as u128, scale: total_scale, neg };
+                return Some(Self { int: (p / d) as u128, frac: (p % d) as u128, scale: total_scale, neg });
             }
             if total_scale <= 38 {
                 let divisor = pow10_128(total_scale);
-                return Self { int: product / divisor, frac: product % divisor, scale: total_scale, neg };
+                return Some(Self { int: product / divisor, frac: product % divisor, scale: total_scale, neg });
             }
             let reduced = product / pow10_128(total_scale - TARGET_SCALE_128);
-            return Self { int: reduced / SCALE38, frac: reduced % SCALE38, scale: TARGET_SCALE_128, neg };
+            return Some(Self { int: reduced / SCALE38, frac: reduced % SCALE38, scale: TARGET_SCALE_128, neg });
         }
 
-        // Partial product path: all parts fit in u64 → 4 native muls, no wide arithmetic
+        // Partial product path: all parts fit in u64
         if self.scale <= 19 && other.scale <= 19
             && self.int <= u64::MAX as u128 && self.frac <= u64::MAX as u128
             && other.int <= u64::MAX as u128 && other.frac <= u64::MAX as u128
@@ -98,15 +155,12 @@ impl AncDec128 {
             let sb_pow = pow10_128(other.scale);
             let scale_pow = pow10_128(total_scale);
 
-            // Split cross terms into integer + fractional at scale total_scale
             let if_int = if_ / sb_pow;
             let if_frac = (if_ % sb_pow) * sa_pow;
 
             let fi_int = fi / sa_pow;
             let fi_frac = (fi % sa_pow) * sb_pow;
 
-            // ff < scale_pow by invariant (frac < 10^scale), so ff is purely fractional
-            // Sum fractional parts: each < scale_pow, sum < 3 * scale_pow < u128::MAX
             let frac_sum = if_frac + fi_frac + ff;
             let (frac, frac_carry) = if frac_sum >= scale_pow {
                 let f = frac_sum - scale_pow;
@@ -117,12 +171,11 @@ impl AncDec128 {
 
             let int = ii.checked_add(if_int)
                 .and_then(|v| v.checked_add(fi_int))
-                .and_then(|v| v.checked_add(frac_carry))
-                .expect("multiplication overflow");
-            return Self { int, frac, scale: total_scale, neg };
+                .and_then(|v| v.checked_add(frac_carry))?;
+            return Some(Self { int, frac, scale: total_scale, neg });
         }
 
-        // Fast path: both fit in u128 → single mul_wide instead of mul_u256
+        // Fast path: both fit in u128
         if let (Some(a), Some(b)) = (
             Self::try_combine_u128(self.int, self.frac, self.scale),
             Self::try_combine_u128(other.int, other.frac, other.scale),
@@ -131,12 +184,12 @@ impl AncDec128 {
             if total_scale > TARGET_SCALE_128 {
                 let divisor = pow10_128(total_scale - TARGET_SCALE_128);
                 let ((q_hi, q_lo), _) = divmod_u256(high, low, divisor);
-                return Self::from_combined((q_hi, q_lo), TARGET_SCALE_128, neg);
+                return Self::checked_from_combined((q_hi, q_lo), TARGET_SCALE_128, neg);
             }
-            return Self::from_combined((high, low), total_scale, neg);
+            return Self::checked_from_combined((high, low), total_scale, neg);
         }
 
-        // Slow path: full u256 * u256 → u512
+        // Slow path: full u256 * u256 -> u512
         let a = Self::combine(self.int, self.frac, self.scale);
         let b = Self::combine(other.int, other.frac, other.scale);
 
@@ -148,24 +201,27 @@ impl AncDec128 {
         } else if w3 == 0 && w2 == 0 {
             ((w1, w0), total_scale)
         } else {
-            panic!("multiplication overflow")
+            return None;
         };
 
-        Self::from_combined(result, final_scale, neg)
+        Self::checked_from_combined(result, final_scale, neg)
     }
 
-    /// Divides `self` by `other`, panics on division by zero. Uses u256/u512 wide arithmetic when needed.
+    /// Checked division. Returns `None` if `other` is zero or the quotient overflows `u128` integer range.
     #[inline(always)]
-    pub fn div(&self, other: &Self) -> Self {
-        assert!(other.int != 0 || other.frac != 0, "division by zero");
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        if other.int == 0 && other.frac == 0 {
+            return None;
+        }
 
         let neg = self.neg ^ other.neg;
 
         // Ultra-fast path: both fit in u64 → native u64 division
+        // (NonZero divisors so the fast paths carry no implicit div-by-zero panic)
         // Decompose: a * 10^exp / b = (a/b) * 10^exp + (a%b) * 10^exp / b
         if let (Some(a64), Some(b64)) = (
             Self::try_combine_u64(self.int, self.frac, self.scale),
-            Self::try_combine_u64(other.int, other.frac, other.scale),
+            Self::try_combine_u64(other.int, other.frac, other.scale).and_then(NonZeroU64::new),
         ) {
             let shift = TARGET_SCALE_128 + other.scale;
             if shift >= self.scale {
@@ -185,15 +241,15 @@ impl AncDec128 {
                     let frac_low = if r == 0 {
                         0
                     } else if let Some(r_scaled) = r.checked_mul(exp_pow) {
-                        r_scaled / (b64 as u128)
+                        r_scaled / NonZeroU128::from(b64)
                     } else {
                         let (h, l) = mul_wide(r, exp_pow);
-                        div_wide(h, l, b64 as u128)
+                        div_wide(h, l, b64.get() as u128)
                     };
 
                     let frac_total = frac_high + frac_low;
                     let carry = (frac_total >= SCALE38) as u128;
-                    return Self { int: int + carry, frac: frac_total - carry * SCALE38, scale: TARGET_SCALE_128, neg };
+                    return Some(Self { int: int + carry, frac: frac_total - carry * SCALE38, scale: TARGET_SCALE_128, neg });
                 }
             }
         }
@@ -201,7 +257,7 @@ impl AncDec128 {
         // Fast path: both fit in u128
         if let (Some(a), Some(b)) = (
             Self::try_combine_u128(self.int, self.frac, self.scale),
-            Self::try_combine_u128(other.int, other.frac, other.scale),
+            Self::try_combine_u128(other.int, other.frac, other.scale).and_then(NonZeroU128::new),
         ) {
             let shift = TARGET_SCALE_128 + other.scale;
             if shift >= self.scale {
@@ -224,12 +280,12 @@ impl AncDec128 {
                         r_scaled / b
                     } else {
                         let (h, l) = mul_wide(r, exp_pow);
-                        div_wide(h, l, b)
+                        div_wide(h, l, b.get())
                     };
 
                     let frac_total = frac_high + frac_low;
                     let carry = (frac_total >= SCALE38) as u128;
-                    return Self { int: int + carry, frac: frac_total - carry * SCALE38, scale: TARGET_SCALE_128, neg };
+                    return Some(Self { int: int + carry, frac: frac_total - carry * SCALE38, scale: TARGET_SCALE_128, neg });
                 }
             }
         }
@@ -244,6 +300,11 @@ impl AncDec128 {
         let quotient = if shift >= self.scale {
             let multiplier = pow10_256(shift - self.scale);
             let (w3, w2, w1, w0) = mul_u256(a, multiplier);
+            // Knuth D needs the top half below the divisor for the quotient to fit in u256
+            // (a u128 divisor is handled by div_u512_by_u128, which saturates instead)
+            if b.0 != 0 && (w3, w2) >= b {
+                return None;
+            }
             div_u512_by_u256(w3, w2, w1, w0, b.0, b.1)
         } else {
             // Rare case: self.scale > shift, divide multiplier instead
@@ -254,181 +315,309 @@ impl AncDec128 {
         };
 
         // Split quotient by SCALE38
-        let ((_, q_hi), r_lo) = divmod_u256(quotient.0, quotient.1, SCALE38);
+        let ((q_top, q_hi), r_lo) = divmod_u256(quotient.0, quotient.1, SCALE38);
+        if q_top != 0 {
+            return None;
+        }
 
-        Self {
+        Some(Self {
             int: q_hi,
             frac: r_lo,
             scale: TARGET_SCALE_128,
             neg,
+        })
+    }
+
+    /// Checked remainder. Returns `None` if `other` is zero.
+    /// Computed exactly from one integer division, so it never overflows.
+    #[inline(always)]
+    pub fn checked_rem(&self, other: &Self) -> Option<Self> {
+        self.int_divmod(other, RoundMode::Truncate).map(|(_, _, r)| r)
+    }
+
+    /// Checked negation. Negation only flips the sign flag, so this always returns `Some`.
+    #[inline(always)]
+    pub fn checked_neg(&self) -> Option<Self> {
+        Some(Self {
+            neg: !self.neg,
+            ..*self
+        })
+    }
+
+    /// Computes the remainder (`self % other`), panics on division by zero
+    /// (returns `ZERO` with `panic-free`).
+    #[inline(always)]
+    pub fn rem(&self, other: &Self) -> Self {
+        unwrap_or_saturate(self.try_rem(other), || Self::ZERO)
+    }
+
+    /// `MAX` with the given sign: the value every saturating operation clamps to.
+    #[inline(always)]
+    pub(crate) fn saturated(neg: bool) -> Self {
+        Self { neg, ..Self::MAX }
+    }
+
+    /// Saturating addition. Clamps to `MAX` or `-MAX` instead of overflowing.
+    #[inline(always)]
+    pub fn saturating_add(&self, other: &Self) -> Self {
+        self.overflowing_add(other).0
+    }
+
+    /// Saturating subtraction. Clamps to `MAX` or `-MAX` instead of overflowing.
+    #[inline(always)]
+    pub fn saturating_sub(&self, other: &Self) -> Self {
+        self.overflowing_sub(other).0
+    }
+
+    /// Saturating multiplication. Clamps to `MAX` or `-MAX` instead of overflowing.
+    #[inline(always)]
+    pub fn saturating_mul(&self, other: &Self) -> Self {
+        self.overflowing_mul(other).0
+    }
+
+    /// Saturating division. Clamps to `MAX` or `-MAX` instead of overflowing.
+    ///
+    /// Unlike the primitive integers, division by zero also saturates rather than panicking:
+    /// `x / 0` clamps by the sign of `x`, and `0 / 0` returns `ZERO`.
+    #[inline(always)]
+    pub fn saturating_div(&self, other: &Self) -> Self {
+        self.overflowing_div(other).0
+    }
+
+    /// Addition returning `(result, overflowed)`. On overflow the result is clamped to `MAX` or `-MAX`.
+    #[inline(always)]
+    pub fn overflowing_add(&self, other: &Self) -> (Self, bool) {
+        match self.checked_add(other) {
+            Some(v) => (v, false),
+            // magnitudes only grow when signs agree, so the overflow takes self's sign
+            None => (Self::saturated(self.neg), true),
         }
     }
 
-    /// Checked addition. Returns `None` if the integer part overflows `u128`.
+    /// Subtraction returning `(result, overflowed)`. On overflow the result is clamped to `MAX` or `-MAX`.
     #[inline(always)]
-    pub fn checked_add(&self, other: &Self) -> Option<Self> {
-        let (a_frac, b_frac, scale, limit) = self.align_frac(other);
+    pub fn overflowing_sub(&self, other: &Self) -> (Self, bool) {
+        match self.checked_sub(other) {
+            Some(v) => (v, false),
+            None => (Self::saturated(self.neg), true),
+        }
+    }
 
-        if self.neg == other.neg {
-            let frac = a_frac + b_frac;
-            let overflow = (frac >= limit) as u128;
-            let int = self.int.checked_add(other.int)?.checked_add(overflow)?;
-            Some(Self {
-                int,
-                frac: frac - overflow * limit,
-                scale,
-                neg: self.neg,
-            })
-        } else {
-            Some(Self::sub_with_cmp(
-                self.int, a_frac, self.neg, other.int, b_frac, other.neg, scale, limit,
-            ))
+    /// Multiplication returning `(result, overflowed)`. On overflow the result is clamped to `MAX` or `-MAX`.
+    #[inline(always)]
+    pub fn overflowing_mul(&self, other: &Self) -> (Self, bool) {
+        match self.checked_mul(other) {
+            Some(v) => (v, false),
+            None => (Self::saturated(self.neg ^ other.neg), true),
         }
     }
 
-    /// Checked subtraction. Returns `None` if the integer part overflows `u128`.
+    /// Division returning `(result, overflowed)`. On overflow or division by zero the result
+    /// is clamped to `MAX` or `-MAX` (`0 / 0` gives `ZERO`) and the flag is `true`.
+    #[inline(always)]
+    pub fn overflowing_div(&self, other: &Self) -> (Self, bool) {
+        match self.checked_div(other) {
+            Some(v) => (v, false),
+            None if self.int == 0 && self.frac == 0 => (Self::ZERO, true),
+            None => (Self::saturated(self.neg ^ other.neg), true),
+        }
+    }
+}
+
+// ============ Integer Division ============
+impl AncDec128 {
+    /// Truncated integer division: `(self / other).trunc()` and `self % other` from a
+    /// single integer division.
     ///
-    /// In practice, subtraction in the current design uses magnitude comparison
-    /// and cannot overflow, so this always returns `Some`.
+    /// Panics on division by zero or if the quotient overflows `u128` (saturates with `panic-free`).
     #[inline(always)]
-    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
-        Some(self.sub(other))
+    pub fn divmod(&self, other: &Self) -> (Self, Self) {
+        self.divmod_or_saturate(other, RoundMode::Truncate)
     }
 
-    /// Checked multiplication. Returns `None` if the result overflows `u128` integer range.
+    /// Floored division: `(self / other).floor()`. Panics like [`divmod`](Self::divmod).
     #[inline(always)]
-    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
-        let neg = self.neg ^ other.neg;
-        let total_scale = self.scale + other.scale;
+    pub fn div_floor(&self, other: &Self) -> Self {
+        self.divmod_or_saturate(other, RoundMode::Floor).0
+    }
 
-        // Ultra-fast path: both fit in u64
-        if let (Some(a), Some(b)) = (
-            Self::try_combine_u64(self.int, self.frac, self.scale),
-            Self::try_combine_u64(other.int, other.frac, other.scale),
-        ) {
-            let product = a as u128 * b as u128;
-            if total_scale <= 19 && product <= u64::MAX as u128 {
-                let p = product as u64;
-                let d = pow10(total_scale);
-                return Some(Self { int: (p / d) as u128, frac: (p % d) as u128, scale: total_scale, neg });
-            }
-            if total_scale <= 38 {
-                let divisor = pow10_128(total_scale);
-                return Some(Self { int: product / divisor, frac: product % divisor, scale: total_scale, neg });
-            }
-            let reduced = product / pow10_128(total_scale - TARGET_SCALE_128);
-            return Some(Self { int: reduced / SCALE38, frac: reduced % SCALE38, scale: TARGET_SCALE_128, neg });
-        }
+    /// Floored remainder `self - other * self.div_floor(other)`, which takes the sign of `other`.
+    /// Panics on division by zero (returns `ZERO` with `panic-free`).
+    #[inline(always)]
+    pub fn mod_floor(&self, other: &Self) -> Self {
+        self.rem_or_zero(other, RoundMode::Floor)
+    }
 
-        // Partial product path: all parts fit in u64
-        if self.scale <= 19 && other.scale <= 19
-            && self.int <= u64::MAX as u128 && self.frac <= u64::MAX as u128
-            && other.int <= u64::MAX as u128 && other.frac <= u64::MAX as u128
-        {
-            let ai = self.int as u64;
-            let af = self.frac as u64;
-            let bi = other.int as u64;
-            let bf = other.frac as u64;
+    /// Euclidean division: the integer `q` with `self = q * other + r` and `0 <= r < |other|`.
+    /// Panics like [`divmod`](Self::divmod).
+    #[inline(always)]
+    pub fn div_euclid(&self, other: &Self) -> Self {
+        self.divmod_or_saturate(other, Self::euclid_mode(other)).0
+    }
 
-            let ii = ai as u128 * bi as u128;
-            let if_ = ai as u128 * bf as u128;
-            let fi = af as u128 * bi as u128;
-            let ff = af as u128 * bf as u128;
+    /// Euclidean remainder, always in `0 <= r < |other|`.
+    /// Panics on division by zero (returns `ZERO` with `panic-free`).
+    #[inline(always)]
+    pub fn rem_euclid(&self, other: &Self) -> Self {
+        self.rem_or_zero(other, Self::euclid_mode(other))
+    }
 
-            let sa_pow = pow10_128(self.scale);
-            let sb_pow = pow10_128(other.scale);
-            let scale_pow = pow10_128(total_scale);
+    /// Checked [`divmod`](Self::divmod). Returns `None` if `other` is zero or the quotient overflows `u128`.
+    #[inline(always)]
+    pub fn checked_divmod(&self, other: &Self) -> Option<(Self, Self)> {
+        self.try_int_divmod(other, RoundMode::Truncate).ok()
+    }
 
-            let if_int = if_ / sb_pow;
-            let if_frac = (if_ % sb_pow) * sa_pow;
+    /// Checked [`div_floor`](Self::div_floor). Returns `None` if `other` is zero or the quotient overflows `u128`.
+    #[inline(always)]
+    pub fn checked_div_floor(&self, other: &Self) -> Option<Self> {
+        self.try_int_divmod(other, RoundMode::Floor).ok().map(|(q, _)| q)
+    }
 
-            let fi_int = fi / sa_pow;
-            let fi_frac = (fi % sa_pow) * sb_pow;
+    /// Checked [`mod_floor`](Self::mod_floor). Returns `None` if `other` is zero.
+    #[inline(always)]
+    pub fn checked_mod_floor(&self, other: &Self) -> Option<Self> {
+        self.int_divmod(other, RoundMode::Floor).map(|(_, _, r)| r)
+    }
 
-            let frac_sum = if_frac + fi_frac + ff;
-            let (frac, frac_carry) = if frac_sum >= scale_pow {
-                let f = frac_sum - scale_pow;
-                if f >= scale_pow { (f - scale_pow, 2u128) } else { (f, 1u128) }
-            } else {
-                (frac_sum, 0u128)
-            };
+    /// Checked [`div_euclid`](Self::div_euclid). Returns `None` if `other` is zero or the quotient overflows `u128`.
+    #[inline(always)]
+    pub fn checked_div_euclid(&self, other: &Self) -> Option<Self> {
+        self.try_int_divmod(other, Self::euclid_mode(other)).ok().map(|(q, _)| q)
+    }
 
-            let int = ii.checked_add(if_int)
-                .and_then(|v| v.checked_add(fi_int))
-                .and_then(|v| v.checked_add(frac_carry))?;
-            return Some(Self { int, frac, scale: total_scale, neg });
-        }
+    /// Checked [`rem_euclid`](Self::rem_euclid). Returns `None` if `other` is zero.
+    #[inline(always)]
+    pub fn checked_rem_euclid(&self, other: &Self) -> Option<Self> {
+        self.int_divmod(other, Self::euclid_mode(other)).map(|(_, _, r)| r)
+    }
 
-        // Fast path: both fit in u128
-        if let (Some(a), Some(b)) = (
-            Self::try_combine_u128(self.int, self.frac, self.scale),
-            Self::try_combine_u128(other.int, other.frac, other.scale),
-        ) {
-            let (high, low) = mul_wide(a, b);
-            if total_scale > TARGET_SCALE_128 {
-                let divisor = pow10_128(total_scale - TARGET_SCALE_128);
-                let ((q_hi, q_lo), _) = divmod_u256(high, low, divisor);
-                return Self::checked_from_combined((q_hi, q_lo), TARGET_SCALE_128, neg);
-            }
-            return Self::checked_from_combined((high, low), total_scale, neg);
-        }
+    /// Truncated integer quotient as `i128`, with the exact remainder.
+    /// Returns `None` if `other` is zero or the quotient is outside `i128` range.
+    #[inline(always)]
+    pub fn divmod_i128(&self, other: &Self) -> Option<(i128, Self)> {
+        let (q, neg, r) = self.int_divmod(other, RoundMode::Truncate)?;
+        Some((signed_i128_wide(q, neg)?, r))
+    }
 
-        // Slow path: full u256 * u256 -> u512
-        let a = Self::combine(self.int, self.frac, self.scale);
-        let b = Self::combine(other.int, other.frac, other.scale);
+    /// Floored integer quotient as `i128`. Returns `None` if `other` is zero or the quotient is outside `i128` range.
+    #[inline(always)]
+    pub fn div_floor_i128(&self, other: &Self) -> Option<i128> {
+        let (q, neg, _) = self.int_divmod(other, RoundMode::Floor)?;
+        signed_i128_wide(q, neg)
+    }
 
-        let (w3, w2, w1, w0) = mul_u256(a, b);
+    /// Euclidean integer quotient as `i128`. Returns `None` if `other` is zero or the quotient is outside `i128` range.
+    #[inline(always)]
+    pub fn div_euclid_i128(&self, other: &Self) -> Option<i128> {
+        let (q, neg, _) = self.int_divmod(other, Self::euclid_mode(other))?;
+        signed_i128_wide(q, neg)
+    }
 
-        let (result, final_scale) = if total_scale > TARGET_SCALE_128 {
-            let divisor = pow10_128(total_scale - TARGET_SCALE_128);
-            (div_u512_by_u128(w3, w2, w1, w0, divisor), TARGET_SCALE_128)
-        } else if w3 == 0 && w2 == 0 {
-            ((w1, w0), total_scale)
+    /// The Euclidean quotient rounds down for positive divisors and up for negative ones.
+    #[inline(always)]
+    fn euclid_mode(other: &Self) -> RoundMode {
+        if other.neg {
+            RoundMode::Ceil
         } else {
-            return None;
-        };
+            RoundMode::Floor
+        }
+    }
 
-        Self::checked_from_combined(result, final_scale, neg)
+    /// `int_divmod` with the quotient converted to a decimal.
+    #[inline(always)]
+    fn try_int_divmod(&self, other: &Self, mode: RoundMode) -> Result<(Self, Self), ArithmeticError> {
+        let (q, neg, r) = self.int_divmod(other, mode).ok_or(ArithmeticError::DivisionByZero)?;
+        let q = Self::checked_from_combined(q, 0, neg).ok_or(ArithmeticError::Overflow)?;
+        Ok((q, r))
     }
 
-    /// Like `from_combined` but returns `None` instead of panicking on overflow.
+    /// Panicking `try_int_divmod`. With `panic-free` an overflowing quotient clamps to the
+    /// integer part of `MAX` and keeps the exact remainder; division by zero falls back like `div`/`rem`.
     #[inline(always)]
-    fn checked_from_combined(n: (u128, u128), scale: u8, neg: bool) -> Option<Self> {
-        if scale == 0 {
-            if n.0 != 0 {
-                return None;
-            }
-            return Some(Self {
-                int: n.1,
-                frac: 0,
-                scale: 0,
-                neg,
-            });
-        }
-        let divisor = pow10_128(scale);
-        let ((q_hi, q), r) = divmod_u256(n.0, n.1, divisor);
-        if q_hi != 0 {
-            return None;
-        }
-        Some(Self {
-            int: q,
-            frac: r,
-            scale,
-            neg,
+    fn divmod_or_saturate(&self, other: &Self, mode: RoundMode) -> (Self, Self) {
+        unwrap_or_saturate(self.try_int_divmod(other, mode), || match self.int_divmod(other, mode) {
+            Some((_, neg, r)) => (Self::saturated(neg).trunc(), r),
+            None => (self.saturating_div(other).trunc(), Self::ZERO),
         })
     }
 
-    /// Computes the remainder (`self % other`), panics on division by zero.
+    /// Remainder of `int_divmod`, which never overflows. Panics on division by zero
+    /// (returns `ZERO` with `panic-free`).
     #[inline(always)]
-    pub fn rem(&self, other: &Self) -> Self {
-        assert!(other.int != 0 || other.frac != 0, "division by zero");
-        let q = self.div(other);
-        let floored = Self {
-            int: q.int,
-            frac: 0,
-            scale: 0,
-            neg: q.neg,
+    fn rem_or_zero(&self, other: &Self, mode: RoundMode) -> Self {
+        let r = self.int_divmod(other, mode).map(|(_, _, r)| r);
+        unwrap_or_saturate(r.ok_or(ArithmeticError::DivisionByZero), || Self::ZERO)
+    }
+
+    /// Divides the magnitudes aligned to their common scale. Returns the integer quotient
+    /// magnitude rounded by `mode` (`Fract` truncates), its sign, and the exact remainder
+    /// `self - q * other`. `None` if `other` is zero.
+    #[inline(always)]
+    pub(crate) fn int_divmod(&self, other: &Self, mode: RoundMode) -> Option<((u128, u128), bool, Self)> {
+        if other.is_zero() {
+            return None;
+        }
+        let scale = self.scale.max(other.scale);
+        // frac * 10^(scale - self.scale) < 10^38, and the combined u256 stays below 2^255
+        let a = Self::combine(self.int, self.frac * pow10_128(scale - self.scale), scale);
+        let b = Self::combine(other.int, other.frac * pow10_128(scale - other.scale), scale);
+        let ((_, _, q_hi, q_lo), r) = divmod_u512((0, 0, a.0, a.1), b);
+
+        // rounding the quotient one step away from zero leaves `b - r`, with the sign opposite
+        // to `self`
+        let neg = self.neg ^ other.neg;
+        let up = mode.rounds_up(Residue::new_wide(r, b), neg, mod10_u256((q_hi, q_lo)));
+        let (q, r) = if up {
+            let (lo, carry) = q_lo.overflowing_add(1);
+            let (r_lo, borrow) = b.1.overflowing_sub(r.1);
+            ((q_hi + carry as u128, lo), (b.0 - r.0 - borrow as u128, r_lo))
+        } else {
+            ((q_hi, q_lo), r)
         };
-        self.sub(&floored.mul(other))
+        let rem = Self::checked_from_combined(r, scale, (self.neg ^ up) && r != (0, 0))?;
+        Some((q, neg && q != (0, 0), rem))
+    }
+}
+
+// ============ Exact Arithmetic ============
+impl AncDec128 {
+    /// Addition that never alters the value. Sums always fit the wider operand scale, so
+    /// this fails only with `ArithmeticError::Overflow`, like [`try_add`](Self::try_add).
+    #[inline(always)]
+    pub fn add_exact(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        self.try_add(other)
+    }
+
+    /// Subtraction that never alters the value; see [`add_exact`](Self::add_exact).
+    #[inline(always)]
+    pub fn sub_exact(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        self.try_sub(other)
+    }
+
+    /// Multiplication that fails with `ArithmeticError::Inexact` instead of truncating
+    /// nonzero digits beyond scale 38. `ArithmeticError::Overflow` if the integer part
+    /// overflows `u128`.
+    pub fn mul_exact(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        match self.mul_round_exact(other, TARGET_SCALE_128, RoundMode::Truncate) {
+            Some((p, true)) => Ok(p),
+            Some((_, false)) => Err(ArithmeticError::Inexact),
+            None => Err(ArithmeticError::Overflow),
+        }
+    }
+
+    /// Division that fails with `ArithmeticError::Inexact` unless the quotient terminates
+    /// within 38 places; exact quotients keep their minimal scale (`1 / 4` gives `0.25`).
+    /// `ArithmeticError::DivisionByZero` if `other` is zero, `ArithmeticError::Overflow`
+    /// if the quotient overflows `u128`.
+    pub fn div_exact(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        if other.is_zero() {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        match self.div_round_exact(other, TARGET_SCALE_128, RoundMode::Truncate) {
+            Some((q, true)) => Ok(q.normalize()),
+            Some((_, false)) => Err(ArithmeticError::Inexact),
+            None => Err(ArithmeticError::Overflow),
+        }
     }
 }
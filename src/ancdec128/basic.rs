@@ -1,6 +1,10 @@
+use crate::error::{unwrap_or_saturate, ArithmeticError};
+use crate::fixed::{self, Fixed};
 use super::AncDec128;
-use crate::util::{pow10_128, pow10_256};
-use crate::wide::{divmod_u256, isqrt_u512, mul_u256, mul_wide};
+use crate::round_mode::Residue;
+use crate::util::{pow10_128, pow10_256, TARGET_SCALE_128};
+use crate::wide::{divmod_u256, isqrt_u512, mod10_u256, mul_u256, mul_wide, nth_root_decimal, pow_decimal};
+use crate::RoundMode;
 
 impl AncDec128 {
     /// Returns the absolute value.
@@ -47,6 +51,12 @@ impl AncDec128 {
         self.int == 0 && self.frac == 0
     }
 
+    /// Returns `true` if the value has no fractional part (`2.00` is an integer).
+    #[inline(always)]
+    pub fn is_integer(&self) -> bool {
+        self.frac == 0
+    }
+
     /// Returns the smaller of `self` and `other`.
     #[inline(always)]
     pub fn min(self, other: Self) -> Self {
@@ -79,11 +89,53 @@ impl AncDec128 {
         }
     }
 
-    /// Returns the square root with 37 fractional digits of precision. Panics if negative.
+    /// Unit in the last place at the value's own scale: `10^-scale` (`1.25` gives `0.01`,
+    /// `3` gives `1`). Always positive. `next_up`/`next_down` step by `EPSILON` instead.
+    #[inline(always)]
+    pub fn ulp(&self) -> Self {
+        if self.scale == 0 {
+            return Self::ONE;
+        }
+        Self {
+            int: 0,
+            frac: 1,
+            scale: self.scale,
+            neg: false,
+        }
+    }
+
+    /// The next representable value above `self`: `self + EPSILON` at scale 38, whatever the
+    /// scale of `self`. Not `self + self.ulp()`, which steps at the value's own scale.
+    ///
+    /// Panics at `MAX` (saturates with `panic-free`).
+    #[inline(always)]
+    pub fn next_up(&self) -> Self {
+        *self + Self::EPSILON
+    }
+
+    /// The next representable value below `self`: `self - EPSILON` at scale 38, whatever the
+    /// scale of `self`. Not `self - self.ulp()`, which steps at the value's own scale.
+    ///
+    /// Panics at `MIN` (saturates with `panic-free`).
+    #[inline(always)]
+    pub fn next_down(&self) -> Self {
+        *self - Self::EPSILON
+    }
+
+    /// Returns the square root with 37 fractional digits of precision. Panics if negative
+    /// (returns `ZERO` with `panic-free`).
+    #[inline(always)]
     pub fn sqrt(&self) -> Self {
-        assert!(!self.neg || self.is_zero(), "square root of negative number");
+        unwrap_or_saturate(self.try_sqrt(), || Self::ZERO)
+    }
+
+    /// Fallible square root. Returns `ArithmeticError::NegativeSqrt` if `self` is negative.
+    pub fn try_sqrt(&self) -> Result<Self, ArithmeticError> {
+        if self.neg && !self.is_zero() {
+            return Err(ArithmeticError::NegativeSqrt);
+        }
         if self.is_zero() {
-            return Self::ZERO;
+            return Ok(Self::ZERO);
         }
 
         // combined = int * 10^scale + frac as u256
@@ -99,31 +151,584 @@ impl AncDec128 {
 
         let scale37 = pow10_128(37);
         let ((_, q), r) = divmod_u256(r_hi, r_lo, scale37);
-        Self { int: q, frac: r, scale: 37, neg: false }
+        Ok(Self { int: q, frac: r, scale: 37, neg: false })
+    }
+
+    /// Checked square root. Returns `None` if `self` is negative.
+    #[inline(always)]
+    pub fn checked_sqrt(&self) -> Option<Self> {
+        self.try_sqrt().ok()
+    }
+
+    /// Square root rounded once to `scale` decimal places (capped at 38) with `mode`. The
+    /// last digit is decided exactly from the integer square root's remainder, so the result
+    /// is correctly rounded; it always has `scale` decimal places. `RoundMode::Fract` truncates.
+    ///
+    /// Panics if `self` is negative (returns `ZERO` with `panic-free`).
+    #[inline(always)]
+    pub fn sqrt_round(&self, scale: u8, mode: RoundMode) -> Self {
+        unwrap_or_saturate(
+            self.checked_sqrt_round(scale, mode).ok_or(ArithmeticError::NegativeSqrt),
+            || Self::ZERO,
+        )
+    }
+
+    /// Checked [`sqrt_round`](Self::sqrt_round). Returns `None` if `self` is negative.
+    pub fn checked_sqrt_round(&self, scale: u8, mode: RoundMode) -> Option<Self> {
+        if self.neg && !self.is_zero() {
+            return None;
+        }
+        let scale = scale.min(TARGET_SCALE_128);
+        // the smallest working scale at which n = value * 10^(2 * work) is an integer
+        let work = scale.max(self.scale.div_ceil(2));
+        let combined = Self::combine(self.int, self.frac, self.scale);
+        let (w3, w2, w1, w0) = mul_u256(combined, pow10_256(2 * work - self.scale));
+        let x = isqrt_u512(w3, w2, w1, w0);
+        // r = n - x^2 <= 2x fits u256, so the low 256 bits subtract exactly
+        let (_, _, s1, s0) = mul_u256(x, x);
+        let (r_lo, borrow) = w0.overflowing_sub(s0);
+        let r = (w1.wrapping_sub(s1).wrapping_sub(borrow as u128), r_lo);
+
+        let (q, residue) = if work == scale {
+            // (2x + 1)^2 is odd, so sqrt(n) is never exactly x + 1/2: above half iff r > x
+            let residue = match r {
+                (0, 0) => Residue::Zero,
+                r if r > x => Residue::AboveHalf,
+                _ => Residue::BelowHalf,
+            };
+            (x, residue)
+        } else {
+            let d = pow10_128(work - scale);
+            let (q, rem) = divmod_u256(x.0, x.1, d);
+            (q, Residue::new(rem, d).with_sticky(r != (0, 0)))
+        };
+        let q = if mode.rounds_up(residue, false, mod10_u256(q)) {
+            let (lo, carry) = q.1.overflowing_add(1);
+            (q.0 + carry as u128, lo)
+        } else {
+            q
+        };
+        Self::checked_from_combined(q, scale, false)
+    }
+
+    /// Returns the cube root with 37 fractional digits of precision, truncated like
+    /// [`sqrt`](Self::sqrt). Negative numbers have negative cube roots.
+    #[inline(always)]
+    pub fn cbrt(&self) -> Self {
+        // odd roots exist for every input and never exceed it in magnitude
+        self.checked_nth_root(3).unwrap_or(Self::ZERO)
+    }
+
+    /// Returns the `n`-th root with 37 fractional digits of precision, truncated like
+    /// [`sqrt`](Self::sqrt): the digits are exact, taken from an integer k-th root of the
+    /// scaled value. Odd roots of negative numbers are negative. Above `n = 31` the
+    /// result keeps fewer fractional digits, as many as the 4096-bit working width allows.
+    ///
+    /// Panics if `n` is zero, or `n` is even and `self` negative (returns `ZERO` with `panic-free`).
+    #[inline(always)]
+    pub fn nth_root(&self, n: u32) -> Self {
+        unwrap_or_saturate(self.try_nth_root(n), || Self::ZERO)
+    }
+
+    /// Fallible `n`-th root. Returns `ArithmeticError::OutOfDomain` if `n` is zero,
+    /// `ArithmeticError::NegativeSqrt` if `n` is even and `self` negative.
+    pub fn try_nth_root(&self, n: u32) -> Result<Self, ArithmeticError> {
+        if n == 0 {
+            return Err(ArithmeticError::OutOfDomain);
+        }
+        if n % 2 == 0 && self.is_negative() {
+            return Err(ArithmeticError::NegativeSqrt);
+        }
+        let combined = Self::combine(self.int, self.frac, self.scale);
+        let (root, scale) = nth_root_decimal(combined, self.scale, n, 37);
+        Self::checked_from_combined(root, scale, self.is_negative()).ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Checked `n`-th root. Returns `None` if `n` is zero, or `n` is even and `self` negative.
+    #[inline(always)]
+    pub fn checked_nth_root(&self, n: u32) -> Option<Self> {
+        self.try_nth_root(n).ok()
     }
 
     /// Raises `self` to the power `n` using binary exponentiation. Supports negative exponents.
+    ///
+    /// Panics if `self` is zero and `n` is negative, or if the result overflows
+    /// (saturates with `panic-free`, see [`saturating_pow`](Self::saturating_pow)).
+    #[inline(always)]
     pub fn pow(&self, n: i32) -> Self {
+        unwrap_or_saturate(self.try_pow(n), || self.saturating_pow(n))
+    }
+
+    /// Fallible exponentiation. Returns `ArithmeticError::DivisionByZero` if `self` is zero and
+    /// `n` is negative, `ArithmeticError::Overflow` if the result overflows.
+    #[inline(always)]
+    pub fn try_pow(&self, n: i32) -> Result<Self, ArithmeticError> {
+        if n < 0 && self.is_zero() {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        self.checked_pow(n).ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Checked exponentiation. Returns `None` on overflow, or if `self` is zero and `n` is negative.
+    #[inline(always)]
+    pub fn checked_pow(&self, n: i32) -> Option<Self> {
+        self.checked_powi(n as i64)
+    }
+
+    /// Raises `self` to a 64-bit integer power, like [`pow`](Self::pow).
+    ///
+    /// Panics if `self` is zero and `n` is negative, or if the result overflows
+    /// (saturates with `panic-free`).
+    #[inline(always)]
+    pub fn powi(&self, n: i64) -> Self {
+        unwrap_or_saturate(self.try_powi(n), || {
+            Self::saturated(self.is_negative() && n % 2 != 0)
+        })
+    }
+
+    /// Fallible [`powi`](Self::powi). Returns `ArithmeticError::DivisionByZero` if `self` is
+    /// zero and `n` is negative, `ArithmeticError::Overflow` if the result overflows.
+    #[inline(always)]
+    pub fn try_powi(&self, n: i64) -> Result<Self, ArithmeticError> {
+        if n < 0 && self.is_zero() {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        self.checked_powi(n).ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Checked exponentiation with a 64-bit exponent. Returns `None` on overflow, or if `self`
+    /// is zero and `n` is negative.
+    ///
+    /// Stops at the first overflowing step, and as soon as the running power truncates to
+    /// zero, so any exponent costs at most 63 squarings.
+    pub fn checked_powi(&self, n: i64) -> Option<Self> {
         if n == 0 {
-            return Self::ONE;
+            return Some(Self::ONE);
         }
 
         let mut base = if n < 0 {
-            assert!(!self.is_zero(), "division by zero in pow with negative exponent");
-            Self::ONE.div(self)
+            Self::ONE.checked_div(self)?
         } else {
             *self
         };
         let mut exp = n.unsigned_abs();
         let mut result = Self::ONE;
 
-        while exp > 0 {
+        loop {
             if exp % 2 == 1 {
-                result = result.mul(&base);
+                result = result.checked_mul(&base)?;
             }
-            base = base.mul(&base);
             exp /= 2;
+            if exp == 0 {
+                return Some(result);
+            }
+            // A remaining bit multiplies in a power of zero
+            if base.is_zero() {
+                return Some(Self::ZERO);
+            }
+            // Square only when another bit remains, so the final step cannot overflow spuriously
+            base = base.checked_mul(&base)?;
+        }
+    }
+
+    /// Saturating exponentiation. Clamps to `MAX` or `-MAX` instead of overflowing.
+    ///
+    /// Zero raised to a negative power saturates to `MAX`.
+    #[inline(always)]
+    pub fn saturating_pow(&self, n: i32) -> Self {
+        self.overflowing_pow(n).0
+    }
+
+    /// Exponentiation returning `(result, overflowed)`. On overflow the result is clamped to
+    /// `MAX` or `-MAX`, with the sign the exact power would have had.
+    pub fn overflowing_pow(&self, n: i32) -> (Self, bool) {
+        match self.checked_pow(n) {
+            Some(v) => (v, false),
+            None => (Self::saturated(self.is_negative() && n % 2 != 0), true),
+        }
+    }
+}
+
+// ============ Exponential and Logarithm ============
+impl AncDec128 {
+    /// Rebuilds a result of the fixed-point engine (rounded at scale 38), trailing zeros stripped
+    #[inline(always)]
+    fn from_fixed(r: Option<((u128, u128), bool)>) -> Option<Self> {
+        let (n, neg) = r?;
+        Self::checked_from_combined(n, TARGET_SCALE_128, neg).map(|v| v.normalize())
+    }
+
+    /// `ln(self)` in the fixed-point engine. `None` if `self` is not positive.
+    #[inline(always)]
+    fn ln_fixed(&self) -> Option<Fixed> {
+        if self.neg || self.is_zero() {
+            return None;
+        }
+        Some(fixed::ln_decimal(Self::combine(self.int, self.frac, self.scale), self.scale))
+    }
+
+    /// Returns `e^self`, rounded half-even to 38 decimal places.
+    ///
+    /// Panics if the result overflows (returns `MAX` with `panic-free`).
+    #[inline(always)]
+    pub fn exp(&self) -> Self {
+        unwrap_or_saturate(self.try_exp(), || Self::MAX)
+    }
+
+    /// Fallible exponential. Returns `ArithmeticError::Overflow` if the result overflows.
+    #[inline(always)]
+    pub fn try_exp(&self) -> Result<Self, ArithmeticError> {
+        self.checked_exp().ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Checked exponential. Returns `None` on overflow.
+    pub fn checked_exp(&self) -> Option<Self> {
+        let combined = Self::combine(self.int, self.frac, self.scale);
+        Self::from_fixed(fixed::exp_decimal(combined, self.scale, self.neg, TARGET_SCALE_128))
+    }
+
+    /// Returns the natural logarithm, rounded half-even to 38 decimal places.
+    ///
+    /// Panics if `self` is not positive (returns `ZERO` with `panic-free`).
+    #[inline(always)]
+    pub fn ln(&self) -> Self {
+        unwrap_or_saturate(self.try_ln(), || Self::ZERO)
+    }
+
+    /// Fallible natural logarithm. Returns `ArithmeticError::OutOfDomain` if `self` is not positive.
+    #[inline(always)]
+    pub fn try_ln(&self) -> Result<Self, ArithmeticError> {
+        self.checked_ln().ok_or(ArithmeticError::OutOfDomain)
+    }
+
+    /// Checked natural logarithm. Returns `None` if `self` is not positive.
+    pub fn checked_ln(&self) -> Option<Self> {
+        Self::from_fixed(self.ln_fixed()?.to_decimal(0, TARGET_SCALE_128))
+    }
+
+    /// Returns the base-10 logarithm, rounded half-even to 38 decimal places.
+    ///
+    /// Panics if `self` is not positive (returns `ZERO` with `panic-free`).
+    #[inline(always)]
+    pub fn log10(&self) -> Self {
+        unwrap_or_saturate(self.try_log10(), || Self::ZERO)
+    }
+
+    /// Fallible base-10 logarithm. Returns `ArithmeticError::OutOfDomain` if `self` is not positive.
+    #[inline(always)]
+    pub fn try_log10(&self) -> Result<Self, ArithmeticError> {
+        self.checked_log10().ok_or(ArithmeticError::OutOfDomain)
+    }
+
+    /// Checked base-10 logarithm. Returns `None` if `self` is not positive.
+    pub fn checked_log10(&self) -> Option<Self> {
+        Self::from_fixed(self.ln_fixed()?.mul(fixed::INV_LN10).to_decimal(0, TARGET_SCALE_128))
+    }
+
+    /// Returns the base-2 logarithm, rounded half-even to 38 decimal places.
+    ///
+    /// Panics if `self` is not positive (returns `ZERO` with `panic-free`).
+    #[inline(always)]
+    pub fn log2(&self) -> Self {
+        unwrap_or_saturate(self.try_log2(), || Self::ZERO)
+    }
+
+    /// Fallible base-2 logarithm. Returns `ArithmeticError::OutOfDomain` if `self` is not positive.
+    #[inline(always)]
+    pub fn try_log2(&self) -> Result<Self, ArithmeticError> {
+        self.checked_log2().ok_or(ArithmeticError::OutOfDomain)
+    }
+
+    /// Checked base-2 logarithm. Returns `None` if `self` is not positive.
+    pub fn checked_log2(&self) -> Option<Self> {
+        Self::from_fixed(self.ln_fixed()?.mul(fixed::INV_LN2).to_decimal(0, TARGET_SCALE_128))
+    }
+
+    /// Returns the logarithm to `base`, rounded half-even to 38 decimal places.
+    ///
+    /// Panics if `self` or `base` is not positive, `base` is one, or the result overflows
+    /// (a base very close to one). With `panic-free` an out-of-domain input returns `ZERO`
+    /// and an overflow saturates.
+    #[inline(always)]
+    pub fn log(&self, base: &Self) -> Self {
+        unwrap_or_saturate(self.try_log(base), || match (self.ln_fixed(), base.ln_fixed()) {
+            (Some(x), Some(b)) if !b.is_zero() => Self::saturated(x.is_neg() != b.is_neg()),
+            _ => Self::ZERO,
+        })
+    }
+
+    /// Fallible logarithm to `base`. Returns `ArithmeticError::OutOfDomain` if `self` or `base`
+    /// is not positive or `base` is one, `ArithmeticError::Overflow` if the result overflows.
+    pub fn try_log(&self, base: &Self) -> Result<Self, ArithmeticError> {
+        let (Some(x), Some(b)) = (self.ln_fixed(), base.ln_fixed()) else {
+            return Err(ArithmeticError::OutOfDomain);
+        };
+        if *base == Self::ONE {
+            return Err(ArithmeticError::OutOfDomain);
+        }
+        let combined = Self::combine(base.int, base.frac, base.scale);
+        Self::from_fixed(fixed::log_decimal(x, b, combined, base.scale, TARGET_SCALE_128)).ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Checked logarithm to `base`. Returns `None` if `self` or `base` is not positive, `base`
+    /// is one, or the result overflows.
+    #[inline(always)]
+    pub fn checked_log(&self, base: &Self) -> Option<Self> {
+        self.try_log(base).ok()
+    }
+
+    /// Raises `self` to a decimal power, rounded half-even to 38 decimal places. Integer
+    /// exponents round the exact power once (unlike [`powi`](Self::powi), which truncates at
+    /// every step) while it fits a 4096-bit intermediate; other exponents, and integer powers
+    /// past that width, are computed as `e^(exponent * ln(self))`.
+    ///
+    /// Panics if `self` is negative and `exponent` is not an integer, if `self` is zero and
+    /// `exponent` is negative, or if the result overflows. With `panic-free` these return
+    /// `ZERO`, `MAX` and the saturated value.
+    pub fn powd(&self, exponent: &Self) -> Self {
+        let r = self.try_powd(exponent);
+        let fallback = match r {
+            Err(ArithmeticError::OutOfDomain) => Self::ZERO,
+            _ => Self::saturated(self.is_negative() && exponent.is_integer() && exponent.int % 2 == 1),
+        };
+        unwrap_or_saturate(r, || fallback)
+    }
+
+    /// Fallible [`powd`](Self::powd). Returns `ArithmeticError::OutOfDomain` if `self` is
+    /// negative and `exponent` is not an integer, `ArithmeticError::DivisionByZero` if `self`
+    /// is zero and `exponent` is negative, `ArithmeticError::Overflow` if the result overflows.
+    pub fn try_powd(&self, exponent: &Self) -> Result<Self, ArithmeticError> {
+        if exponent.is_zero() {
+            return Ok(Self::ONE);
+        }
+        if self.is_negative() && !exponent.is_integer() {
+            return Err(ArithmeticError::OutOfDomain);
+        }
+        if self.is_zero() {
+            return match exponent.neg {
+                true => Err(ArithmeticError::DivisionByZero),
+                false => Ok(Self::ZERO),
+            };
+        }
+
+        // Only integer exponents reach here with a negative base
+        let neg = self.is_negative() && exponent.int % 2 == 1;
+        if self.abs() == Self::ONE {
+            return Ok(if neg { -Self::ONE } else { Self::ONE });
+        }
+        if exponent.is_integer() {
+            let exact = u64::try_from(exponent.int).ok().and_then(|n| {
+                pow_decimal(Self::combine(self.int, self.frac, self.scale), self.scale, n, exponent.neg, TARGET_SCALE_128)
+            });
+            if let Some(n) = exact {
+                return Self::from_fixed(Some((n, neg && n != (0, 0)))).ok_or(ArithmeticError::Overflow);
+            }
+        }
+        let ln = self.abs().ln_fixed().ok_or(ArithmeticError::OutOfDomain)?;
+        let combined = Self::combine(exponent.int, exponent.frac, exponent.scale);
+        let r = match Fixed::from_decimal(combined, exponent.scale, exponent.neg)
+            .and_then(|y| y.checked_mul(ln))
+        {
+            Some(t) => fixed::exp_to_decimal(t, TARGET_SCALE_128),
+            // |exponent * ln(self)| >= 2^64
+            None if exponent.neg != ln.is_neg() => Some(((0, 0), false)),
+            None => None,
+        };
+        Self::from_fixed(r.map(|(n, _)| (n, neg && n != (0, 0)))).ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Checked [`powd`](Self::powd). Returns `None` if `try_powd` would fail.
+    #[inline(always)]
+    pub fn checked_powd(&self, exponent: &Self) -> Option<Self> {
+        self.try_powd(exponent).ok()
+    }
+}
+
+// ============ Trigonometric and Hyperbolic ============
+impl AncDec128 {
+    /// Rounds an engine value known to fit (an angle or a normal quantile, `|v| <= 16`) to the
+    /// maximum scale
+    #[inline(always)]
+    fn from_bounded(v: Fixed) -> Self {
+        Self::from_fixed(v.to_decimal(0, TARGET_SCALE_128)).unwrap_or(Self::ZERO)
+    }
+
+    /// Returns the sine of `self` (in radians), rounded half-even to 38 decimal places.
+    pub fn sin(&self) -> Self {
+        Self::from_bounded(fixed::sin_decimal(Self::combine(self.int, self.frac, self.scale), self.scale, self.neg))
+    }
+
+    /// Returns the cosine of `self` (in radians), rounded half-even to 38 decimal places.
+    pub fn cos(&self) -> Self {
+        Self::from_bounded(fixed::cos_decimal(Self::combine(self.int, self.frac, self.scale), self.scale))
+    }
+
+    /// Returns the tangent of `self` (in radians), rounded half-even to 38 decimal places.
+    ///
+    /// Panics if the result overflows (saturates with `panic-free`).
+    #[inline(always)]
+    pub fn tan(&self) -> Self {
+        unwrap_or_saturate(self.try_tan(), || {
+            Self::saturated(self.sin().is_negative() != self.cos().is_negative())
+        })
+    }
+
+    /// Fallible tangent. Returns `ArithmeticError::Overflow` if the result overflows.
+    #[inline(always)]
+    pub fn try_tan(&self) -> Result<Self, ArithmeticError> {
+        self.checked_tan().ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Checked tangent. Returns `None` on overflow.
+    pub fn checked_tan(&self) -> Option<Self> {
+        Self::from_fixed(fixed::tan_decimal(Self::combine(self.int, self.frac, self.scale), self.scale, self.neg, TARGET_SCALE_128))
+    }
+
+    /// Returns the arcsine in radians, in `[-π/2, π/2]`, rounded half-even to 38 decimal places.
+    ///
+    /// Panics if `|self| > 1` (returns `ZERO` with `panic-free`).
+    #[inline(always)]
+    pub fn asin(&self) -> Self {
+        unwrap_or_saturate(self.try_asin(), || Self::ZERO)
+    }
+
+    /// Fallible arcsine. Returns `ArithmeticError::OutOfDomain` if `|self| > 1`.
+    #[inline(always)]
+    pub fn try_asin(&self) -> Result<Self, ArithmeticError> {
+        self.checked_asin().ok_or(ArithmeticError::OutOfDomain)
+    }
+
+    /// Checked arcsine. Returns `None` if `|self| > 1`.
+    pub fn checked_asin(&self) -> Option<Self> {
+        if self.abs() > Self::ONE {
+            return None;
+        }
+        let x = Fixed::from_decimal(Self::combine(self.int, self.frac, self.scale), self.scale, self.neg)?;
+        Some(Self::from_bounded(fixed::asin(x)))
+    }
+
+    /// Returns the arccosine in radians, in `[0, π]`, rounded half-even to 38 decimal places.
+    ///
+    /// Panics if `|self| > 1` (returns `ZERO` with `panic-free`).
+    #[inline(always)]
+    pub fn acos(&self) -> Self {
+        unwrap_or_saturate(self.try_acos(), || Self::ZERO)
+    }
+
+    /// Fallible arccosine. Returns `ArithmeticError::OutOfDomain` if `|self| > 1`.
+    #[inline(always)]
+    pub fn try_acos(&self) -> Result<Self, ArithmeticError> {
+        self.checked_acos().ok_or(ArithmeticError::OutOfDomain)
+    }
+
+    /// Checked arccosine. Returns `None` if `|self| > 1`.
+    pub fn checked_acos(&self) -> Option<Self> {
+        if self.abs() > Self::ONE {
+            return None;
+        }
+        let x = Fixed::from_decimal(Self::combine(self.int, self.frac, self.scale), self.scale, self.neg)?;
+        Some(Self::from_bounded(fixed::acos(x)))
+    }
+
+    /// Returns the arctangent in radians, in `(-π/2, π/2)`, rounded half-even to 38 decimal places.
+    #[inline(always)]
+    pub fn atan(&self) -> Self {
+        self.atan2(&Self::ONE)
+    }
+
+    /// Returns the angle of the point `(other, self)` in radians, in `(-π, π]`, like
+    /// `f64::atan2` with `self` as `y`. Rounded half-even to 38 decimal places;
+    /// `atan2` of two zeros is zero.
+    pub fn atan2(&self, other: &Self) -> Self {
+        let y = (Self::combine(self.int, self.frac, self.scale), self.scale, self.is_negative());
+        let x = (Self::combine(other.int, other.frac, other.scale), other.scale, other.is_negative());
+        Self::from_bounded(fixed::atan2_decimal(y, x))
+    }
+
+    /// Returns the hyperbolic sine, rounded half-even to 38 decimal places.
+    ///
+    /// Panics if the result overflows (saturates with `panic-free`).
+    #[inline(always)]
+    pub fn sinh(&self) -> Self {
+        unwrap_or_saturate(self.try_sinh(), || Self::saturated(self.is_negative()))
+    }
+
+    /// Fallible hyperbolic sine. Returns `ArithmeticError::Overflow` if the result overflows.
+    #[inline(always)]
+    pub fn try_sinh(&self) -> Result<Self, ArithmeticError> {
+        self.checked_sinh().ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Checked hyperbolic sine. Returns `None` on overflow.
+    pub fn checked_sinh(&self) -> Option<Self> {
+        Self::from_fixed(fixed::sinh_decimal(Self::combine(self.int, self.frac, self.scale), self.scale, self.neg, TARGET_SCALE_128))
+    }
+
+    /// Returns the hyperbolic cosine, rounded half-even to 38 decimal places.
+    ///
+    /// Panics if the result overflows (returns `MAX` with `panic-free`).
+    #[inline(always)]
+    pub fn cosh(&self) -> Self {
+        unwrap_or_saturate(self.try_cosh(), || Self::MAX)
+    }
+
+    /// Fallible hyperbolic cosine. Returns `ArithmeticError::Overflow` if the result overflows.
+    #[inline(always)]
+    pub fn try_cosh(&self) -> Result<Self, ArithmeticError> {
+        self.checked_cosh().ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Checked hyperbolic cosine. Returns `None` on overflow.
+    pub fn checked_cosh(&self) -> Option<Self> {
+        Self::from_fixed(fixed::cosh_decimal(Self::combine(self.int, self.frac, self.scale), self.scale, TARGET_SCALE_128))
+    }
+
+    /// Returns the hyperbolic tangent, rounded half-even to 38 decimal places.
+    pub fn tanh(&self) -> Self {
+        Self::from_fixed(fixed::tanh_decimal(Self::combine(self.int, self.frac, self.scale), self.scale, self.neg, TARGET_SCALE_128)).unwrap_or(Self::ZERO)
+    }
+}
+
+// ============ Error Function and Normal Distribution ============
+impl AncDec128 {
+    /// Returns the error function `erf(self)`, rounded half-even to 38 decimal places.
+    pub fn erf(&self) -> Self {
+        Self::from_fixed(fixed::erf_decimal(Self::combine(self.int, self.frac, self.scale), self.scale, self.neg, TARGET_SCALE_128)).unwrap_or(Self::ZERO)
+    }
+
+    /// Returns the complementary error function `1 - erf(self)`, rounded half-even to
+    /// 38 decimal places. Computed directly, so the tail keeps every digit `erf` rounds away.
+    pub fn erfc(&self) -> Self {
+        Self::from_fixed(fixed::erfc_decimal(Self::combine(self.int, self.frac, self.scale), self.scale, self.neg, TARGET_SCALE_128)).unwrap_or(Self::ZERO)
+    }
+
+    /// Returns the standard normal cumulative distribution function `Φ(self)`, the
+    /// probability that a standard normal variable is at most `self`, rounded half-even to
+    /// 38 decimal places.
+    pub fn norm_cdf(&self) -> Self {
+        Self::from_fixed(fixed::norm_cdf_decimal(Self::combine(self.int, self.frac, self.scale), self.scale, self.neg, TARGET_SCALE_128)).unwrap_or(Self::ZERO)
+    }
+
+    /// Returns the inverse standard normal CDF (quantile function): the `x` with
+    /// `Φ(x) = self`, rounded half-even to 38 decimal places.
+    ///
+    /// Panics unless `0 < self < 1` (returns `ZERO` with `panic-free`).
+    #[inline(always)]
+    pub fn norm_inv_cdf(&self) -> Self {
+        unwrap_or_saturate(self.try_norm_inv_cdf(), || Self::ZERO)
+    }
+
+    /// Fallible inverse normal CDF. Returns `ArithmeticError::OutOfDomain` unless `0 < self < 1`.
+    #[inline(always)]
+    pub fn try_norm_inv_cdf(&self) -> Result<Self, ArithmeticError> {
+        self.checked_norm_inv_cdf().ok_or(ArithmeticError::OutOfDomain)
+    }
+
+    /// Checked inverse normal CDF. Returns `None` unless `0 < self < 1`.
+    pub fn checked_norm_inv_cdf(&self) -> Option<Self> {
+        if self.neg || self.is_zero() || *self >= Self::ONE {
+            return None;
         }
-        result
+        Some(Self::from_bounded(fixed::norm_inv_cdf_decimal(Self::combine(self.int, self.frac, self.scale), self.scale)))
     }
 }
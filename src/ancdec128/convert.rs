@@ -1,5 +1,5 @@
 use super::AncDec128;
-use crate::error::ParseError;
+use crate::error::{unwrap_or_saturate, ArithmeticError, ParseError};
 use crate::util::pow10_128;
 use crate::util::StackBuf;
 use core::convert::TryFrom;
@@ -22,25 +22,47 @@ impl AncDec128 {
         }
     }
 
-    /// Converts to `i64`, truncating the fractional part. Panics on overflow.
+    /// Converts to `i64`, truncating the fractional part. Panics on overflow
+    /// (clamps to `i64::MIN`/`i64::MAX` with `panic-free`).
     pub fn to_i64(&self) -> i64 {
+        unwrap_or_saturate(self.try_to_i64(), || if self.neg { i64::MIN } else { i64::MAX })
+    }
+
+    /// Fallible conversion to `i64`, truncating the fractional part.
+    /// Returns `ArithmeticError::Overflow` if the integer part is out of range.
+    pub fn try_to_i64(&self) -> Result<i64, ArithmeticError> {
         if self.neg {
-            assert!(self.int <= i64::MAX as u128 + 1, "integer overflow in to_i64");
-            -(self.int as i64)
+            if self.int > i64::MAX as u128 + 1 {
+                return Err(ArithmeticError::Overflow);
+            }
+            Ok((self.int as i64).wrapping_neg())
         } else {
-            assert!(self.int <= i64::MAX as u128, "integer overflow in to_i64");
-            self.int as i64
+            if self.int > i64::MAX as u128 {
+                return Err(ArithmeticError::Overflow);
+            }
+            Ok(self.int as i64)
         }
     }
 
-    /// Converts to `i128`, truncating the fractional part. Panics on overflow.
+    /// Converts to `i128`, truncating the fractional part. Panics on overflow
+    /// (clamps to `i128::MIN`/`i128::MAX` with `panic-free`).
     pub fn to_i128(&self) -> i128 {
+        unwrap_or_saturate(self.try_to_i128(), || if self.neg { i128::MIN } else { i128::MAX })
+    }
+
+    /// Fallible conversion to `i128`, truncating the fractional part.
+    /// Returns `ArithmeticError::Overflow` if the integer part is out of range.
+    pub fn try_to_i128(&self) -> Result<i128, ArithmeticError> {
         if self.neg {
-            assert!(self.int <= i128::MAX as u128 + 1, "integer overflow in to_i128");
-            -(self.int as i128)
+            if self.int > i128::MAX as u128 + 1 {
+                return Err(ArithmeticError::Overflow);
+            }
+            Ok((self.int as i128).wrapping_neg())
         } else {
-            assert!(self.int <= i128::MAX as u128, "integer overflow in to_i128");
-            self.int as i128
+            if self.int > i128::MAX as u128 {
+                return Err(ArithmeticError::Overflow);
+            }
+            Ok(self.int as i128)
         }
     }
 }
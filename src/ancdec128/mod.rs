@@ -3,13 +3,15 @@ mod basic;
 mod cmp;
 mod convert;
 mod fmt_impl;
+mod num;
 mod ops;
 mod rounding;
 
 #[cfg(feature = "serde")]
 mod serde_impl;
 
-use crate::error::ParseError;
+use crate::context::{Signal, Signals};
+use crate::error::{unwrap_or_saturate, ArithmeticError, ParseError};
 use crate::util::{pow10, pow10_128, StackBuf, TARGET_SCALE_128};
 use crate::wide::divmod_u256;
 use core::fmt::{Display, Write};
@@ -70,16 +72,90 @@ impl AncDec128 {
         scale: 38,
         neg: false,
     };
+    /// The minimum representable value (`-MAX`).
+    pub const MIN: AncDec128 = AncDec128 {
+        int: u128::MAX,
+        frac: 99_999_999_999_999_999_999_999_999_999_999_999_999,
+        scale: 38,
+        neg: true,
+    };
+    /// The smallest positive value (`1e-38`).
+    pub const MIN_POSITIVE: AncDec128 = AncDec128 {
+        int: 0,
+        frac: 1,
+        scale: 38,
+        neg: false,
+    };
+    /// The difference between `1` and the next larger representable value. Values are
+    /// evenly spaced at the maximum scale, so this equals [`MIN_POSITIVE`](Self::MIN_POSITIVE).
+    pub const EPSILON: AncDec128 = Self::MIN_POSITIVE;
+
+    // Mathematical constants mirroring `core::f64::consts`, correctly rounded
+    // (half-even) to the maximum scale of 38 places.
+
+    /// Archimedes' constant (π): `3.14159265358979323846264338327950288420`.
+    pub const PI: AncDec128 = AncDec128 {
+        int: 3,
+        frac: 14_159_265_358_979_323_846_264_338_327_950_288_420,
+        scale: 38,
+        neg: false,
+    };
+    /// Euler's number (e): `2.71828182845904523536028747135266249776`.
+    pub const E: AncDec128 = AncDec128 {
+        int: 2,
+        frac: 71_828_182_845_904_523_536_028_747_135_266_249_776,
+        scale: 38,
+        neg: false,
+    };
+    /// ln(2): `0.69314718055994530941723212145817656808`.
+    pub const LN_2: AncDec128 = AncDec128 {
+        int: 0,
+        frac: 69_314_718_055_994_530_941_723_212_145_817_656_808,
+        scale: 38,
+        neg: false,
+    };
+    /// ln(10): `2.30258509299404568401799145468436420760`.
+    pub const LN_10: AncDec128 = AncDec128 {
+        int: 2,
+        frac: 30_258_509_299_404_568_401_799_145_468_436_420_760,
+        scale: 38,
+        neg: false,
+    };
+    /// sqrt(2): `1.41421356237309504880168872420969807857`.
+    pub const SQRT_2: AncDec128 = AncDec128 {
+        int: 1,
+        frac: 41_421_356_237_309_504_880_168_872_420_969_807_857,
+        scale: 38,
+        neg: false,
+    };
+    /// 1/sqrt(2): `0.70710678118654752440084436210484903928`.
+    pub const FRAC_1_SQRT_2: AncDec128 = AncDec128 {
+        int: 0,
+        frac: 70_710_678_118_654_752_440_084_436_210_484_903_928,
+        scale: 38,
+        neg: false,
+    };
 }
 
 // ============ Constructor / Accessors ============
 impl AncDec128 {
-    /// Creates a new `AncDec128`. Panics if `scale > 38` or `frac >= 10^scale`.
+    /// Creates a new `AncDec128`. Panics if `scale > 38` or `frac >= 10^scale`
+    /// (with `panic-free`, clamps `scale` to 38 and `frac` to `10^scale - 1`).
     #[inline(always)]
     pub fn new(int: u128, frac: u128, scale: u8, neg: bool) -> Self {
-        assert!(scale <= 38, "scale must be <= 38");
-        assert!(frac < pow10_128(scale), "frac must be < 10^scale");
-        Self { int, frac, scale, neg }
+        unwrap_or_saturate(Self::try_new(int, frac, scale, neg), || {
+            let scale = scale.min(38);
+            Self { int, frac: frac.min(pow10_128(scale) - 1), scale, neg }
+        })
+    }
+
+    /// Fallible constructor. Returns `ArithmeticError::ScaleOverflow` if `scale > 38` or `frac >= 10^scale`.
+    #[inline(always)]
+    pub fn try_new(int: u128, frac: u128, scale: u8, neg: bool) -> Result<Self, ArithmeticError> {
+        if scale > 38 || frac >= pow10_128(scale) {
+            return Err(ArithmeticError::ScaleOverflow);
+        }
+        Ok(Self { int, frac, scale, neg })
     }
 
     /// Returns the integer part.
@@ -116,8 +192,25 @@ impl AncDec128 {
         Self::parse_str(buf.as_str())
     }
 
+    /// Parses like `from_str`, but fails with `ParseError::Inexact` instead of dropping
+    /// nonzero fractional digits beyond scale 38. Dropped trailing zeros are accepted.
+    #[inline(always)]
+    pub fn parse_exact(s: &str) -> Result<Self, ParseError> {
+        match Self::parse_str_signals(s)? {
+            (_, signals) if signals.contains(Signal::Inexact) => Err(ParseError::Inexact),
+            (v, _) => Ok(v),
+        }
+    }
+
     /// Byte-level string parsing with validation
+    #[inline(always)]
     pub(crate) fn parse_str(s: &str) -> Result<Self, ParseError> {
+        Self::parse_str_signals(s).map(|(v, _)| v)
+    }
+
+    /// `parse_str`, also reporting dropped fractional digits as `Clamped` (plus `Inexact`
+    /// if any of them was nonzero)
+    pub(crate) fn parse_str_signals(s: &str) -> Result<(Self, Signals), ParseError> {
         let b = s.as_bytes();
         let len = b.len();
         if len == 0 {
@@ -173,6 +266,7 @@ impl AncDec128 {
         let mut frac_u64: u64 = 0;
         let mut frac: u128;
         let mut frac_digits: u8 = 0;
+        let mut signals = Signals::EMPTY;
         // Stage 1: accumulate first 18 digits as u64
         while i < len && frac_digits < 18 {
             let d = unsafe { *b.get_unchecked(i) }.wrapping_sub(b'0');
@@ -194,6 +288,11 @@ impl AncDec128 {
                 if frac_digits < TARGET_SCALE_128 {
                     frac = frac * 10 + d as u128;
                     frac_digits += 1;
+                } else {
+                    signals.insert(Signal::Clamped);
+                    if d != 0 {
+                        signals.insert(Signal::Inexact);
+                    }
                 }
                 i += 1;
             }
@@ -207,12 +306,15 @@ impl AncDec128 {
             return Err(ParseError::TrailingChars);
         }
 
-        Ok(Self {
-            int,
-            frac,
-            scale: frac_digits,
-            neg,
-        })
+        Ok((
+            Self {
+                int,
+                frac,
+                scale: frac_digits,
+                neg,
+            },
+            signals,
+        ))
     }
 
     /// Align fractional parts to same scale, returns (self_frac, other_frac, scale, limit)
@@ -258,7 +360,7 @@ impl AncDec128 {
         int.checked_mul(pow10_128(scale))?.checked_add(frac)
     }
 
-    /// Add aligned values (same sign), handles frac overflow
+    /// Add aligned values (same sign), handles frac overflow. Returns None on integer overflow
     #[inline(always)]
     pub(crate) fn add_aligned(
         a_int: u128,
@@ -267,13 +369,11 @@ impl AncDec128 {
         b_frac: u128,
         scale: u8,
         limit: u128,
-    ) -> (u128, u128, u8) {
+    ) -> Option<(u128, u128, u8)> {
         let frac = a_frac + b_frac;
         let overflow = (frac >= limit) as u128;
-        let int = a_int.checked_add(b_int)
-            .and_then(|v| v.checked_add(overflow))
-            .expect("integer overflow in addition");
-        (int, frac - overflow * limit, scale)
+        let int = a_int.checked_add(b_int)?.checked_add(overflow)?;
+        Some((int, frac - overflow * limit, scale))
     }
 
     /// Subtract with magnitude comparison, returns result with correct sign
@@ -308,26 +408,39 @@ impl AncDec128 {
         }
     }
 
-    /// Split u256 (hi, lo) back to int/frac using divmod_u256
+    /// Split u256 (hi, lo) back to int/frac using divmod_u256. Returns None if the integer part overflows
     #[inline(always)]
-    pub(crate) fn from_combined(n: (u128, u128), scale: u8, neg: bool) -> Self {
+    pub(crate) fn checked_from_combined(n: (u128, u128), scale: u8, neg: bool) -> Option<Self> {
         if scale == 0 {
-            assert!(n.0 == 0, "integer overflow in from_combined");
-            return Self {
+            if n.0 != 0 {
+                return None;
+            }
+            return Some(Self {
                 int: n.1,
                 frac: 0,
                 scale: 0,
                 neg,
-            };
+            });
         }
         let divisor = pow10_128(scale);
         let ((q_hi, q), r) = divmod_u256(n.0, n.1, divisor);
-        assert!(q_hi == 0, "integer overflow in from_combined");
-        Self {
+        if q_hi != 0 {
+            return None;
+        }
+        Some(Self {
             int: q,
             frac: r,
             scale,
             neg,
-        }
+        })
+    }
+
+    /// Like `checked_from_combined`, panics on overflow (saturates with `panic-free`)
+    #[inline(always)]
+    pub(crate) fn from_combined(n: (u128, u128), scale: u8, neg: bool) -> Self {
+        unwrap_or_saturate(
+            Self::checked_from_combined(n, scale, neg).ok_or(ArithmeticError::Overflow),
+            || Self::saturated(neg),
+        )
     }
 }
@@ -0,0 +1,179 @@
+use crate::error::{unwrap_or_saturate, ArithmeticError};
+use crate::wide::{approximate_fraction, divmod_u256, divmod_u512, exact_fraction, gcd_u256, mul_u256, mul_wide};
+use crate::util::{pow10_128, signed_i128};
+use super::AncDec128;
+
+// ============ GCD / LCM ============
+impl AncDec128 {
+    /// Both magnitudes as u256 combined integers at their common scale (see `align_frac`)
+    #[inline(always)]
+    fn aligned_combined(&self, other: &Self) -> ((u128, u128), (u128, u128), u8) {
+        let (a_frac, b_frac, scale, _) = self.align_frac(other);
+        (
+            Self::combine(self.int, a_frac, scale),
+            Self::combine(other.int, b_frac, scale),
+            scale,
+        )
+    }
+
+    /// Greatest common divisor of the magnitudes, computed on the combined integers at the
+    /// common scale: the largest decimal of that scale dividing both (`gcd(0.25, 0.10)` is
+    /// `0.05`). Never negative; `gcd(x, 0) = |x|`.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (a, b, scale) = self.aligned_combined(other);
+        // the gcd is at most max(a, b), so it always fits
+        Self::checked_from_combined(gcd_u256(a, b), scale, false).unwrap_or(Self::ZERO)
+    }
+
+    /// Least common multiple of the magnitudes at the common scale (`lcm(0.25, 0.10)` is
+    /// `0.50`). Never negative; zero if either operand is zero.
+    ///
+    /// Panics if the result overflows (returns `MAX` with `panic-free`).
+    #[inline(always)]
+    pub fn lcm(&self, other: &Self) -> Self {
+        unwrap_or_saturate(self.try_lcm(other), || Self::MAX)
+    }
+
+    /// Fallible least common multiple. Returns `ArithmeticError::Overflow` if the result overflows.
+    #[inline(always)]
+    pub fn try_lcm(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        self.checked_lcm(other).ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Checked least common multiple. Returns `None` on overflow.
+    pub fn checked_lcm(&self, other: &Self) -> Option<Self> {
+        let (a, b, scale) = self.aligned_combined(other);
+        if a == (0, 0) || b == (0, 0) {
+            return Self::checked_from_combined((0, 0), scale, false);
+        }
+        let g = gcd_u256(a, b);
+        let ((_, _, q_hi, q_lo), _) = divmod_u512((0, 0, a.0, a.1), g);
+        match mul_u256((q_hi, q_lo), b) {
+            (0, 0, hi, lo) => Self::checked_from_combined((hi, lo), scale, false),
+            _ => None,
+        }
+    }
+}
+
+// ============ Factorial / Binomial ============
+impl AncDec128 {
+    /// `n!` as an integer `AncDec128`.
+    ///
+    /// Panics if the result overflows `u128`, from `35!` on (returns `MAX` with `panic-free`).
+    #[inline(always)]
+    pub fn factorial(n: u32) -> Self {
+        unwrap_or_saturate(Self::try_factorial(n), || Self::MAX)
+    }
+
+    /// Fallible factorial. Returns `ArithmeticError::Overflow` if `n!` overflows `u128`.
+    #[inline(always)]
+    pub fn try_factorial(n: u32) -> Result<Self, ArithmeticError> {
+        Self::checked_factorial(n).ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Checked factorial. Returns `None` if `n!` overflows `u128`.
+    pub fn checked_factorial(n: u32) -> Option<Self> {
+        let mut acc: u128 = 1;
+        for i in 2..=n {
+            acc = acc.checked_mul(i as u128)?;
+        }
+        Some(Self::from(acc))
+    }
+
+    /// The binomial coefficient `n choose k` as an integer `AncDec128`; zero if `k > n`.
+    ///
+    /// Panics if the result overflows `u128` (returns `MAX` with `panic-free`).
+    #[inline(always)]
+    pub fn binomial(n: u32, k: u32) -> Self {
+        unwrap_or_saturate(Self::try_binomial(n, k), || Self::MAX)
+    }
+
+    /// Fallible binomial coefficient. Returns `ArithmeticError::Overflow` if the result
+    /// overflows `u128`.
+    #[inline(always)]
+    pub fn try_binomial(n: u32, k: u32) -> Result<Self, ArithmeticError> {
+        Self::checked_binomial(n, k).ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Checked binomial coefficient. Returns `None` if the result overflows `u128`.
+    pub fn checked_binomial(n: u32, k: u32) -> Option<Self> {
+        if k > n {
+            return Some(Self::ZERO);
+        }
+        // C(n, i + 1) = C(n, i) * (n - i) / (i + 1), exact at every step. With k <= n / 2 the
+        // partial results only grow, so the first one past u128 means the result overflows;
+        // since C(n, i) >= 2^i that happens within 128 steps.
+        let k = k.min(n - k);
+        let mut acc: u128 = 1;
+        for i in 0..k {
+            let (hi, lo) = mul_wide(acc, (n - i) as u128);
+            acc = match divmod_u256(hi, lo, (i + 1) as u128) {
+                ((0, q), _) => q,
+                _ => return None,
+            };
+        }
+        Some(Self::from(acc))
+    }
+}
+
+// ============ Fractions ============
+impl AncDec128 {
+    /// The exact value as `(numerator, denominator)` in lowest terms, with the sign on the
+    /// numerator. The denominator divides `10^scale`; zero is `(0, 1)`.
+    ///
+    /// Panics if the numerator overflows `i128` (returns `(i128::MIN, 1)` or `(i128::MAX, 1)`
+    /// with `panic-free`).
+    ///
+    /// ```
+    /// use ancdec::AncDec128;
+    ///
+    /// let x: AncDec128 = "-1.25".parse().unwrap();
+    /// assert_eq!(x.to_fraction(), (-5, 4));
+    /// ```
+    #[inline(always)]
+    pub fn to_fraction(&self) -> (i128, u128) {
+        unwrap_or_saturate(self.try_to_fraction(), || self.saturated_fraction())
+    }
+
+    /// Fallible exact fraction. Returns `ArithmeticError::Overflow` if the numerator overflows
+    /// `i128`.
+    pub fn try_to_fraction(&self) -> Result<(i128, u128), ArithmeticError> {
+        exact_fraction(self.int, self.frac, pow10_128(self.scale))
+            .and_then(|(n, d)| Some((signed_i128(n, self.neg)?, d)))
+            .ok_or(ArithmeticError::Overflow)
+    }
+
+    /// The closest fraction with a denominator of at most `max_denominator` (0 is treated as
+    /// 1), found from the continued fraction expansion, e.g. bond prices in 32nds or gear
+    /// ratios. The exact fraction is returned when it already fits. Matches Python's
+    /// `Fraction.limit_denominator`, ties included: a negative value is expanded from its
+    /// floor, so `-0.5` with a bound of 1 gives `-1`.
+    ///
+    /// Panics if the numerator overflows `i128` (returns `(i128::MIN, 1)` or `(i128::MAX, 1)`
+    /// with `panic-free`).
+    ///
+    /// ```
+    /// use ancdec::AncDec128;
+    ///
+    /// let x: AncDec128 = "3.14159".parse().unwrap();
+    /// assert_eq!(x.approximate_fraction(1000), (355, 113));
+    /// ```
+    #[inline(always)]
+    pub fn approximate_fraction(&self, max_denominator: u128) -> (i128, u128) {
+        unwrap_or_saturate(self.try_approximate_fraction(max_denominator), || self.saturated_fraction())
+    }
+
+    /// Fallible best rational approximation. Returns `ArithmeticError::Overflow` if the
+    /// numerator overflows `i128`.
+    pub fn try_approximate_fraction(&self, max_denominator: u128) -> Result<(i128, u128), ArithmeticError> {
+        approximate_fraction(self.int, self.frac, pow10_128(self.scale), self.neg, max_denominator)
+            .and_then(|(n, d)| Some((signed_i128(n, self.neg)?, d)))
+            .ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Overflow fallback for the fraction conversions: the integer bound on the value's side
+    #[inline(always)]
+    fn saturated_fraction(&self) -> (i128, u128) {
+        (if self.neg { i128::MIN } else { i128::MAX }, 1)
+    }
+}
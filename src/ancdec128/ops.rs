@@ -1,6 +1,4 @@
 use super::AncDec128;
-use crate::wide::mul_wide;
-use crate::util::pow10_128;
 use core::hash::{Hash, Hasher};
 use core::iter::{Product, Sum};
 use core::ops::{
@@ -226,37 +224,14 @@ impl Default for AncDec128 {
 }
 
 // ============ Hash ============
-/// Normalizes trailing zeros so 1.0 == 1.00 have same hash
-/// Uses u256 combined value via mul_wide
+/// Hashes the normalized form, so equal values (1.0 and 1.00, 0 and -0) hash the same
 impl Hash for AncDec128 {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        let (mut hi, mut lo) = mul_wide(self.int, pow10_128(self.scale));
-        let (lo2, carry) = lo.overflowing_add(self.frac);
-        lo = lo2;
-        hi += carry as u128;
-
-        // Binary search: strip trailing zeros in O(log scale) instead of O(scale)
-        // u256 divisibility by 10: (hi % 10) * 6 + lo % 10) % 10 == 0  (since 2^128 mod 10 = 6)
-        if (hi > 0 || lo > 0) && ((hi % 10) * 6 + lo % 10) % 10 == 0 {
-            macro_rules! strip_u256 {
-                ($pow:expr) => {
-                    let ((qh, ql), r) = crate::wide::divmod_u256(hi, lo, $pow);
-                    if r == 0 { hi = qh; lo = ql; }
-                };
-            }
-            strip_u256!(pow10_128(32));
-            strip_u256!(pow10_128(16));
-            strip_u256!(100_000_000u128);
-            strip_u256!(10_000u128);
-            strip_u256!(100u128);
-            strip_u256!(10u128);
-        }
-
-        hi.hash(state);
-        lo.hash(state);
-        if hi != 0 || lo != 0 {
-            self.neg.hash(state);
-        }
+        let n = self.normalize();
+        n.int.hash(state);
+        n.frac.hash(state);
+        n.scale.hash(state);
+        n.neg.hash(state);
     }
 }
 
@@ -1,7 +1,16 @@
 use super::AncDec128;
-use crate::util::pow10_128;
-use crate::wide::{divmod_u256, mul_wide};
+use crate::error::{unwrap_or_saturate, ArithmeticError};
+use crate::round_mode::Residue;
+use crate::util::{digits_128, pow10_128, pow10_256, signed_i128_wide, TARGET_SCALE_128};
+use crate::wide::{
+    div_u512_by_u128, div_u512_by_u256, divmod_u256, divmod_u512, mod10_u256, mul_u256,
+    mul_wide,
+};
 use crate::RoundMode;
+#[cfg(feature = "rand")]
+use crate::round_mode::stochastic_rounds_up;
+#[cfg(feature = "rand")]
+use rand_core::RngCore;
 
 impl AncDec128 {
     /// Rounds to the given number of decimal places using the specified mode.
@@ -14,8 +23,65 @@ impl AncDec128 {
                 neg: self.neg,
             };
         }
+        self.round_by(decimal_places, |r, d, last_digit| {
+            mode.rounds_up(Residue::new(r, d), self.neg, last_digit)
+        })
+        .0
+    }
+
+    /// Returns the largest integer less than or equal to `self`.
+    #[inline(always)]
+    pub fn floor(&self) -> Self {
+        self.round(0, RoundMode::Floor)
+    }
+    /// Returns the smallest integer greater than or equal to `self`.
+    #[inline(always)]
+    pub fn ceil(&self) -> Self {
+        self.round(0, RoundMode::Ceil)
+    }
+    /// Returns the integer part, truncating toward zero.
+    #[inline(always)]
+    pub fn trunc(&self) -> Self {
+        self.round(0, RoundMode::Truncate)
+    }
+    /// Returns the fractional part only.
+    #[inline(always)]
+    pub fn fract(&self) -> Self {
+        self.round(0, RoundMode::Fract)
+    }
+
+    /// Rounds like [`round`](Self::round) and also returns the exact residual removed, so
+    /// `rounded + residual == self`: round each period's amount and carry the residual into
+    /// the next. The residual keeps `self`'s scale. `RoundMode::Fract` truncates.
+    ///
+    /// Panics if rounding overflows (saturates with `panic-free`).
+    pub fn round_with_residual(&self, decimal_places: u8, mode: RoundMode) -> (Self, Self) {
+        self.round_by(decimal_places, |r, d, last_digit| {
+            mode.rounds_up(Residue::new(r, d), self.neg, last_digit)
+        })
+    }
+
+    /// Stochastic rounding to `decimal_places`: the magnitude rounds up with probability
+    /// equal to the discarded part over one unit in the last kept place (`1.25` to one
+    /// place gives `1.3` half of the time), so the result is unbiased on average.
+    ///
+    /// Panics if rounding up overflows (saturates with `panic-free`).
+    #[cfg(feature = "rand")]
+    pub fn round_stochastic(&self, decimal_places: u8, rng: &mut impl RngCore) -> Self {
+        self.round_by(decimal_places, |r, d, _| stochastic_rounds_up(r, d, rng)).0
+    }
+
+    /// Cuts to `decimal_places`, adding one unit in the last kept place when
+    /// `rounds_up(remainder, divisor, last_digit)` says so. Also returns the residual
+    /// `self - rounded` at `self`'s scale.
+    #[inline(always)]
+    fn round_by(
+        &self,
+        decimal_places: u8,
+        rounds_up: impl FnOnce(u128, u128, u8) -> bool,
+    ) -> (Self, Self) {
         if self.scale <= decimal_places {
-            return *self;
+            return (*self, Self::ZERO);
         }
 
         // combined = int * 10^scale + frac as u256
@@ -27,60 +93,442 @@ impl AncDec128 {
         let divisor = pow10_128(cut);
 
         // divmod_u256: combined / divisor -> (truncated_u256, remainder_u128)
-        let (truncated_256, remainder) = divmod_u256(combined.0, combined.1, divisor);
-        // truncated fits in u128 for practical values (int * 10^decimal_places + frac_part)
-        let truncated = truncated_256.1;
-
-        if self.should_round_up(truncated, remainder, divisor, mode) {
-            // from_combined with truncated + 1, carry into hi limb on overflow
-            let (lo, carry) = truncated.overflowing_add(1);
-            Self::from_combined((carry as u128, lo), decimal_places, self.neg)
+        let (truncated, remainder) = divmod_u256(combined.0, combined.1, divisor);
+        let up = rounds_up(remainder, divisor, mod10_u256(truncated));
+        let truncated = if up {
+            let (lo, carry) = truncated.1.overflowing_add(1);
+            (truncated.0 + carry as u128, lo)
+        } else {
+            truncated
+        };
+        // remainder < divisor <= 10^scale, so the residual is purely fractional; rounding up
+        // leaves `divisor - remainder` with the opposite sign
+        let residual = if up { divisor - remainder } else { remainder };
+        let residual = Self {
+            int: 0,
+            frac: residual,
+            scale: self.scale,
+            neg: (self.neg ^ up) && residual != 0,
+        };
+        (Self::from_combined(truncated, decimal_places, self.neg), residual)
+    }
+}
+
+// ============ Rounded Arithmetic ============
+impl AncDec128 {
+    /// Multiplies and rounds the exact product once to `scale` decimal places (capped at 38).
+    /// Products that already fit in `scale` digits keep their exact scale.
+    ///
+    /// Panics on overflow (saturates with `panic-free`). `RoundMode::Fract` truncates.
+    #[inline(always)]
+    pub fn mul_round(&self, other: &Self, scale: u8, mode: RoundMode) -> Self {
+        unwrap_or_saturate(
+            self.checked_mul_round(other, scale, mode).ok_or(ArithmeticError::Overflow),
+            || Self::saturated(self.neg ^ other.neg),
+        )
+    }
+
+    /// Divides and rounds the exact quotient once to `scale` decimal places (capped at 38).
+    ///
+    /// Panics on division by zero or overflow (saturates with `panic-free`, see
+    /// [`saturating_div`](Self::saturating_div)). `RoundMode::Fract` truncates.
+    #[inline(always)]
+    pub fn div_round(&self, other: &Self, scale: u8, mode: RoundMode) -> Self {
+        let result = if other.is_zero() {
+            Err(ArithmeticError::DivisionByZero)
         } else {
-            Self::from_combined((0, truncated), decimal_places, self.neg)
+            self.checked_div_round(other, scale, mode).ok_or(ArithmeticError::Overflow)
+        };
+        unwrap_or_saturate(result, || self.saturating_div(other))
+    }
+
+    /// Checked [`mul_round`](Self::mul_round). Returns `None` if the rounded result overflows `u128`.
+    pub fn checked_mul_round(&self, other: &Self, scale: u8, mode: RoundMode) -> Option<Self> {
+        self.mul_round_exact(other, scale, mode).map(|(p, _)| p)
+    }
+
+    /// Checked [`div_round`](Self::div_round). Returns `None` if `other` is zero or the
+    /// rounded quotient overflows `u128`.
+    pub fn checked_div_round(&self, other: &Self, scale: u8, mode: RoundMode) -> Option<Self> {
+        self.div_round_exact(other, scale, mode).map(|(q, _)| q)
+    }
+
+    /// Divides, keeping the minimal exact scale when the quotient terminates within 38 places
+    /// (`1 / 2` gives `0.5` at scale 1 rather than a scale-38 value). Quotients that do not
+    /// terminate are rounded to `default_scale` places (capped at 38) with `mode`.
+    ///
+    /// Panics on division by zero or overflow (saturates with `panic-free`, see
+    /// [`saturating_div`](Self::saturating_div)).
+    #[inline(always)]
+    pub fn div_min_scale(&self, other: &Self, default_scale: u8, mode: RoundMode) -> Self {
+        let result = if other.is_zero() {
+            Err(ArithmeticError::DivisionByZero)
+        } else {
+            self.checked_div_min_scale(other, default_scale, mode).ok_or(ArithmeticError::Overflow)
+        };
+        unwrap_or_saturate(result, || self.saturating_div(other))
+    }
+
+    /// Checked [`div_min_scale`](Self::div_min_scale). Returns `None` if `other` is zero or the
+    /// quotient overflows `u128`.
+    pub fn checked_div_min_scale(&self, other: &Self, default_scale: u8, mode: RoundMode) -> Option<Self> {
+        match self.div_round_exact(other, TARGET_SCALE_128, RoundMode::Truncate) {
+            Some((q, true)) => Some(q.normalize()),
+            _ => self.checked_div_round(other, default_scale, mode),
         }
     }
 
-    fn should_round_up(
-        &self,
-        truncated: u128,
-        remainder: u128,
-        divisor: u128,
-        mode: RoundMode,
-    ) -> bool {
-        if remainder == 0 {
-            return false;
+    /// `checked_mul_round`, also reporting whether the product was exact (nothing was rounded off)
+    pub(crate) fn mul_round_exact(&self, other: &Self, scale: u8, mode: RoundMode) -> Option<(Self, bool)> {
+        let neg = self.neg ^ other.neg;
+        let a = Self::combine(self.int, self.frac, self.scale);
+        let b = Self::combine(other.int, other.frac, other.scale);
+        let total_scale = self.scale + other.scale;
+        let scale = scale.min(TARGET_SCALE_128);
+
+        // u512 product, divided once by 10^(total_scale - scale)
+        let (w3, w2, w1, w0) = mul_u256(a, b);
+        if total_scale <= scale {
+            if w3 != 0 || w2 != 0 {
+                return None;
+            }
+            return Self::checked_from_combined((w1, w0), total_scale, neg).map(|p| (p, true));
         }
-        let half = divisor / 2;
+        let d = pow10_256(total_scale - scale);
+        let (q, exact) = Self::div_round_wide((w3, w2, w1, w0), d, neg, mode)?;
+        Some((Self::checked_from_combined(q, scale, neg)?, exact))
+    }
 
-        match mode {
-            RoundMode::Floor => self.neg,
-            RoundMode::Ceil => !self.neg,
-            RoundMode::Truncate => false,
-            RoundMode::HalfUp => remainder >= half,
-            RoundMode::HalfDown => remainder > half,
-            RoundMode::HalfEven => remainder > half || (remainder == half && truncated % 2 == 1),
-            RoundMode::Fract => false,
+    /// `checked_div_round`, also reporting whether the quotient was exact (nothing was rounded off)
+    pub(crate) fn div_round_exact(&self, other: &Self, scale: u8, mode: RoundMode) -> Option<(Self, bool)> {
+        if other.is_zero() {
+            return None;
         }
+        let neg = self.neg ^ other.neg;
+        let a = Self::combine(self.int, self.frac, self.scale);
+        let b = Self::combine(other.int, other.frac, other.scale);
+        let scale = scale.min(TARGET_SCALE_128);
+
+        // quotient at `scale` digits: a * 10^(scale + b.scale - a.scale) / b
+        let shift = scale + other.scale;
+        let (q, exact) = if shift >= self.scale {
+            let n = mul_u256(a, pow10_256(shift - self.scale));
+            Self::div_round_wide(n, b, neg, mode)?
+        } else {
+            let (d3, d2, d1, d0) = mul_u256(b, pow10_256(self.scale - shift));
+            if d3 != 0 || d2 != 0 {
+                // divisor >= 2^256 > 2a: the quotient is 0 and the remainder below half
+                let residue = if a == (0, 0) { Residue::Zero } else { Residue::BelowHalf };
+                ((0, mode.rounds_up(residue, neg, 0) as u128), a == (0, 0))
+            } else {
+                Self::div_round_wide((0, 0, a.0, a.1), (d1, d0), neg, mode)?
+            }
+        };
+        Some((Self::checked_from_combined(q, scale, neg)?, exact))
     }
 
-    /// Returns the largest integer less than or equal to `self`.
+    /// Computes `self * b / c` from the exact u512 product, rounded once to 38 decimal places.
+    ///
+    /// Returns `None` if `c` is zero or the final quotient overflows `u128` integer range;
+    /// the intermediate product itself never overflows. `RoundMode::Fract` truncates.
+    pub fn mul_div(&self, b: &Self, c: &Self, mode: RoundMode) -> Option<Self> {
+        if c.is_zero() {
+            return None;
+        }
+        let neg = self.neg ^ b.neg ^ c.neg;
+        let x = Self::combine(self.int, self.frac, self.scale);
+        let y = Self::combine(b.int, b.frac, b.scale);
+        let z = Self::combine(c.int, c.frac, c.scale);
+
+        // quotient at 38 places: x * y * 10^(38 + c.scale - self.scale - b.scale) / z
+        let n = mul_u256(x, y);
+        let (up, down) = (TARGET_SCALE_128 + c.scale, self.scale + b.scale);
+        let ((n3, n2, n1, n0), r1) = divmod_u512(n, z);
+        let (q, residue) = if up >= down {
+            // q1 * 10^e + r1 * 10^e / z, where r1 < z keeps the second quotient below 10^e
+            let k = pow10_256(up - down);
+            if n3 != 0 || n2 != 0 {
+                return None;
+            }
+            let (p3, p2, p1, p0) = mul_u256((n1, n0), k);
+            if p3 != 0 || p2 != 0 {
+                return None;
+            }
+            let ((_, _, s1, s0), r2) = divmod_u512(mul_u256(r1, k), z);
+            let (lo, carry) = p0.overflowing_add(s0);
+            let hi = p1.checked_add(s1)?.checked_add(carry as u128)?;
+            ((hi, lo), Residue::new_wide(r2, z))
+        } else {
+            let k = pow10_256(down - up);
+            let ((q3, q2, q1, q0), r2) = divmod_u512((n3, n2, n1, n0), k);
+            if q3 != 0 || q2 != 0 {
+                return None;
+            }
+            ((q1, q0), Residue::new_wide(r2, k).with_sticky(r1 != (0, 0)))
+        };
+        let up = mode.rounds_up(residue, neg, mod10_u256(q));
+        let (lo, carry) = q.1.overflowing_add(up as u128);
+        Self::checked_from_combined((q.0.checked_add(carry as u128)?, lo), TARGET_SCALE_128, neg)
+    }
+
+    /// Rounded u512 / u256 quotient and whether it was exact. `None` if it does not fit in u256.
+    fn div_round_wide(
+        n: (u128, u128, u128, u128),
+        d: (u128, u128),
+        neg: bool,
+        mode: RoundMode,
+    ) -> Option<((u128, u128), bool)> {
+        let (w3, w2, w1, w0) = n;
+        let q = if d.0 == 0 {
+            // div_u512_by_u128 saturates to (MAX, MAX) when the quotient overflows u256
+            let q = div_u512_by_u128(w3, w2, w1, w0, d.1);
+            if q == (u128::MAX, u128::MAX) {
+                return None;
+            }
+            q
+        } else {
+            // Knuth D needs the top half below the divisor for the quotient to fit in u256
+            if (w3, w2) >= d {
+                return None;
+            }
+            div_u512_by_u256(w3, w2, w1, w0, d.0, d.1)
+        };
+
+        // remainder < d fits in u256, so the low 256 bits of n - q * d suffice
+        let (_, _, p1, p0) = mul_u256(q, d);
+        let (r_lo, borrow) = w0.overflowing_sub(p0);
+        let r_hi = w1.wrapping_sub(p1).wrapping_sub(borrow as u128);
+        let up = mode.rounds_up(Residue::new_wide((r_hi, r_lo), d), neg, mod10_u256(q));
+        let (lo, carry) = q.1.overflowing_add(up as u128);
+        Some(((q.0.checked_add(carry as u128)?, lo), (r_hi, r_lo) == (0, 0)))
+    }
+}
+
+// ============ Scale Management ============
+impl AncDec128 {
+    /// Number of trailing zeros among the fractional digits (`1.2300` has 2, `1.000` has 3):
+    /// how far [`normalize`](Self::normalize) lowers the scale.
+    pub fn trailing_zeros(&self) -> u8 {
+        if self.frac == 0 {
+            return self.scale;
+        }
+        // Binary search: strip trailing zeros in O(log scale) instead of O(scale)
+        let (mut frac, mut n) = (self.frac, 0);
+        if frac % 100_000_000_000_000_000_000_000_000_000_000 == 0 {
+            frac /= 100_000_000_000_000_000_000_000_000_000_000;
+            n += 32;
+        }
+        if frac % 10_000_000_000_000_000 == 0 {
+            frac /= 10_000_000_000_000_000;
+            n += 16;
+        }
+        if frac % 100_000_000 == 0 {
+            frac /= 100_000_000;
+            n += 8;
+        }
+        if frac % 10_000 == 0 {
+            frac /= 10_000;
+            n += 4;
+        }
+        if frac % 100 == 0 {
+            frac /= 100;
+            n += 2;
+        }
+        n + (frac % 10 == 0) as u8
+    }
+
+    /// Strips trailing fractional zeros (`1.2300` becomes `1.23` at scale 2). Zero becomes
+    /// [`ZERO`](Self::ZERO), so equal values normalize to the same representation.
     #[inline(always)]
-    pub fn floor(&self) -> Self {
-        self.round(0, RoundMode::Floor)
+    pub fn normalize(&self) -> Self {
+        if self.is_zero() {
+            return Self::ZERO;
+        }
+        let tz = self.trailing_zeros();
+        Self {
+            int: self.int,
+            frac: self.frac / pow10_128(tz),
+            scale: self.scale - tz,
+            neg: self.neg,
+        }
     }
-    /// Returns the smallest integer greater than or equal to `self`.
+
+    /// Changes the scale to exactly `scale`: widens with zeros, or narrows by rounding with
+    /// `mode` (`RoundMode::Fract` truncates). Unlike [`round`](Self::round), the result
+    /// always has the requested scale.
+    ///
+    /// Panics if `scale` exceeds 38 or rounding overflows (saturates with `panic-free`).
     #[inline(always)]
-    pub fn ceil(&self) -> Self {
-        self.round(0, RoundMode::Ceil)
+    pub fn rescale(&self, scale: u8, mode: RoundMode) -> Self {
+        let result = if scale > TARGET_SCALE_128 {
+            Err(ArithmeticError::ScaleOverflow)
+        } else {
+            self.checked_rescale(scale, mode).ok_or(ArithmeticError::Overflow)
+        };
+        unwrap_or_saturate(result, || Self::saturated(self.neg))
     }
-    /// Returns the integer part, truncating toward zero.
+
+    /// Checked [`rescale`](Self::rescale). Returns `None` if `scale` exceeds 38 or the
+    /// rounded value overflows `u128`.
+    pub fn checked_rescale(&self, scale: u8, mode: RoundMode) -> Option<Self> {
+        if scale > TARGET_SCALE_128 {
+            return None;
+        }
+        if scale >= self.scale {
+            // SAFETY: frac < 10^self.scale, so the widened frac stays below 10^38
+            return Some(Self {
+                frac: self.frac * pow10_128(scale - self.scale),
+                scale,
+                ..*self
+            });
+        }
+        // times ONE (scale 0) rounds the exact value once to `scale`
+        self.mul_round_exact(&Self::ONE, scale, mode).map(|(v, _)| v)
+    }
+
+    /// Changes the scale to exactly `scale` without altering the value. Returns `None` if
+    /// narrowing would drop nonzero digits or `scale` exceeds 38.
+    pub fn with_scale(&self, scale: u8) -> Option<Self> {
+        if scale < self.scale && self.trailing_zeros() < self.scale - scale {
+            return None;
+        }
+        self.checked_rescale(scale, RoundMode::Truncate)
+    }
+}
+
+// ============ Increment Rounding ============
+impl AncDec128 {
+    /// Rounds to a multiple of `increment` using `mode`: tick sizes like `0.25`, or cash
+    /// rounding to `0.05`. The quotient is rounded exactly on the combined integers and the
+    /// result takes `increment`'s scale; the sign of `increment` is ignored
+    /// (`RoundMode::Fract` truncates).
+    ///
+    /// Panics if `increment` is zero or the result overflows `u128` (saturates with
+    /// `panic-free`, where a zero increment returns `self` unchanged).
+    pub fn round_to_increment(&self, increment: &Self, mode: RoundMode) -> Self {
+        let result = if increment.is_zero() {
+            Err(ArithmeticError::DivisionByZero)
+        } else {
+            self.checked_round_to_increment(increment, mode).ok_or(ArithmeticError::Overflow)
+        };
+        unwrap_or_saturate(result, || {
+            if increment.is_zero() {
+                *self
+            } else {
+                Self::saturated(self.neg)
+            }
+        })
+    }
+
+    /// Checked [`round_to_increment`](Self::round_to_increment). Returns `None` if
+    /// `increment` is zero or the result overflows `u128`.
+    pub fn checked_round_to_increment(&self, increment: &Self, mode: RoundMode) -> Option<Self> {
+        let step = increment.abs();
+        let (q, neg, _) = self.int_divmod(&step, mode)?;
+        let m = Self::combine(step.int, step.frac, step.scale);
+        let (w3, w2, hi, lo) = mul_u256(q, m);
+        if w3 != 0 || w2 != 0 {
+            return None;
+        }
+        Self::checked_from_combined((hi, lo), step.scale, neg)
+    }
+
+    /// Whether `self` is an exact multiple of `increment` (`1.25` of `0.05`, but not `1.26`).
+    /// Only zero is a multiple of zero.
+    pub fn is_multiple_of(&self, increment: &Self) -> bool {
+        match self.int_divmod(increment, RoundMode::Truncate) {
+            Some((_, _, r)) => r.is_zero(),
+            None => self.is_zero(),
+        }
+    }
+}
+
+// ============ Significant Digits ============
+impl AncDec128 {
+    /// Number of digits in the integer part (`123.45` has 3); 0 when `|self| < 1`.
     #[inline(always)]
-    pub fn trunc(&self) -> Self {
-        self.round(0, RoundMode::Truncate)
+    pub fn int_digits(&self) -> u8 {
+        digits_128(self.int)
     }
-    /// Returns the fractional part only.
+
+    /// Number of zeros between the decimal point and the first nonzero fractional digit
+    /// (`0.0012` has 2); all `scale` digits when the fractional part is zero.
     #[inline(always)]
-    pub fn fract(&self) -> Self {
-        self.round(0, RoundMode::Fract)
+    pub fn leading_frac_zeros(&self) -> u8 {
+        self.scale - digits_128(self.frac)
+    }
+
+    /// Number of significant digits, from the first nonzero digit through the last stored
+    /// fractional digit (`0.0120` has 3, `100` has 3). Zero has none.
+    pub fn precision(&self) -> u8 {
+        if self.int != 0 {
+            self.int_digits() + self.scale
+        } else {
+            digits_128(self.frac)
+        }
+    }
+
+    /// Rounds to `digits` significant digits (at least 1) using `mode`: `1234.5` to 2 digits gives
+    /// `1200`, `0.012345` to 3 gives `0.0123`. Integer digits past `digits` become zeros, and a carry
+    /// into a new leading digit drops the extra fractional zero (`9.96` to 2 digits gives `10`).
+    /// `RoundMode::Fract` truncates.
+    ///
+    /// Panics if rounding overflows (saturates with `panic-free`).
+    pub fn round_sig(&self, digits: u8, mode: RoundMode) -> Self {
+        let digits = digits.max(1);
+        let mode = if mode == RoundMode::Fract { RoundMode::Truncate } else { mode };
+        let int_digits = self.int_digits();
+        if int_digits > digits {
+            // 10^(int_digits - digits) is below `int`, so it is representable
+            let step = Self {
+                int: pow10_128(int_digits - digits),
+                frac: 0,
+                scale: 0,
+                neg: false,
+            };
+            return self.round_to_increment(&step, mode);
+        }
+        let places = if int_digits > 0 {
+            digits - int_digits
+        } else {
+            digits.saturating_add(self.leading_frac_zeros())
+        };
+        if places >= self.scale {
+            return *self;
+        }
+        let rounded = self.round(places, mode);
+        if rounded.precision() > digits && rounded.scale > 0 {
+            // the carry left a trailing zero (9.96 -> 10.0)
+            return Self {
+                frac: rounded.frac / 10,
+                scale: rounded.scale - 1,
+                ..rounded
+            };
+        }
+        rounded
+    }
+
+    /// Decomposes into `(mantissa, exponent)` with `self == mantissa * 10^exponent` and no
+    /// trailing zeros in the mantissa: `12.50` gives `(125, -1)`, `1200` gives `(12, 2)` and
+    /// zero gives `(0, 0)`. Panics if the mantissa is outside `i128` range (clamps to
+    /// `i128::MIN`/`i128::MAX` with `panic-free`).
+    pub fn mantissa_exponent(&self) -> (i128, i32) {
+        unwrap_or_saturate(self.try_mantissa_exponent(), || {
+            (if self.neg { i128::MIN } else { i128::MAX }, 0)
+        })
+    }
+
+    /// Fallible [`mantissa_exponent`](Self::mantissa_exponent). Returns
+    /// `ArithmeticError::Overflow` if the mantissa is outside `i128` range.
+    pub fn try_mantissa_exponent(&self) -> Result<(i128, i32), ArithmeticError> {
+        let v = self.normalize();
+        let mut m = Self::combine(v.int, v.frac, v.scale);
+        let mut exponent = -(v.scale as i32);
+        // only a normalized integer can still end in zeros
+        while m != (0, 0) && mod10_u256(m) == 0 {
+            m = divmod_u256(m.0, m.1, 10).0;
+            exponent += 1;
+        }
+        Ok((signed_i128_wide(m, v.neg).ok_or(ArithmeticError::Overflow)?, exponent))
     }
 }
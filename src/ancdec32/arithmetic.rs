@@ -1,97 +1,68 @@
+use crate::error::{unwrap_or_saturate, ArithmeticError};
 use super::AncDec32;
-use crate::util::{pow10, pow10_128, SCALE9, TARGET_SCALE_32};
+use core::num::{NonZeroU128, NonZeroU64};
+use crate::round_mode::Residue;
+use crate::util::{pow10, pow10_128, signed_i128, SCALE9, TARGET_SCALE_32};
+use crate::RoundMode;
 
 impl AncDec32 {
-    /// Adds two decimals, panics on integer overflow.
+    /// Adds two decimals, panics on integer overflow (saturates with `panic-free`).
     #[inline(always)]
     pub fn add(&self, other: &Self) -> Self {
-        let (a_frac, b_frac, scale, limit) = self.align_frac(other);
-
-        if self.neg == other.neg {
-            let (int, frac, scale) =
-                Self::add_aligned(self.int, a_frac, other.int, b_frac, scale, limit);
-            Self {
-                int,
-                frac,
-                scale,
-                neg: self.neg,
-            }
-        } else {
-            Self::sub_with_cmp(
-                self.int, a_frac, self.neg, other.int, b_frac, other.neg, scale, limit,
-            )
-        }
+        unwrap_or_saturate(self.try_add(other), || Self::saturated(self.neg))
     }
 
-    /// Subtracts `other` from `self`, panics on integer overflow.
+    /// Subtracts `other` from `self`, panics on integer overflow (saturates with `panic-free`).
     #[inline(always)]
     pub fn sub(&self, other: &Self) -> Self {
-        let (a_frac, b_frac, scale, limit) = self.align_frac(other);
-        let other_neg = !other.neg;
-
-        if self.neg == other_neg {
-            let (int, frac, scale) =
-                Self::add_aligned(self.int, a_frac, other.int, b_frac, scale, limit);
-            Self {
-                int,
-                frac,
-                scale,
-                neg: self.neg,
-            }
-        } else {
-            Self::sub_with_cmp(
-                self.int, a_frac, self.neg, other.int, b_frac, other_neg, scale, limit,
-            )
-        }
+        unwrap_or_saturate(self.try_sub(other), || Self::saturated(self.neg))
     }
 
-    /// Multiplies two decimals, panics on overflow.
+    /// Multiplies two decimals, panics on overflow (saturates with `panic-free`).
     #[inline(always)]
     pub fn mul(&self, other: &Self) -> Self {
-        let a = self.int as u64 * pow10(self.scale) + self.frac as u64;
-        let b = other.int as u64 * pow10(other.scale) + other.frac as u64;
-        let total_scale = self.scale + other.scale;
-
-        let product = a as u128 * b as u128;
-
-        let (result, final_scale) = if total_scale > TARGET_SCALE_32 {
-            let divisor = pow10_128(total_scale - TARGET_SCALE_32);
-            ((product / divisor) as u64, TARGET_SCALE_32)
-        } else if product <= u64::MAX as u128 {
-            (product as u64, total_scale)
-        } else {
-            panic!("multiplication overflow")
-        };
-
-        Self::from_combined(result, final_scale, self.neg ^ other.neg)
+        unwrap_or_saturate(self.try_mul(other), || Self::saturated(self.neg ^ other.neg))
     }
 
-    /// Divides `self` by `other`, panics on division by zero.
+    /// Divides `self` by `other`, panics on division by zero or if the quotient overflows `u32`
+    /// (saturates with `panic-free`, see [`saturating_div`](Self::saturating_div)).
     #[inline(always)]
     pub fn div(&self, other: &Self) -> Self {
-        assert!(other.int != 0 || other.frac != 0, "division by zero");
+        unwrap_or_saturate(self.try_div(other), || self.saturating_div(other))
+    }
 
-        let a = self.int as u64 * pow10(self.scale) + self.frac as u64;
-        let b = other.int as u64 * pow10(other.scale) + other.frac as u64;
+    /// Fallible addition. Returns `ArithmeticError::Overflow` if the integer part overflows `u32`.
+    #[inline(always)]
+    pub fn try_add(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        self.checked_add(other).ok_or(ArithmeticError::Overflow)
+    }
 
-        let shift = TARGET_SCALE_32 + other.scale;
+    /// Fallible subtraction. Returns `ArithmeticError::Overflow` if the integer part overflows `u32`.
+    #[inline(always)]
+    pub fn try_sub(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        self.checked_sub(other).ok_or(ArithmeticError::Overflow)
+    }
 
-        let quotient = if shift >= self.scale {
-            let exp = shift - self.scale;
-            let numerator = a as u128 * pow10_128(exp);
-            (numerator / b as u128) as u64
-        } else {
-            a / (b * pow10(self.scale - shift))
-        };
+    /// Fallible multiplication. Returns `ArithmeticError::Overflow` if the result overflows `u32` integer range.
+    #[inline(always)]
+    pub fn try_mul(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        self.checked_mul(other).ok_or(ArithmeticError::Overflow)
+    }
 
-        let q = quotient / SCALE9;
-        let r = quotient - q * SCALE9;
-        Self {
-            int: q as u32,
-            frac: r as u32,
-            scale: TARGET_SCALE_32,
-            neg: self.neg ^ other.neg,
+    /// Fallible division. Returns `ArithmeticError::DivisionByZero` if `other` is zero,
+    /// `ArithmeticError::Overflow` if the quotient overflows `u32` integer range.
+    #[inline(always)]
+    pub fn try_div(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        if other.is_zero() {
+            return Err(ArithmeticError::DivisionByZero);
         }
+        self.checked_div(other).ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Fallible remainder. Returns `ArithmeticError::DivisionByZero` if `other` is zero.
+    #[inline(always)]
+    pub fn try_rem(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        self.checked_rem(other).ok_or(ArithmeticError::DivisionByZero)
     }
 
     /// Checked addition. Returns `None` if the integer part overflows `u32`.
@@ -100,12 +71,11 @@ impl AncDec32 {
         let (a_frac, b_frac, scale, limit) = self.align_frac(other);
 
         if self.neg == other.neg {
-            let frac = a_frac + b_frac;
-            let overflow = (frac >= limit) as u32;
-            let int = self.int.checked_add(other.int)?.checked_add(overflow)?;
+            let (int, frac, scale) =
+                Self::add_aligned(self.int, a_frac, other.int, b_frac, scale, limit)?;
             Some(Self {
                 int,
-                frac: frac - overflow * limit,
+                frac,
                 scale,
                 neg: self.neg,
             })
@@ -118,11 +88,14 @@ impl AncDec32 {
 
     /// Checked subtraction. Returns `None` if the integer part overflows `u32`.
     ///
-    /// In practice, subtraction in the current design uses magnitude comparison
-    /// and cannot overflow, so this always returns `Some`.
+    /// Operands of equal sign go through magnitude comparison and cannot overflow;
+    /// opposite signs add magnitudes, which can.
     #[inline(always)]
     pub fn checked_sub(&self, other: &Self) -> Option<Self> {
-        Some(self.sub(other))
+        self.checked_add(&Self {
+            neg: !other.neg,
+            ..*other
+        })
     }
 
     /// Checked multiplication. Returns `None` if the result overflows `u32` integer range.
@@ -135,8 +108,11 @@ impl AncDec32 {
         let product = a as u128 * b as u128;
 
         let (result, final_scale) = if total_scale > TARGET_SCALE_32 {
-            let divisor = pow10_128(total_scale - TARGET_SCALE_32);
-            ((product / divisor) as u64, TARGET_SCALE_32)
+            let reduced = product / pow10_128(total_scale - TARGET_SCALE_32);
+            if reduced > u64::MAX as u128 {
+                return None;
+            }
+            (reduced as u64, TARGET_SCALE_32)
         } else if product <= u64::MAX as u128 {
             (product as u64, total_scale)
         } else {
@@ -146,44 +122,323 @@ impl AncDec32 {
         Self::checked_from_combined(result, final_scale, self.neg ^ other.neg)
     }
 
-    /// Like `from_combined` but returns `None` instead of panicking on overflow.
+    /// Checked division. Returns `None` if `other` is zero or the quotient overflows `u32` integer range.
     #[inline(always)]
-    fn checked_from_combined(n: u64, scale: u8, neg: bool) -> Option<Self> {
-        if scale == 0 {
-            if n > u32::MAX as u64 {
-                return None;
-            }
-            return Some(Self {
-                int: n as u32,
-                frac: 0,
-                scale: 0,
-                neg,
-            });
-        }
-        let divisor = pow10(scale);
-        let int_part = n / divisor;
-        if int_part > u32::MAX as u64 {
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        let a = self.int as u64 * pow10(self.scale) + self.frac as u64;
+        // NonZero divisor: the zero check also removes the implicit div-by-zero panic
+        let b = NonZeroU64::new(other.int as u64 * pow10(other.scale) + other.frac as u64)?;
+
+        let shift = TARGET_SCALE_32 + other.scale;
+
+        // numerator < 2^63 * 10^18 fits in u128, so the quotient needs no wide arithmetic
+        let quotient = if shift >= self.scale {
+            let exp = shift - self.scale;
+            let numerator = a as u128 * pow10_128(exp);
+            numerator / NonZeroU128::from(b)
+        } else {
+            (a / b / pow10(self.scale - shift)) as u128
+        };
+
+        let q = quotient / SCALE9 as u128;
+        if q > u32::MAX as u128 {
             return None;
         }
+        let r = quotient - q * SCALE9 as u128;
         Some(Self {
-            int: int_part as u32,
-            frac: (n % divisor) as u32,
-            scale,
-            neg,
+            int: q as u32,
+            frac: r as u32,
+            scale: TARGET_SCALE_32,
+            neg: self.neg ^ other.neg,
+        })
+    }
+
+    /// Checked remainder. Returns `None` if `other` is zero.
+    /// Computed exactly from one integer division, so it never overflows.
+    #[inline(always)]
+    pub fn checked_rem(&self, other: &Self) -> Option<Self> {
+        self.int_divmod(other, RoundMode::Truncate).map(|(_, _, r)| r)
+    }
+
+    /// Checked negation. Negation only flips the sign flag, so this always returns `Some`.
+    #[inline(always)]
+    pub fn checked_neg(&self) -> Option<Self> {
+        Some(Self {
+            neg: !self.neg,
+            ..*self
         })
     }
 
-    /// Computes the remainder (`self % other`), panics on division by zero.
+    /// Computes the remainder (`self % other`), panics on division by zero
+    /// (returns `ZERO` with `panic-free`).
     #[inline(always)]
     pub fn rem(&self, other: &Self) -> Self {
-        assert!(other.int != 0 || other.frac != 0, "division by zero");
-        let q = self.div(other);
-        let floored = Self {
-            int: q.int,
-            frac: 0,
-            scale: 0,
-            neg: q.neg,
-        };
-        self.sub(&floored.mul(other))
+        unwrap_or_saturate(self.try_rem(other), || Self::ZERO)
+    }
+
+    /// `MAX` with the given sign: the value every saturating operation clamps to.
+    #[inline(always)]
+    pub(crate) fn saturated(neg: bool) -> Self {
+        Self { neg, ..Self::MAX }
+    }
+
+    /// Saturating addition. Clamps to `MAX` or `-MAX` instead of overflowing.
+    #[inline(always)]
+    pub fn saturating_add(&self, other: &Self) -> Self {
+        self.overflowing_add(other).0
+    }
+
+    /// Saturating subtraction. Clamps to `MAX` or `-MAX` instead of overflowing.
+    #[inline(always)]
+    pub fn saturating_sub(&self, other: &Self) -> Self {
+        self.overflowing_sub(other).0
+    }
+
+    /// Saturating multiplication. Clamps to `MAX` or `-MAX` instead of overflowing.
+    #[inline(always)]
+    pub fn saturating_mul(&self, other: &Self) -> Self {
+        self.overflowing_mul(other).0
+    }
+
+    /// Saturating division. Clamps to `MAX` or `-MAX` instead of overflowing.
+    ///
+    /// Unlike the primitive integers, division by zero also saturates rather than panicking:
+    /// `x / 0` clamps by the sign of `x`, and `0 / 0` returns `ZERO`.
+    #[inline(always)]
+    pub fn saturating_div(&self, other: &Self) -> Self {
+        self.overflowing_div(other).0
+    }
+
+    /// Addition returning `(result, overflowed)`. On overflow the result is clamped to `MAX` or `-MAX`.
+    #[inline(always)]
+    pub fn overflowing_add(&self, other: &Self) -> (Self, bool) {
+        match self.checked_add(other) {
+            Some(v) => (v, false),
+            // magnitudes only grow when signs agree, so the overflow takes self's sign
+            None => (Self::saturated(self.neg), true),
+        }
+    }
+
+    /// Subtraction returning `(result, overflowed)`. On overflow the result is clamped to `MAX` or `-MAX`.
+    #[inline(always)]
+    pub fn overflowing_sub(&self, other: &Self) -> (Self, bool) {
+        match self.checked_sub(other) {
+            Some(v) => (v, false),
+            None => (Self::saturated(self.neg), true),
+        }
+    }
+
+    /// Multiplication returning `(result, overflowed)`. On overflow the result is clamped to `MAX` or `-MAX`.
+    #[inline(always)]
+    pub fn overflowing_mul(&self, other: &Self) -> (Self, bool) {
+        match self.checked_mul(other) {
+            Some(v) => (v, false),
+            None => (Self::saturated(self.neg ^ other.neg), true),
+        }
+    }
+
+    /// Division returning `(result, overflowed)`. On overflow or division by zero the result
+    /// is clamped to `MAX` or `-MAX` (`0 / 0` gives `ZERO`) and the flag is `true`.
+    #[inline(always)]
+    pub fn overflowing_div(&self, other: &Self) -> (Self, bool) {
+        match self.checked_div(other) {
+            Some(v) => (v, false),
+            None if self.int == 0 && self.frac == 0 => (Self::ZERO, true),
+            None => (Self::saturated(self.neg ^ other.neg), true),
+        }
+    }
+}
+
+// ============ Integer Division ============
+impl AncDec32 {
+    /// Truncated integer division: `(self / other).trunc()` and `self % other` from a
+    /// single integer division.
+    ///
+    /// Panics on division by zero or if the quotient overflows `u32` (saturates with `panic-free`).
+    #[inline(always)]
+    pub fn divmod(&self, other: &Self) -> (Self, Self) {
+        self.divmod_or_saturate(other, RoundMode::Truncate)
+    }
+
+    /// Floored division: `(self / other).floor()`. Panics like [`divmod`](Self::divmod).
+    #[inline(always)]
+    pub fn div_floor(&self, other: &Self) -> Self {
+        self.divmod_or_saturate(other, RoundMode::Floor).0
+    }
+
+    /// Floored remainder `self - other * self.div_floor(other)`, which takes the sign of `other`.
+    /// Panics on division by zero (returns `ZERO` with `panic-free`).
+    #[inline(always)]
+    pub fn mod_floor(&self, other: &Self) -> Self {
+        self.rem_or_zero(other, RoundMode::Floor)
+    }
+
+    /// Euclidean division: the integer `q` with `self = q * other + r` and `0 <= r < |other|`.
+    /// Panics like [`divmod`](Self::divmod).
+    #[inline(always)]
+    pub fn div_euclid(&self, other: &Self) -> Self {
+        self.divmod_or_saturate(other, Self::euclid_mode(other)).0
+    }
+
+    /// Euclidean remainder, always in `0 <= r < |other|`.
+    /// Panics on division by zero (returns `ZERO` with `panic-free`).
+    #[inline(always)]
+    pub fn rem_euclid(&self, other: &Self) -> Self {
+        self.rem_or_zero(other, Self::euclid_mode(other))
+    }
+
+    /// Checked [`divmod`](Self::divmod). Returns `None` if `other` is zero or the quotient overflows `u32`.
+    #[inline(always)]
+    pub fn checked_divmod(&self, other: &Self) -> Option<(Self, Self)> {
+        self.try_int_divmod(other, RoundMode::Truncate).ok()
+    }
+
+    /// Checked [`div_floor`](Self::div_floor). Returns `None` if `other` is zero or the quotient overflows `u32`.
+    #[inline(always)]
+    pub fn checked_div_floor(&self, other: &Self) -> Option<Self> {
+        self.try_int_divmod(other, RoundMode::Floor).ok().map(|(q, _)| q)
+    }
+
+    /// Checked [`mod_floor`](Self::mod_floor). Returns `None` if `other` is zero.
+    #[inline(always)]
+    pub fn checked_mod_floor(&self, other: &Self) -> Option<Self> {
+        self.int_divmod(other, RoundMode::Floor).map(|(_, _, r)| r)
+    }
+
+    /// Checked [`div_euclid`](Self::div_euclid). Returns `None` if `other` is zero or the quotient overflows `u32`.
+    #[inline(always)]
+    pub fn checked_div_euclid(&self, other: &Self) -> Option<Self> {
+        self.try_int_divmod(other, Self::euclid_mode(other)).ok().map(|(q, _)| q)
+    }
+
+    /// Checked [`rem_euclid`](Self::rem_euclid). Returns `None` if `other` is zero.
+    #[inline(always)]
+    pub fn checked_rem_euclid(&self, other: &Self) -> Option<Self> {
+        self.int_divmod(other, Self::euclid_mode(other)).map(|(_, _, r)| r)
+    }
+
+    /// Truncated integer quotient as `i128`, with the exact remainder.
+    /// Returns `None` if `other` is zero.
+    #[inline(always)]
+    pub fn divmod_i128(&self, other: &Self) -> Option<(i128, Self)> {
+        let (q, neg, r) = self.int_divmod(other, RoundMode::Truncate)?;
+        Some((signed_i128(q as u128, neg)?, r))
+    }
+
+    /// Floored integer quotient as `i128`. Returns `None` if `other` is zero.
+    #[inline(always)]
+    pub fn div_floor_i128(&self, other: &Self) -> Option<i128> {
+        let (q, neg, _) = self.int_divmod(other, RoundMode::Floor)?;
+        signed_i128(q as u128, neg)
+    }
+
+    /// Euclidean integer quotient as `i128`. Returns `None` if `other` is zero.
+    #[inline(always)]
+    pub fn div_euclid_i128(&self, other: &Self) -> Option<i128> {
+        let (q, neg, _) = self.int_divmod(other, Self::euclid_mode(other))?;
+        signed_i128(q as u128, neg)
+    }
+
+    /// The Euclidean quotient rounds down for positive divisors and up for negative ones.
+    #[inline(always)]
+    fn euclid_mode(other: &Self) -> RoundMode {
+        if other.neg {
+            RoundMode::Ceil
+        } else {
+            RoundMode::Floor
+        }
+    }
+
+    /// `int_divmod` with the quotient converted to a decimal.
+    #[inline(always)]
+    fn try_int_divmod(&self, other: &Self, mode: RoundMode) -> Result<(Self, Self), ArithmeticError> {
+        let (q, neg, r) = self.int_divmod(other, mode).ok_or(ArithmeticError::DivisionByZero)?;
+        let q = Self::checked_from_combined(q, 0, neg).ok_or(ArithmeticError::Overflow)?;
+        Ok((q, r))
+    }
+
+    /// Panicking `try_int_divmod`. With `panic-free` an overflowing quotient clamps to the
+    /// integer part of `MAX` and keeps the exact remainder; division by zero falls back like `div`/`rem`.
+    #[inline(always)]
+    fn divmod_or_saturate(&self, other: &Self, mode: RoundMode) -> (Self, Self) {
+        unwrap_or_saturate(self.try_int_divmod(other, mode), || match self.int_divmod(other, mode) {
+            Some((_, neg, r)) => (Self::saturated(neg).trunc(), r),
+            None => (self.saturating_div(other).trunc(), Self::ZERO),
+        })
+    }
+
+    /// Remainder of `int_divmod`, which never overflows. Panics on division by zero
+    /// (returns `ZERO` with `panic-free`).
+    #[inline(always)]
+    fn rem_or_zero(&self, other: &Self, mode: RoundMode) -> Self {
+        let r = self.int_divmod(other, mode).map(|(_, _, r)| r);
+        unwrap_or_saturate(r.ok_or(ArithmeticError::DivisionByZero), || Self::ZERO)
+    }
+
+    /// Divides the magnitudes aligned to their common scale. Returns the integer quotient
+    /// magnitude rounded by `mode` (`Fract` truncates), its sign, and the exact remainder
+    /// `self - q * other`. `None` if `other` is zero.
+    #[inline(always)]
+    pub(crate) fn int_divmod(&self, other: &Self, mode: RoundMode) -> Option<(u64, bool, Self)> {
+        let scale = self.scale.max(other.scale);
+        // int * 10^scale + frac stays below 2^32 * 10^9 < 2^64
+        let a = (self.int as u64) * pow10(scale) + (self.frac as u64) * pow10(scale - self.scale);
+        let b = NonZeroU64::new(
+            (other.int as u64) * pow10(scale) + (other.frac as u64) * pow10(scale - other.scale),
+        )?;
+        let (mut q, mut r) = (a / b, a % b);
+
+        // rounding the quotient one step away from zero leaves `b - r`, with the sign opposite
+        // to `self`
+        let neg = self.neg ^ other.neg;
+        let up = mode.rounds_up(Residue::new(r as u128, b.get() as u128), neg, (q % 10) as u8);
+        if up {
+            q += 1;
+            r = b.get() - r;
+        }
+        let rem = Self::checked_from_combined(r, scale, (self.neg ^ up) && r != 0)?;
+        Some((q, neg && q != 0, rem))
+    }
+}
+
+// ============ Exact Arithmetic ============
+impl AncDec32 {
+    /// Addition that never alters the value. Sums always fit the wider operand scale, so
+    /// this fails only with `ArithmeticError::Overflow`, like [`try_add`](Self::try_add).
+    #[inline(always)]
+    pub fn add_exact(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        self.try_add(other)
+    }
+
+    /// Subtraction that never alters the value; see [`add_exact`](Self::add_exact).
+    #[inline(always)]
+    pub fn sub_exact(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        self.try_sub(other)
+    }
+
+    /// Multiplication that fails with `ArithmeticError::Inexact` instead of truncating
+    /// nonzero digits beyond scale 9. `ArithmeticError::Overflow` if the integer part
+    /// overflows `u32`.
+    pub fn mul_exact(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        match self.mul_round_exact(other, TARGET_SCALE_32, RoundMode::Truncate) {
+            Some((p, true)) => Ok(p),
+            Some((_, false)) => Err(ArithmeticError::Inexact),
+            None => Err(ArithmeticError::Overflow),
+        }
+    }
+
+    /// Division that fails with `ArithmeticError::Inexact` unless the quotient terminates
+    /// within 9 places; exact quotients keep their minimal scale (`1 / 4` gives `0.25`).
+    /// `ArithmeticError::DivisionByZero` if `other` is zero, `ArithmeticError::Overflow`
+    /// if the quotient overflows `u32`.
+    pub fn div_exact(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        if other.is_zero() {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        match self.div_round_exact(other, TARGET_SCALE_32, RoundMode::Truncate) {
+            Some((q, true)) => Ok(q.normalize()),
+            Some((_, false)) => Err(ArithmeticError::Inexact),
+            None => Err(ArithmeticError::Overflow),
+        }
     }
 }
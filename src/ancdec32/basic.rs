@@ -1,6 +1,10 @@
+use crate::error::{unwrap_or_saturate, ArithmeticError};
+use crate::fixed::{self, Fixed};
 use super::AncDec32;
-use crate::util::pow10;
-use crate::wide::isqrt_u128;
+use crate::round_mode::Residue;
+use crate::util::{pow10, TARGET_SCALE_32};
+use crate::RoundMode;
+use crate::wide::{isqrt_u128, nth_root_decimal};
 
 impl AncDec32 {
     /// Returns the absolute value.
@@ -47,6 +51,12 @@ impl AncDec32 {
         self.int == 0 && self.frac == 0
     }
 
+    /// Returns `true` if the value has no fractional part (`2.00` is an integer).
+    #[inline(always)]
+    pub fn is_integer(&self) -> bool {
+        self.frac == 0
+    }
+
     /// Returns the smaller of `self` and `other`.
     #[inline(always)]
     pub fn min(self, other: Self) -> Self {
@@ -79,11 +89,53 @@ impl AncDec32 {
         }
     }
 
-    /// Returns the square root with 8 fractional digits of precision. Panics if negative.
+    /// Unit in the last place at the value's own scale: `10^-scale` (`1.25` gives `0.01`,
+    /// `3` gives `1`). Always positive. `next_up`/`next_down` step by `EPSILON` instead.
+    #[inline(always)]
+    pub fn ulp(&self) -> Self {
+        if self.scale == 0 {
+            return Self::ONE;
+        }
+        Self {
+            int: 0,
+            frac: 1,
+            scale: self.scale,
+            neg: false,
+        }
+    }
+
+    /// The next representable value above `self`: `self + EPSILON` at scale 9, whatever the
+    /// scale of `self`. Not `self + self.ulp()`, which steps at the value's own scale.
+    ///
+    /// Panics at `MAX` (saturates with `panic-free`).
+    #[inline(always)]
+    pub fn next_up(&self) -> Self {
+        *self + Self::EPSILON
+    }
+
+    /// The next representable value below `self`: `self - EPSILON` at scale 9, whatever the
+    /// scale of `self`. Not `self - self.ulp()`, which steps at the value's own scale.
+    ///
+    /// Panics at `MIN` (saturates with `panic-free`).
+    #[inline(always)]
+    pub fn next_down(&self) -> Self {
+        *self - Self::EPSILON
+    }
+
+    /// Returns the square root with 8 fractional digits of precision. Panics if negative
+    /// (returns `ZERO` with `panic-free`).
+    #[inline(always)]
     pub fn sqrt(&self) -> Self {
-        assert!(!self.neg || self.is_zero(), "square root of negative number");
+        unwrap_or_saturate(self.try_sqrt(), || Self::ZERO)
+    }
+
+    /// Fallible square root. Returns `ArithmeticError::NegativeSqrt` if `self` is negative.
+    pub fn try_sqrt(&self) -> Result<Self, ArithmeticError> {
+        if self.neg && !self.is_zero() {
+            return Err(ArithmeticError::NegativeSqrt);
+        }
         if self.is_zero() {
-            return Self::ZERO;
+            return Ok(Self::ZERO);
         }
 
         // combined = int * 10^scale + frac (u64)
@@ -95,36 +147,362 @@ impl AncDec32 {
         let x = isqrt_u128(n);
 
         let scale8 = pow10(8);
-        Self {
+        Ok(Self {
             int: (x / scale8 as u128) as u32,
             frac: (x % scale8 as u128) as u32,
             scale: 8,
             neg: false,
+        })
+    }
+
+    /// Checked square root. Returns `None` if `self` is negative.
+    #[inline(always)]
+    pub fn checked_sqrt(&self) -> Option<Self> {
+        self.try_sqrt().ok()
+    }
+
+    /// Square root rounded once to `scale` decimal places (capped at 9) with `mode`. The
+    /// last digit is decided exactly from the integer square root's remainder, so the result
+    /// is correctly rounded; it always has `scale` decimal places. `RoundMode::Fract` truncates.
+    ///
+    /// Panics if `self` is negative (returns `ZERO` with `panic-free`).
+    #[inline(always)]
+    pub fn sqrt_round(&self, scale: u8, mode: RoundMode) -> Self {
+        unwrap_or_saturate(
+            self.checked_sqrt_round(scale, mode).ok_or(ArithmeticError::NegativeSqrt),
+            || Self::ZERO,
+        )
+    }
+
+    /// Checked [`sqrt_round`](Self::sqrt_round). Returns `None` if `self` is negative.
+    pub fn checked_sqrt_round(&self, scale: u8, mode: RoundMode) -> Option<Self> {
+        if self.neg && !self.is_zero() {
+            return None;
         }
+        let scale = scale.min(TARGET_SCALE_32);
+        // the smallest working scale at which n = value * 10^(2 * work) is an integer
+        let work = scale.max(self.scale.div_ceil(2));
+        let combined = self.int as u64 * pow10(self.scale) + self.frac as u64;
+        // value * 10^18 < 2^128
+        let n = combined as u128 * pow10(2 * work - self.scale) as u128;
+        let x = isqrt_u128(n);
+        let r = n - x * x;
+
+        let (q, residue) = if work == scale {
+            // (2x + 1)^2 is odd, so sqrt(n) is never exactly x + 1/2: above half iff r > x
+            let residue = match r {
+                0 => Residue::Zero,
+                r if r > x => Residue::AboveHalf,
+                _ => Residue::BelowHalf,
+            };
+            (x, residue)
+        } else {
+            let d = pow10(work - scale) as u128;
+            (x / d, Residue::new(x % d, d).with_sticky(r != 0))
+        };
+        let q = q + mode.rounds_up(residue, false, (q % 10) as u8) as u128;
+        // sqrt(2^32) * 10^9 < 2^64
+        Self::checked_from_combined(q as u64, scale, false)
+    }
+
+    /// Returns the cube root with 8 fractional digits of precision, truncated like
+    /// [`sqrt`](Self::sqrt). Negative numbers have negative cube roots.
+    #[inline(always)]
+    pub fn cbrt(&self) -> Self {
+        // odd roots exist for every input and never exceed it in magnitude
+        self.checked_nth_root(3).unwrap_or(Self::ZERO)
+    }
+
+    /// Returns the `n`-th root with 8 fractional digits of precision, truncated like
+    /// [`sqrt`](Self::sqrt): the digits are exact, taken from an integer k-th root of the
+    /// scaled value. Odd roots of negative numbers are negative. Above `n = 144` the
+    /// result keeps fewer fractional digits, as many as the 4096-bit working width allows.
+    ///
+    /// Panics if `n` is zero, or `n` is even and `self` negative (returns `ZERO` with `panic-free`).
+    #[inline(always)]
+    pub fn nth_root(&self, n: u32) -> Self {
+        unwrap_or_saturate(self.try_nth_root(n), || Self::ZERO)
+    }
+
+    /// Fallible `n`-th root. Returns `ArithmeticError::OutOfDomain` if `n` is zero,
+    /// `ArithmeticError::NegativeSqrt` if `n` is even and `self` negative.
+    pub fn try_nth_root(&self, n: u32) -> Result<Self, ArithmeticError> {
+        if n == 0 {
+            return Err(ArithmeticError::OutOfDomain);
+        }
+        if n % 2 == 0 && self.is_negative() {
+            return Err(ArithmeticError::NegativeSqrt);
+        }
+        let (root, scale) = nth_root_decimal(self.wide_combined(), self.scale, n, 8);
+        let root = match root {
+            (0, n) if n <= u64::MAX as u128 => Self::checked_from_combined(n as u64, scale, self.is_negative()),
+            _ => None,
+        };
+        root.ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Checked `n`-th root. Returns `None` if `n` is zero, or `n` is even and `self` negative.
+    #[inline(always)]
+    pub fn checked_nth_root(&self, n: u32) -> Option<Self> {
+        self.try_nth_root(n).ok()
     }
 
     /// Raises `self` to the power `n` using binary exponentiation. Supports negative exponents.
+    ///
+    /// Panics if `self` is zero and `n` is negative, or if the result overflows
+    /// (saturates with `panic-free`, see [`saturating_pow`](Self::saturating_pow)).
+    #[inline(always)]
     pub fn pow(&self, n: i32) -> Self {
+        unwrap_or_saturate(self.try_pow(n), || self.saturating_pow(n))
+    }
+
+    /// Fallible exponentiation. Returns `ArithmeticError::DivisionByZero` if `self` is zero and
+    /// `n` is negative, `ArithmeticError::Overflow` if the result overflows.
+    #[inline(always)]
+    pub fn try_pow(&self, n: i32) -> Result<Self, ArithmeticError> {
+        if n < 0 && self.is_zero() {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        self.checked_pow(n).ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Checked exponentiation. Returns `None` on overflow, or if `self` is zero and `n` is negative.
+    #[inline(always)]
+    pub fn checked_pow(&self, n: i32) -> Option<Self> {
+        self.checked_powi(n as i64)
+    }
+
+    /// Raises `self` to a 64-bit integer power, like [`pow`](Self::pow).
+    ///
+    /// Panics if `self` is zero and `n` is negative, or if the result overflows
+    /// (saturates with `panic-free`).
+    #[inline(always)]
+    pub fn powi(&self, n: i64) -> Self {
+        unwrap_or_saturate(self.try_powi(n), || {
+            Self::saturated(self.is_negative() && n % 2 != 0)
+        })
+    }
+
+    /// Fallible [`powi`](Self::powi). Returns `ArithmeticError::DivisionByZero` if `self` is
+    /// zero and `n` is negative, `ArithmeticError::Overflow` if the result overflows.
+    #[inline(always)]
+    pub fn try_powi(&self, n: i64) -> Result<Self, ArithmeticError> {
+        if n < 0 && self.is_zero() {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        self.checked_powi(n).ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Checked exponentiation with a 64-bit exponent. Returns `None` on overflow, or if `self`
+    /// is zero and `n` is negative.
+    ///
+    /// Stops at the first overflowing step, and as soon as the running power truncates to
+    /// zero, so any exponent costs at most 63 squarings.
+    pub fn checked_powi(&self, n: i64) -> Option<Self> {
         if n == 0 {
-            return Self::ONE;
+            return Some(Self::ONE);
         }
 
         let mut base = if n < 0 {
-            assert!(!self.is_zero(), "division by zero in pow with negative exponent");
-            Self::ONE.div(self)
+            Self::ONE.checked_div(self)?
         } else {
             *self
         };
         let mut exp = n.unsigned_abs();
         let mut result = Self::ONE;
 
-        while exp > 0 {
+        loop {
             if exp % 2 == 1 {
-                result = result.mul(&base);
+                result = result.checked_mul(&base)?;
             }
-            base = base.mul(&base);
             exp /= 2;
+            if exp == 0 {
+                return Some(result);
+            }
+            // A remaining bit multiplies in a power of zero
+            if base.is_zero() {
+                return Some(Self::ZERO);
+            }
+            // Square only when another bit remains, so the final step cannot overflow spuriously
+            base = base.checked_mul(&base)?;
+        }
+    }
+
+    /// Saturating exponentiation. Clamps to `MAX` or `-MAX` instead of overflowing.
+    ///
+    /// Zero raised to a negative power saturates to `MAX`.
+    #[inline(always)]
+    pub fn saturating_pow(&self, n: i32) -> Self {
+        self.overflowing_pow(n).0
+    }
+
+    /// Exponentiation returning `(result, overflowed)`. On overflow the result is clamped to
+    /// `MAX` or `-MAX`, with the sign the exact power would have had.
+    pub fn overflowing_pow(&self, n: i32) -> (Self, bool) {
+        match self.checked_pow(n) {
+            Some(v) => (v, false),
+            None => (Self::saturated(self.is_negative() && n % 2 != 0), true),
+        }
+    }
+}
+
+// ============ Trigonometric and Hyperbolic ============
+impl AncDec32 {
+    /// `self` as the u256 combined magnitude the fixed-point engine takes
+    #[inline(always)]
+    fn wide_combined(&self) -> (u128, u128) {
+        (0, (self.int as u64 * pow10(self.scale) + self.frac as u64) as u128)
+    }
+
+    /// Rebuilds a result of the fixed-point engine (rounded at scale 9), trailing zeros stripped
+    #[inline(always)]
+    fn from_fixed(r: Option<((u128, u128), bool)>) -> Option<Self> {
+        match r? {
+            ((0, n), neg) if n <= u64::MAX as u128 => {
+                Self::checked_from_combined(n as u64, TARGET_SCALE_32, neg).map(|v| v.normalize())
+            }
+            _ => None,
         }
-        result
+    }
+
+    /// Rounds an engine value known to fit (`|v| <= π`) to the maximum scale
+    #[inline(always)]
+    fn from_bounded(v: Fixed) -> Self {
+        Self::from_fixed(v.to_decimal(0, TARGET_SCALE_32)).unwrap_or(Self::ZERO)
+    }
+
+    /// Returns the sine of `self` (in radians), rounded half-even to 9 decimal places.
+    pub fn sin(&self) -> Self {
+        Self::from_bounded(fixed::sin_decimal(self.wide_combined(), self.scale, self.neg))
+    }
+
+    /// Returns the cosine of `self` (in radians), rounded half-even to 9 decimal places.
+    pub fn cos(&self) -> Self {
+        Self::from_bounded(fixed::cos_decimal(self.wide_combined(), self.scale))
+    }
+
+    /// Returns the tangent of `self` (in radians), rounded half-even to 9 decimal places.
+    ///
+    /// Panics if the result overflows (saturates with `panic-free`).
+    #[inline(always)]
+    pub fn tan(&self) -> Self {
+        unwrap_or_saturate(self.try_tan(), || {
+            Self::saturated(self.sin().is_negative() != self.cos().is_negative())
+        })
+    }
+
+    /// Fallible tangent. Returns `ArithmeticError::Overflow` if the result overflows.
+    #[inline(always)]
+    pub fn try_tan(&self) -> Result<Self, ArithmeticError> {
+        self.checked_tan().ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Checked tangent. Returns `None` on overflow.
+    pub fn checked_tan(&self) -> Option<Self> {
+        Self::from_fixed(fixed::tan_decimal(self.wide_combined(), self.scale, self.neg, TARGET_SCALE_32))
+    }
+
+    /// Returns the arcsine in radians, in `[-π/2, π/2]`, rounded half-even to 9 decimal places.
+    ///
+    /// Panics if `|self| > 1` (returns `ZERO` with `panic-free`).
+    #[inline(always)]
+    pub fn asin(&self) -> Self {
+        unwrap_or_saturate(self.try_asin(), || Self::ZERO)
+    }
+
+    /// Fallible arcsine. Returns `ArithmeticError::OutOfDomain` if `|self| > 1`.
+    #[inline(always)]
+    pub fn try_asin(&self) -> Result<Self, ArithmeticError> {
+        self.checked_asin().ok_or(ArithmeticError::OutOfDomain)
+    }
+
+    /// Checked arcsine. Returns `None` if `|self| > 1`.
+    pub fn checked_asin(&self) -> Option<Self> {
+        if self.abs() > Self::ONE {
+            return None;
+        }
+        let x = Fixed::from_decimal(self.wide_combined(), self.scale, self.neg)?;
+        Some(Self::from_bounded(fixed::asin(x)))
+    }
+
+    /// Returns the arccosine in radians, in `[0, π]`, rounded half-even to 9 decimal places.
+    ///
+    /// Panics if `|self| > 1` (returns `ZERO` with `panic-free`).
+    #[inline(always)]
+    pub fn acos(&self) -> Self {
+        unwrap_or_saturate(self.try_acos(), || Self::ZERO)
+    }
+
+    /// Fallible arccosine. Returns `ArithmeticError::OutOfDomain` if `|self| > 1`.
+    #[inline(always)]
+    pub fn try_acos(&self) -> Result<Self, ArithmeticError> {
+        self.checked_acos().ok_or(ArithmeticError::OutOfDomain)
+    }
+
+    /// Checked arccosine. Returns `None` if `|self| > 1`.
+    pub fn checked_acos(&self) -> Option<Self> {
+        if self.abs() > Self::ONE {
+            return None;
+        }
+        let x = Fixed::from_decimal(self.wide_combined(), self.scale, self.neg)?;
+        Some(Self::from_bounded(fixed::acos(x)))
+    }
+
+    /// Returns the arctangent in radians, in `(-π/2, π/2)`, rounded half-even to 9 decimal places.
+    #[inline(always)]
+    pub fn atan(&self) -> Self {
+        self.atan2(&Self::ONE)
+    }
+
+    /// Returns the angle of the point `(other, self)` in radians, in `(-π, π]`, like
+    /// `f64::atan2` with `self` as `y`. Rounded half-even to 9 decimal places;
+    /// `atan2` of two zeros is zero.
+    pub fn atan2(&self, other: &Self) -> Self {
+        let y = (self.wide_combined(), self.scale, self.is_negative());
+        let x = (other.wide_combined(), other.scale, other.is_negative());
+        Self::from_bounded(fixed::atan2_decimal(y, x))
+    }
+
+    /// Returns the hyperbolic sine, rounded half-even to 9 decimal places.
+    ///
+    /// Panics if the result overflows (saturates with `panic-free`).
+    #[inline(always)]
+    pub fn sinh(&self) -> Self {
+        unwrap_or_saturate(self.try_sinh(), || Self::saturated(self.is_negative()))
+    }
+
+    /// Fallible hyperbolic sine. Returns `ArithmeticError::Overflow` if the result overflows.
+    #[inline(always)]
+    pub fn try_sinh(&self) -> Result<Self, ArithmeticError> {
+        self.checked_sinh().ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Checked hyperbolic sine. Returns `None` on overflow.
+    pub fn checked_sinh(&self) -> Option<Self> {
+        Self::from_fixed(fixed::sinh_decimal(self.wide_combined(), self.scale, self.neg, TARGET_SCALE_32))
+    }
+
+    /// Returns the hyperbolic cosine, rounded half-even to 9 decimal places.
+    ///
+    /// Panics if the result overflows (returns `MAX` with `panic-free`).
+    #[inline(always)]
+    pub fn cosh(&self) -> Self {
+        unwrap_or_saturate(self.try_cosh(), || Self::MAX)
+    }
+
+    /// Fallible hyperbolic cosine. Returns `ArithmeticError::Overflow` if the result overflows.
+    #[inline(always)]
+    pub fn try_cosh(&self) -> Result<Self, ArithmeticError> {
+        self.checked_cosh().ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Checked hyperbolic cosine. Returns `None` on overflow.
+    pub fn checked_cosh(&self) -> Option<Self> {
+        Self::from_fixed(fixed::cosh_decimal(self.wide_combined(), self.scale, TARGET_SCALE_32))
+    }
+
+    /// Returns the hyperbolic tangent, rounded half-even to 9 decimal places.
+    pub fn tanh(&self) -> Self {
+        Self::from_fixed(fixed::tanh_decimal(self.wide_combined(), self.scale, self.neg, TARGET_SCALE_32)).unwrap_or(Self::ZERO)
     }
 }
@@ -1,4 +1,5 @@
 use super::AncDec32;
+use crate::util::pow10_32;
 use core::fmt;
 
 /// Display trait: enables `format!`, `println!`, `to_string()`
@@ -13,7 +14,7 @@ impl fmt::Display for AncDec32 {
             } else if self.scale == 0 {
                 write!(f, "{}{}.{:0>w$}", sign, self.int, "", w = prec)
             } else if prec <= self.scale as usize {
-                let div = 10u32.pow((self.scale as u32) - (prec as u32));
+                let div = pow10_32(self.scale - prec as u8);
                 write!(f, "{}{}.{:0>w$}", sign, self.int, self.frac / div, w = prec)
             } else {
                 write!(
@@ -3,13 +3,15 @@ mod basic;
 mod cmp;
 mod convert;
 mod fmt_impl;
+mod num;
 mod ops;
 mod rounding;
 
 #[cfg(feature = "serde")]
 mod serde_impl;
 
-use crate::error::ParseError;
+use crate::context::{Signal, Signals};
+use crate::error::{unwrap_or_saturate, ArithmeticError, ParseError};
 use crate::util::{pow10_32, StackBuf, TARGET_SCALE_32};
 use core::fmt::{Display, Write};
 
@@ -69,16 +71,90 @@ impl AncDec32 {
         scale: 9,
         neg: false,
     };
+    /// The minimum representable value (`-4294967295.999999999`, `-MAX`).
+    pub const MIN: AncDec32 = AncDec32 {
+        int: u32::MAX,
+        frac: 999_999_999,
+        scale: 9,
+        neg: true,
+    };
+    /// The smallest positive value (`0.000000001`).
+    pub const MIN_POSITIVE: AncDec32 = AncDec32 {
+        int: 0,
+        frac: 1,
+        scale: 9,
+        neg: false,
+    };
+    /// The difference between `1` and the next larger representable value. Values are
+    /// evenly spaced at the maximum scale, so this equals [`MIN_POSITIVE`](Self::MIN_POSITIVE).
+    pub const EPSILON: AncDec32 = Self::MIN_POSITIVE;
+
+    // Mathematical constants mirroring `core::f64::consts`, correctly rounded
+    // (half-even) to the maximum scale of 9 places.
+
+    /// Archimedes' constant (π): `3.141592654`.
+    pub const PI: AncDec32 = AncDec32 {
+        int: 3,
+        frac: 141_592_654,
+        scale: 9,
+        neg: false,
+    };
+    /// Euler's number (e): `2.718281828`.
+    pub const E: AncDec32 = AncDec32 {
+        int: 2,
+        frac: 718_281_828,
+        scale: 9,
+        neg: false,
+    };
+    /// ln(2): `0.693147181`.
+    pub const LN_2: AncDec32 = AncDec32 {
+        int: 0,
+        frac: 693_147_181,
+        scale: 9,
+        neg: false,
+    };
+    /// ln(10): `2.302585093`.
+    pub const LN_10: AncDec32 = AncDec32 {
+        int: 2,
+        frac: 302_585_093,
+        scale: 9,
+        neg: false,
+    };
+    /// sqrt(2): `1.414213562`.
+    pub const SQRT_2: AncDec32 = AncDec32 {
+        int: 1,
+        frac: 414_213_562,
+        scale: 9,
+        neg: false,
+    };
+    /// 1/sqrt(2): `0.707106781`.
+    pub const FRAC_1_SQRT_2: AncDec32 = AncDec32 {
+        int: 0,
+        frac: 707_106_781,
+        scale: 9,
+        neg: false,
+    };
 }
 
 // ============ Constructor / Accessors ============
 impl AncDec32 {
-    /// Creates a new `AncDec32`. Panics if `scale > 9` or `frac >= 10^scale`.
+    /// Creates a new `AncDec32`. Panics if `scale > 9` or `frac >= 10^scale`
+    /// (with `panic-free`, clamps `scale` to 9 and `frac` to `10^scale - 1`).
     #[inline(always)]
     pub fn new(int: u32, frac: u32, scale: u8, neg: bool) -> Self {
-        assert!(scale <= 9, "scale must be <= 9");
-        assert!(frac < pow10_32(scale), "frac must be < 10^scale");
-        Self { int, frac, scale, neg }
+        unwrap_or_saturate(Self::try_new(int, frac, scale, neg), || {
+            let scale = scale.min(9);
+            Self { int, frac: frac.min(pow10_32(scale) - 1), scale, neg }
+        })
+    }
+
+    /// Fallible constructor. Returns `ArithmeticError::ScaleOverflow` if `scale > 9` or `frac >= 10^scale`.
+    #[inline(always)]
+    pub fn try_new(int: u32, frac: u32, scale: u8, neg: bool) -> Result<Self, ArithmeticError> {
+        if scale > 9 || frac >= pow10_32(scale) {
+            return Err(ArithmeticError::ScaleOverflow);
+        }
+        Ok(Self { int, frac, scale, neg })
     }
 
     /// Returns the integer part.
@@ -115,8 +191,25 @@ impl AncDec32 {
         Self::parse_str(buf.as_str())
     }
 
+    /// Parses like `from_str`, but fails with `ParseError::Inexact` instead of dropping
+    /// nonzero fractional digits beyond scale 9. Dropped trailing zeros are accepted.
+    #[inline(always)]
+    pub fn parse_exact(s: &str) -> Result<Self, ParseError> {
+        match Self::parse_str_signals(s)? {
+            (_, signals) if signals.contains(Signal::Inexact) => Err(ParseError::Inexact),
+            (v, _) => Ok(v),
+        }
+    }
+
     /// Byte-level string parsing with validation
+    #[inline(always)]
     pub(crate) fn parse_str(s: &str) -> Result<Self, ParseError> {
+        Self::parse_str_signals(s).map(|(v, _)| v)
+    }
+
+    /// `parse_str`, also reporting dropped fractional digits as `Clamped` (plus `Inexact`
+    /// if any of them was nonzero)
+    pub(crate) fn parse_str_signals(s: &str) -> Result<(Self, Signals), ParseError> {
         let b = s.as_bytes();
         let len = b.len();
         if len == 0 {
@@ -154,6 +247,7 @@ impl AncDec32 {
         // parse fractional part (truncate at 9 digits)
         let mut frac: u32 = 0;
         let mut frac_digits: u8 = 0;
+        let mut signals = Signals::EMPTY;
         while i < len {
             let d = unsafe { *b.get_unchecked(i) }.wrapping_sub(b'0');
             if d > 9 {
@@ -162,6 +256,11 @@ impl AncDec32 {
             if frac_digits < TARGET_SCALE_32 {
                 frac = frac * 10 + d as u32;
                 frac_digits += 1;
+            } else {
+                signals.insert(Signal::Clamped);
+                if d != 0 {
+                    signals.insert(Signal::Inexact);
+                }
             }
             i += 1;
         }
@@ -174,12 +273,15 @@ impl AncDec32 {
             return Err(ParseError::TrailingChars);
         }
 
-        Ok(Self {
-            int,
-            frac,
-            scale: frac_digits,
-            neg,
-        })
+        Ok((
+            Self {
+                int,
+                frac,
+                scale: frac_digits,
+                neg,
+            },
+            signals,
+        ))
     }
 
     /// Align fractional parts to same scale, returns (self_frac, other_frac, scale, limit)
@@ -207,7 +309,7 @@ impl AncDec32 {
         }
     }
 
-    /// Add aligned values (same sign), handles frac overflow
+    /// Add aligned values (same sign), handles frac overflow. Returns None on integer overflow
     #[inline(always)]
     pub(crate) fn add_aligned(
         a_int: u32,
@@ -216,13 +318,11 @@ impl AncDec32 {
         b_frac: u32,
         scale: u8,
         limit: u32,
-    ) -> (u32, u32, u8) {
+    ) -> Option<(u32, u32, u8)> {
         let frac = a_frac + b_frac;
         let overflow = (frac >= limit) as u32;
-        let int = a_int.checked_add(b_int)
-            .and_then(|v| v.checked_add(overflow))
-            .expect("integer overflow in addition");
-        (int, frac - overflow * limit, scale)
+        let int = a_int.checked_add(b_int)?.checked_add(overflow)?;
+        Some((int, frac - overflow * limit, scale))
     }
 
     /// Subtract with magnitude comparison, returns result with correct sign
@@ -257,26 +357,39 @@ impl AncDec32 {
         }
     }
 
-    /// Split combined u64 back to int/frac
+    /// Split combined u64 back to int/frac. Returns None if the integer part overflows
     #[inline(always)]
-    pub(crate) fn from_combined(n: u64, scale: u8, neg: bool) -> Self {
+    pub(crate) fn checked_from_combined(n: u64, scale: u8, neg: bool) -> Option<Self> {
         if scale == 0 {
-            assert!(n <= u32::MAX as u64, "integer overflow in from_combined");
-            return Self {
+            if n > u32::MAX as u64 {
+                return None;
+            }
+            return Some(Self {
                 int: n as u32,
                 frac: 0,
                 scale: 0,
                 neg,
-            };
+            });
         }
         let divisor = pow10_32(scale) as u64;
         let int_part = n / divisor;
-        assert!(int_part <= u32::MAX as u64, "integer overflow in from_combined");
-        Self {
+        if int_part > u32::MAX as u64 {
+            return None;
+        }
+        Some(Self {
             int: int_part as u32,
             frac: (n % divisor) as u32,
             scale,
             neg,
-        }
+        })
+    }
+
+    /// Like `checked_from_combined`, panics on overflow (saturates with `panic-free`)
+    #[inline(always)]
+    pub(crate) fn from_combined(n: u64, scale: u8, neg: bool) -> Self {
+        unwrap_or_saturate(
+            Self::checked_from_combined(n, scale, neg).ok_or(ArithmeticError::Overflow),
+            || Self::saturated(neg),
+        )
     }
 }
@@ -1,5 +1,4 @@
 use super::AncDec32;
-use crate::util::pow10;
 use core::hash::{Hash, Hasher};
 use core::iter::{Product, Sum};
 use core::ops::{
@@ -225,21 +224,14 @@ impl Default for AncDec32 {
 }
 
 // ============ Hash ============
-/// Normalizes trailing zeros so 1.0 == 1.00 have same hash
+/// Hashes the normalized form, so equal values (1.0 and 1.00, 0 and -0) hash the same
 impl Hash for AncDec32 {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        let mut combined = self.int as u64 * pow10(self.scale) + self.frac as u64;
-        // Binary search: strip trailing zeros in O(log scale) instead of O(scale)
-        if combined > 0 {
-            if combined % 100_000_000 == 0 { combined /= 100_000_000; }
-            if combined % 10_000 == 0 { combined /= 10_000; }
-            if combined % 100 == 0 { combined /= 100; }
-            if combined % 10 == 0 { combined /= 10; }
-        }
-        combined.hash(state);
-        if combined != 0 {
-            self.neg.hash(state);
-        } // 0 == -0
+        let n = self.normalize();
+        n.int.hash(state);
+        n.frac.hash(state);
+        n.scale.hash(state);
+        n.neg.hash(state);
     }
 }
 
@@ -1,5 +1,9 @@
+use crate::error::{unwrap_or_saturate, ArithmeticError};
 use super::AncDec8;
-use crate::util::{pow10_u8, pow10_16, pow10_32};
+use crate::round_mode::Residue;
+use crate::util::{pow10_u8, pow10_16, pow10_32, TARGET_SCALE_8};
+use crate::RoundMode;
+use crate::wide::nth_root_decimal;
 
 /// Integer square root of a u32 value, returns u16
 #[inline(always)]
@@ -9,7 +13,8 @@ fn isqrt_u32(n: u32) -> u16 {
     }
     let mut x = 1u32 << (32 - n.leading_zeros()).div_ceil(2);
     loop {
-        let q = n / x;
+        // x stays >= isqrt(n) >= 1; max(1) only lets the compiler drop the zero check
+        let q = n / x.max(1);
         let x_new = (x >> 1) + (q >> 1) + (x & q & 1);
         if x_new >= x {
             break;
@@ -67,6 +72,12 @@ impl AncDec8 {
         self.int == 0 && self.frac == 0
     }
 
+    /// Returns `true` if the value has no fractional part (`2.00` is an integer).
+    #[inline(always)]
+    pub fn is_integer(&self) -> bool {
+        self.frac == 0
+    }
+
     /// Returns the smaller of `self` and `other`.
     #[inline(always)]
     pub fn min(self, other: Self) -> Self {
@@ -99,11 +110,53 @@ impl AncDec8 {
         }
     }
 
-    /// Returns the square root with 1 fractional digit of precision. Panics if negative.
+    /// Unit in the last place at the value's own scale: `10^-scale` (`1.25` gives `0.01`,
+    /// `3` gives `1`). Always positive. `next_up`/`next_down` step by `EPSILON` instead.
+    #[inline(always)]
+    pub fn ulp(&self) -> Self {
+        if self.scale == 0 {
+            return Self::ONE;
+        }
+        Self {
+            int: 0,
+            frac: 1,
+            scale: self.scale,
+            neg: false,
+        }
+    }
+
+    /// The next representable value above `self`: `self + EPSILON` at scale 2, whatever the
+    /// scale of `self`. Not `self + self.ulp()`, which steps at the value's own scale.
+    ///
+    /// Panics at `MAX` (saturates with `panic-free`).
+    #[inline(always)]
+    pub fn next_up(&self) -> Self {
+        *self + Self::EPSILON
+    }
+
+    /// The next representable value below `self`: `self - EPSILON` at scale 2, whatever the
+    /// scale of `self`. Not `self - self.ulp()`, which steps at the value's own scale.
+    ///
+    /// Panics at `MIN` (saturates with `panic-free`).
+    #[inline(always)]
+    pub fn next_down(&self) -> Self {
+        *self - Self::EPSILON
+    }
+
+    /// Returns the square root with 1 fractional digit of precision. Panics if negative
+    /// (returns `ZERO` with `panic-free`).
+    #[inline(always)]
     pub fn sqrt(&self) -> Self {
-        assert!(!self.neg || self.is_zero(), "square root of negative number");
+        unwrap_or_saturate(self.try_sqrt(), || Self::ZERO)
+    }
+
+    /// Fallible square root. Returns `ArithmeticError::NegativeSqrt` if `self` is negative.
+    pub fn try_sqrt(&self) -> Result<Self, ArithmeticError> {
+        if self.neg && !self.is_zero() {
+            return Err(ArithmeticError::NegativeSqrt);
+        }
         if self.is_zero() {
-            return Self::ZERO;
+            return Ok(Self::ZERO);
         }
 
         // combined = int * 10^scale + frac as u16
@@ -116,36 +169,201 @@ impl AncDec8 {
 
         let int = (x / pow10_u8(1) as u16) as u8;
         let frac = (x % pow10_u8(1) as u16) as u8;
-        Self {
+        Ok(Self {
             int,
             frac,
             scale: 1,
             neg: false,
+        })
+    }
+
+    /// Checked square root. Returns `None` if `self` is negative.
+    #[inline(always)]
+    pub fn checked_sqrt(&self) -> Option<Self> {
+        self.try_sqrt().ok()
+    }
+
+    /// Square root rounded once to `scale` decimal places (capped at 2) with `mode`. The
+    /// last digit is decided exactly from the integer square root's remainder, so the result
+    /// is correctly rounded; it always has `scale` decimal places. `RoundMode::Fract` truncates.
+    ///
+    /// Panics if `self` is negative (returns `ZERO` with `panic-free`).
+    #[inline(always)]
+    pub fn sqrt_round(&self, scale: u8, mode: RoundMode) -> Self {
+        unwrap_or_saturate(
+            self.checked_sqrt_round(scale, mode).ok_or(ArithmeticError::NegativeSqrt),
+            || Self::ZERO,
+        )
+    }
+
+    /// Checked [`sqrt_round`](Self::sqrt_round). Returns `None` if `self` is negative.
+    pub fn checked_sqrt_round(&self, scale: u8, mode: RoundMode) -> Option<Self> {
+        if self.neg && !self.is_zero() {
+            return None;
         }
+        let scale = scale.min(TARGET_SCALE_8);
+        // the smallest working scale at which n = value * 10^(2 * work) is an integer
+        let work = scale.max(self.scale.div_ceil(2));
+        let combined = self.int as u32 * pow10_32(self.scale) + self.frac as u32;
+        let n = combined * pow10_32(2 * work - self.scale);
+        let x = isqrt_u32(n) as u32;
+        let r = n - x * x;
+
+        let (q, residue) = if work == scale {
+            // (2x + 1)^2 is odd, so sqrt(n) is never exactly x + 1/2: above half iff r > x
+            let residue = match r {
+                0 => Residue::Zero,
+                r if r > x => Residue::AboveHalf,
+                _ => Residue::BelowHalf,
+            };
+            (x, residue)
+        } else {
+            let d = pow10_32(work - scale);
+            (x / d, Residue::new((x % d) as u128, d as u128).with_sticky(r != 0))
+        };
+        let q = q + mode.rounds_up(residue, false, (q % 10) as u8) as u32;
+        // sqrt(255.99) < 16, so q fits u16
+        Self::checked_from_combined(q as u16, scale, false)
+    }
+
+    /// Returns the cube root with 1 fractional digits of precision, truncated like
+    /// [`sqrt`](Self::sqrt). Negative numbers have negative cube roots.
+    #[inline(always)]
+    pub fn cbrt(&self) -> Self {
+        // odd roots exist for every input and never exceed it in magnitude
+        self.checked_nth_root(3).unwrap_or(Self::ZERO)
+    }
+
+    /// Returns the `n`-th root with 1 fractional digits of precision, truncated like
+    /// [`sqrt`](Self::sqrt): the digits are exact, taken from an integer k-th root of the
+    /// scaled value. Odd roots of negative numbers are negative. Above `n = 1155` the
+    /// result keeps fewer fractional digits, as many as the 4096-bit working width allows.
+    ///
+    /// Panics if `n` is zero, or `n` is even and `self` negative (returns `ZERO` with `panic-free`).
+    #[inline(always)]
+    pub fn nth_root(&self, n: u32) -> Self {
+        unwrap_or_saturate(self.try_nth_root(n), || Self::ZERO)
+    }
+
+    /// Fallible `n`-th root. Returns `ArithmeticError::OutOfDomain` if `n` is zero,
+    /// `ArithmeticError::NegativeSqrt` if `n` is even and `self` negative.
+    pub fn try_nth_root(&self, n: u32) -> Result<Self, ArithmeticError> {
+        if n == 0 {
+            return Err(ArithmeticError::OutOfDomain);
+        }
+        if n % 2 == 0 && self.is_negative() {
+            return Err(ArithmeticError::NegativeSqrt);
+        }
+        let combined = self.int as u16 * pow10_16(self.scale) + self.frac as u16;
+        let (root, scale) = nth_root_decimal((0, combined as u128), self.scale, n, 1);
+        let root = match root {
+            (0, n) if n <= u16::MAX as u128 => Self::checked_from_combined(n as u16, scale, self.is_negative()),
+            _ => None,
+        };
+        root.ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Checked `n`-th root. Returns `None` if `n` is zero, or `n` is even and `self` negative.
+    #[inline(always)]
+    pub fn checked_nth_root(&self, n: u32) -> Option<Self> {
+        self.try_nth_root(n).ok()
     }
 
     /// Raises `self` to the power `n` using binary exponentiation. Supports negative exponents.
+    ///
+    /// Panics if `self` is zero and `n` is negative, or if the result overflows
+    /// (saturates with `panic-free`, see [`saturating_pow`](Self::saturating_pow)).
+    #[inline(always)]
     pub fn pow(&self, n: i32) -> Self {
+        unwrap_or_saturate(self.try_pow(n), || self.saturating_pow(n))
+    }
+
+    /// Fallible exponentiation. Returns `ArithmeticError::DivisionByZero` if `self` is zero and
+    /// `n` is negative, `ArithmeticError::Overflow` if the result overflows.
+    #[inline(always)]
+    pub fn try_pow(&self, n: i32) -> Result<Self, ArithmeticError> {
+        if n < 0 && self.is_zero() {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        self.checked_pow(n).ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Checked exponentiation. Returns `None` on overflow, or if `self` is zero and `n` is negative.
+    #[inline(always)]
+    pub fn checked_pow(&self, n: i32) -> Option<Self> {
+        self.checked_powi(n as i64)
+    }
+
+    /// Raises `self` to a 64-bit integer power, like [`pow`](Self::pow).
+    ///
+    /// Panics if `self` is zero and `n` is negative, or if the result overflows
+    /// (saturates with `panic-free`).
+    #[inline(always)]
+    pub fn powi(&self, n: i64) -> Self {
+        unwrap_or_saturate(self.try_powi(n), || {
+            Self::saturated(self.is_negative() && n % 2 != 0)
+        })
+    }
+
+    /// Fallible [`powi`](Self::powi). Returns `ArithmeticError::DivisionByZero` if `self` is
+    /// zero and `n` is negative, `ArithmeticError::Overflow` if the result overflows.
+    #[inline(always)]
+    pub fn try_powi(&self, n: i64) -> Result<Self, ArithmeticError> {
+        if n < 0 && self.is_zero() {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+        self.checked_powi(n).ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Checked exponentiation with a 64-bit exponent. Returns `None` on overflow, or if `self`
+    /// is zero and `n` is negative.
+    ///
+    /// Stops at the first overflowing step, and as soon as the running power truncates to
+    /// zero, so any exponent costs at most 63 squarings.
+    pub fn checked_powi(&self, n: i64) -> Option<Self> {
         if n == 0 {
-            return Self::ONE;
+            return Some(Self::ONE);
         }
 
         let mut base = if n < 0 {
-            assert!(!self.is_zero(), "division by zero in pow with negative exponent");
-            Self::ONE.div(self)
+            Self::ONE.checked_div(self)?
         } else {
             *self
         };
         let mut exp = n.unsigned_abs();
         let mut result = Self::ONE;
 
-        while exp > 0 {
+        loop {
             if exp % 2 == 1 {
-                result = result.mul(&base);
+                result = result.checked_mul(&base)?;
             }
-            base = base.mul(&base);
             exp /= 2;
+            if exp == 0 {
+                return Some(result);
+            }
+            // A remaining bit multiplies in a power of zero
+            if base.is_zero() {
+                return Some(Self::ZERO);
+            }
+            // Square only when another bit remains, so the final step cannot overflow spuriously
+            base = base.checked_mul(&base)?;
+        }
+    }
+
+    /// Saturating exponentiation. Clamps to `MAX` or `-MAX` instead of overflowing.
+    ///
+    /// Zero raised to a negative power saturates to `MAX`.
+    #[inline(always)]
+    pub fn saturating_pow(&self, n: i32) -> Self {
+        self.overflowing_pow(n).0
+    }
+
+    /// Exponentiation returning `(result, overflowed)`. On overflow the result is clamped to
+    /// `MAX` or `-MAX`, with the sign the exact power would have had.
+    pub fn overflowing_pow(&self, n: i32) -> (Self, bool) {
+        match self.checked_pow(n) {
+            Some(v) => (v, false),
+            None => (Self::saturated(self.is_negative() && n % 2 != 0), true),
         }
-        result
     }
 }
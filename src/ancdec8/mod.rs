@@ -3,13 +3,15 @@ mod basic;
 mod cmp;
 mod convert;
 mod fmt_impl;
+mod num;
 mod ops;
 mod rounding;
 
 #[cfg(feature = "serde")]
 mod serde_impl;
 
-use crate::error::ParseError;
+use crate::context::{Signal, Signals};
+use crate::error::{unwrap_or_saturate, ArithmeticError, ParseError};
 use crate::util::{pow10_u8, pow10_16, StackBuf, TARGET_SCALE_8};
 use core::fmt::{Display, Write};
 
@@ -69,16 +71,90 @@ impl AncDec8 {
         scale: 2,
         neg: false,
     };
+    /// The minimum representable value (`-255.99`, `-MAX`).
+    pub const MIN: AncDec8 = AncDec8 {
+        int: u8::MAX,
+        frac: 99,
+        scale: 2,
+        neg: true,
+    };
+    /// The smallest positive value (`0.01`).
+    pub const MIN_POSITIVE: AncDec8 = AncDec8 {
+        int: 0,
+        frac: 1,
+        scale: 2,
+        neg: false,
+    };
+    /// The difference between `1` and the next larger representable value. Values are
+    /// evenly spaced at the maximum scale, so this equals [`MIN_POSITIVE`](Self::MIN_POSITIVE).
+    pub const EPSILON: AncDec8 = Self::MIN_POSITIVE;
+
+    // Mathematical constants mirroring `core::f64::consts`, correctly rounded
+    // (half-even) to the maximum scale of 2 places.
+
+    /// Archimedes' constant (π): `3.14`.
+    pub const PI: AncDec8 = AncDec8 {
+        int: 3,
+        frac: 14,
+        scale: 2,
+        neg: false,
+    };
+    /// Euler's number (e): `2.72`.
+    pub const E: AncDec8 = AncDec8 {
+        int: 2,
+        frac: 72,
+        scale: 2,
+        neg: false,
+    };
+    /// ln(2): `0.69`.
+    pub const LN_2: AncDec8 = AncDec8 {
+        int: 0,
+        frac: 69,
+        scale: 2,
+        neg: false,
+    };
+    /// ln(10): `2.30`.
+    pub const LN_10: AncDec8 = AncDec8 {
+        int: 2,
+        frac: 30,
+        scale: 2,
+        neg: false,
+    };
+    /// sqrt(2): `1.41`.
+    pub const SQRT_2: AncDec8 = AncDec8 {
+        int: 1,
+        frac: 41,
+        scale: 2,
+        neg: false,
+    };
+    /// 1/sqrt(2): `0.71`.
+    pub const FRAC_1_SQRT_2: AncDec8 = AncDec8 {
+        int: 0,
+        frac: 71,
+        scale: 2,
+        neg: false,
+    };
 }
 
 // ============ Constructor / Accessors ============
 impl AncDec8 {
-    /// Creates a new `AncDec8`. Panics if `scale > 2` or `frac >= 10^scale`.
+    /// Creates a new `AncDec8`. Panics if `scale > 2` or `frac >= 10^scale`
+    /// (with `panic-free`, clamps `scale` to 2 and `frac` to `10^scale - 1`).
     #[inline(always)]
     pub fn new(int: u8, frac: u8, scale: u8, neg: bool) -> Self {
-        assert!(scale <= 2, "scale must be <= 2");
-        assert!(frac < pow10_u8(scale), "frac must be < 10^scale");
-        Self { int, frac, scale, neg }
+        unwrap_or_saturate(Self::try_new(int, frac, scale, neg), || {
+            let scale = scale.min(2);
+            Self { int, frac: frac.min(pow10_u8(scale) - 1), scale, neg }
+        })
+    }
+
+    /// Fallible constructor. Returns `ArithmeticError::ScaleOverflow` if `scale > 2` or `frac >= 10^scale`.
+    #[inline(always)]
+    pub fn try_new(int: u8, frac: u8, scale: u8, neg: bool) -> Result<Self, ArithmeticError> {
+        if scale > 2 || frac >= pow10_u8(scale) {
+            return Err(ArithmeticError::ScaleOverflow);
+        }
+        Ok(Self { int, frac, scale, neg })
     }
 
     /// Returns the integer part.
@@ -115,8 +191,25 @@ impl AncDec8 {
         Self::parse_str(buf.as_str())
     }
 
+    /// Parses like `from_str`, but fails with `ParseError::Inexact` instead of dropping
+    /// nonzero fractional digits beyond scale 2. Dropped trailing zeros are accepted.
+    #[inline(always)]
+    pub fn parse_exact(s: &str) -> Result<Self, ParseError> {
+        match Self::parse_str_signals(s)? {
+            (_, signals) if signals.contains(Signal::Inexact) => Err(ParseError::Inexact),
+            (v, _) => Ok(v),
+        }
+    }
+
     /// Byte-level string parsing with validation
+    #[inline(always)]
     pub(crate) fn parse_str(s: &str) -> Result<Self, ParseError> {
+        Self::parse_str_signals(s).map(|(v, _)| v)
+    }
+
+    /// `parse_str`, also reporting dropped fractional digits as `Clamped` (plus `Inexact`
+    /// if any of them was nonzero)
+    pub(crate) fn parse_str_signals(s: &str) -> Result<(Self, Signals), ParseError> {
         let b = s.as_bytes();
         let len = b.len();
         if len == 0 {
@@ -154,6 +247,7 @@ impl AncDec8 {
         // parse fractional part (truncate at 2 digits)
         let mut frac: u8 = 0;
         let mut frac_digits: u8 = 0;
+        let mut signals = Signals::EMPTY;
         while i < len {
             let d = unsafe { *b.get_unchecked(i) }.wrapping_sub(b'0');
             if d > 9 {
@@ -162,6 +256,11 @@ impl AncDec8 {
             if frac_digits < TARGET_SCALE_8 {
                 frac = frac * 10 + d;
                 frac_digits += 1;
+            } else {
+                signals.insert(Signal::Clamped);
+                if d != 0 {
+                    signals.insert(Signal::Inexact);
+                }
             }
             i += 1;
         }
@@ -174,12 +273,15 @@ impl AncDec8 {
             return Err(ParseError::TrailingChars);
         }
 
-        Ok(Self {
-            int,
-            frac,
-            scale: frac_digits,
-            neg,
-        })
+        Ok((
+            Self {
+                int,
+                frac,
+                scale: frac_digits,
+                neg,
+            },
+            signals,
+        ))
     }
 
     /// Align fractional parts to same scale, returns (self_frac, other_frac, scale, limit)
@@ -209,7 +311,7 @@ impl AncDec8 {
         }
     }
 
-    /// Add aligned values (same sign), handles frac overflow
+    /// Add aligned values (same sign), handles frac overflow. Returns None on integer overflow
     #[inline(always)]
     pub(crate) fn add_aligned(
         a_int: u8,
@@ -218,13 +320,11 @@ impl AncDec8 {
         b_frac: u8,
         scale: u8,
         limit: u8,
-    ) -> (u8, u8, u8) {
+    ) -> Option<(u8, u8, u8)> {
         let frac = a_frac + b_frac;
         let overflow = (frac >= limit) as u8;
-        let int = a_int.checked_add(b_int)
-            .and_then(|v| v.checked_add(overflow))
-            .expect("integer overflow in addition");
-        (int, frac - overflow * limit, scale)
+        let int = a_int.checked_add(b_int)?.checked_add(overflow)?;
+        Some((int, frac - overflow * limit, scale))
     }
 
     /// Subtract with magnitude comparison, returns result with correct sign
@@ -259,26 +359,39 @@ impl AncDec8 {
         }
     }
 
-    /// Split u16 combined value back to int/frac
+    /// Split u16 combined value back to int/frac. Returns None if the integer part overflows
     #[inline(always)]
-    pub(crate) fn from_combined(n: u16, scale: u8, neg: bool) -> Self {
+    pub(crate) fn checked_from_combined(n: u16, scale: u8, neg: bool) -> Option<Self> {
         if scale == 0 {
-            assert!(n <= u8::MAX as u16, "integer overflow in from_combined");
-            return Self {
+            if n > u8::MAX as u16 {
+                return None;
+            }
+            return Some(Self {
                 int: n as u8,
                 frac: 0,
                 scale: 0,
                 neg,
-            };
+            });
         }
         let divisor = pow10_16(scale);
         let int_part = n / divisor;
-        assert!(int_part <= u8::MAX as u16, "integer overflow in from_combined");
-        Self {
+        if int_part > u8::MAX as u16 {
+            return None;
+        }
+        Some(Self {
             int: int_part as u8,
             frac: (n % divisor) as u8,
             scale,
             neg,
-        }
+        })
+    }
+
+    /// Like `checked_from_combined`, panics on overflow (saturates with `panic-free`)
+    #[inline(always)]
+    pub(crate) fn from_combined(n: u16, scale: u8, neg: bool) -> Self {
+        unwrap_or_saturate(
+            Self::checked_from_combined(n, scale, neg).ok_or(ArithmeticError::Overflow),
+            || Self::saturated(neg),
+        )
     }
 }
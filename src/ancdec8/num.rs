@@ -0,0 +1,87 @@
+use crate::error::{unwrap_or_saturate, ArithmeticError};
+use crate::wide::{approximate_fraction, exact_fraction, gcd_u128};
+use crate::util::{pow10_u8, signed_i128};
+use super::AncDec8;
+
+// ============ GCD / LCM ============
+impl AncDec8 {
+    /// Both magnitudes as combined integers at their common scale (see `align_frac`)
+    #[inline(always)]
+    fn aligned_combined(&self, other: &Self) -> (u16, u16, u8) {
+        let (a_frac, b_frac, scale, limit) = self.align_frac(other);
+        let limit = limit as u16;
+        ((self.int as u16) * limit + a_frac as u16, (other.int as u16) * limit + b_frac as u16, scale)
+    }
+
+    /// Greatest common divisor of the magnitudes, computed on the combined integers at the
+    /// common scale: the largest decimal of that scale dividing both (`gcd(0.25, 0.10)` is
+    /// `0.05`). Never negative; `gcd(x, 0) = |x|`.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (a, b, scale) = self.aligned_combined(other);
+        // the gcd is at most max(a, b), so it always fits
+        Self::checked_from_combined(gcd_u128(a as u128, b as u128) as u16, scale, false).unwrap_or(Self::ZERO)
+    }
+
+    /// Least common multiple of the magnitudes at the common scale (`lcm(0.25, 0.10)` is
+    /// `0.50`). Never negative; zero if either operand is zero.
+    ///
+    /// Panics if the result overflows (returns `MAX` with `panic-free`).
+    #[inline(always)]
+    pub fn lcm(&self, other: &Self) -> Self {
+        unwrap_or_saturate(self.try_lcm(other), || Self::MAX)
+    }
+
+    /// Fallible least common multiple. Returns `ArithmeticError::Overflow` if the result overflows.
+    #[inline(always)]
+    pub fn try_lcm(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        self.checked_lcm(other).ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Checked least common multiple. Returns `None` on overflow.
+    pub fn checked_lcm(&self, other: &Self) -> Option<Self> {
+        let (a, b, scale) = self.aligned_combined(other);
+        if a == 0 || b == 0 {
+            return Self::checked_from_combined(0, scale, false);
+        }
+        let g = gcd_u128(a as u128, b as u128) as u16;
+        Self::checked_from_combined(a.checked_div(g)?.checked_mul(b)?, scale, false)
+    }
+}
+
+// ============ Fractions ============
+impl AncDec8 {
+    /// The exact value as `(numerator, denominator)` in lowest terms, with the sign on the
+    /// numerator. The denominator divides `10^scale`; zero is `(0, 1)`.
+    ///
+    /// ```
+    /// use ancdec::AncDec8;
+    ///
+    /// let x: AncDec8 = "-1.25".parse().unwrap();
+    /// assert_eq!(x.to_fraction(), (-5, 4));
+    /// ```
+    pub fn to_fraction(&self) -> (i128, u128) {
+        // |self| < 2^8 * 10^2, so the numerator always fits
+        exact_fraction(self.int as u128, self.frac as u128, pow10_u8(self.scale) as u128)
+            .and_then(|(n, d)| Some((signed_i128(n, self.neg)?, d)))
+            .unwrap_or((0, 1))
+    }
+
+    /// The closest fraction with a denominator of at most `max_denominator` (0 is treated as
+    /// 1), found from the continued fraction expansion, e.g. bond prices in 32nds or gear
+    /// ratios. The exact fraction is returned when it already fits. Matches Python's
+    /// `Fraction.limit_denominator`, ties included: a negative value is expanded from its
+    /// floor, so `-0.5` with a bound of 1 gives `-1`.
+    ///
+    /// ```
+    /// use ancdec::AncDec8;
+    ///
+    /// let x: AncDec8 = "1.33".parse().unwrap();
+    /// assert_eq!(x.approximate_fraction(3), (4, 3));
+    /// ```
+    pub fn approximate_fraction(&self, max_denominator: u128) -> (i128, u128) {
+        // the numerator is at most |self| * max_denominator rounded up, far below 2^127
+        approximate_fraction(self.int as u128, self.frac as u128, pow10_u8(self.scale) as u128, self.neg, max_denominator)
+            .and_then(|(n, d)| Some((signed_i128(n, self.neg)?, d)))
+            .unwrap_or((0, 1))
+    }
+}
@@ -1,5 +1,4 @@
 use super::AncDec8;
-use crate::util::pow10_16;
 use core::hash::{Hash, Hasher};
 use core::iter::{Product, Sum};
 use core::ops::{
@@ -225,20 +224,14 @@ impl Default for AncDec8 {
 }
 
 // ============ Hash ============
-/// Normalizes trailing zeros so 1.0 == 1.00 have same hash
-/// Uses u16 combined value
+/// Hashes the normalized form, so equal values (1.0 and 1.00, 0 and -0) hash the same
 impl Hash for AncDec8 {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        let mut combined = (self.int as u16) * pow10_16(self.scale) + (self.frac as u16);
-        // Unrolled: max 2 trailing zeros for scale <= 2
-        if combined > 0 {
-            if combined % 100 == 0 { combined /= 100; }
-            else if combined % 10 == 0 { combined /= 10; }
-        }
-        combined.hash(state);
-        if combined != 0 {
-            self.neg.hash(state);
-        } // 0 == -0
+        let n = self.normalize();
+        n.int.hash(state);
+        n.frac.hash(state);
+        n.scale.hash(state);
+        n.neg.hash(state);
     }
 }
 
@@ -0,0 +1,414 @@
+use core::fmt;
+use core::ops::{BitAnd, BitOr, BitOrAssign};
+
+use crate::error::ParseError;
+use crate::RoundMode;
+
+/// Exceptional conditions recorded by a [`Context`] (after Python `decimal`).
+///
+/// Non-exhaustive: new signals may be added in minor releases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Signal {
+    /// Divisor was zero.
+    DivisionByZero,
+    /// Integer part of the result overflowed; the result saturated to `±MAX`.
+    Overflow,
+    /// Fractional digits beyond the type's maximum scale were dropped while parsing.
+    Clamped,
+    /// Nonzero digits were discarded, so the result differs from the exact value.
+    Inexact,
+    /// Digits were discarded to fit the context's scale (even if they were all zero).
+    Rounded,
+}
+
+impl Signal {
+    // declaration order is trap priority: the first trapped signal of an operation is reported
+    const ALL: [Signal; 5] = [
+        Self::DivisionByZero,
+        Self::Overflow,
+        Self::Clamped,
+        Self::Inexact,
+        Self::Rounded,
+    ];
+
+    #[inline(always)]
+    const fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+impl fmt::Display for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DivisionByZero => f.write_str("division by zero"),
+            Self::Overflow => f.write_str("overflow"),
+            Self::Clamped => f.write_str("clamped"),
+            Self::Inexact => f.write_str("inexact"),
+            Self::Rounded => f.write_str("rounded"),
+        }
+    }
+}
+
+/// A set of [`Signal`]s, used for both the traps and the sticky flags of a [`Context`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Signals(u8);
+
+impl Signals {
+    /// No signals.
+    pub const EMPTY: Signals = Signals(0);
+    /// Every signal.
+    pub const ALL: Signals = Signals(0b1_1111);
+
+    /// Returns `true` if `signal` is in the set.
+    #[inline(always)]
+    pub const fn contains(self, signal: Signal) -> bool {
+        self.0 & signal.bit() != 0
+    }
+
+    /// Returns `true` if the set holds no signal.
+    #[inline(always)]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Adds `signal` to the set.
+    #[inline(always)]
+    pub fn insert(&mut self, signal: Signal) {
+        self.0 |= signal.bit();
+    }
+
+    /// Removes `signal` from the set.
+    #[inline(always)]
+    pub fn remove(&mut self, signal: Signal) {
+        self.0 &= !signal.bit();
+    }
+
+    /// Returns the set with `signal` added.
+    #[inline(always)]
+    pub const fn with(self, signal: Signal) -> Self {
+        Self(self.0 | signal.bit())
+    }
+
+    /// Highest-priority signal in the set (see [`Signal`] declaration order)
+    #[inline(always)]
+    fn first(self) -> Option<Signal> {
+        Signal::ALL.into_iter().find(|s| self.contains(*s))
+    }
+}
+
+/// Lists the set signals by name, e.g. `Signals { Inexact, Rounded }`.
+impl fmt::Debug for Signals {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Signals {")?;
+        let mut sep = " ";
+        for s in Signal::ALL.into_iter().filter(|s| self.contains(*s)) {
+            f.write_str(sep)?;
+            fmt::Debug::fmt(&s, f)?;
+            sep = ", ";
+        }
+        f.write_str(if self.is_empty() { "}" } else { " }" })
+    }
+}
+
+impl From<Signal> for Signals {
+    #[inline(always)]
+    fn from(signal: Signal) -> Self {
+        Self(signal.bit())
+    }
+}
+
+impl BitOr for Signals {
+    type Output = Signals;
+    #[inline(always)]
+    fn bitor(self, rhs: Signals) -> Signals {
+        Signals(self.0 | rhs.0)
+    }
+}
+
+impl BitOr<Signal> for Signals {
+    type Output = Signals;
+    #[inline(always)]
+    fn bitor(self, rhs: Signal) -> Signals {
+        self.with(rhs)
+    }
+}
+
+impl BitOr for Signal {
+    type Output = Signals;
+    #[inline(always)]
+    fn bitor(self, rhs: Signal) -> Signals {
+        Signals::from(self).with(rhs)
+    }
+}
+
+impl BitOrAssign for Signals {
+    #[inline(always)]
+    fn bitor_assign(&mut self, rhs: Signals) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl BitAnd for Signals {
+    type Output = Signals;
+    #[inline(always)]
+    fn bitand(self, rhs: Signals) -> Signals {
+        Signals(self.0 & rhs.0)
+    }
+}
+
+/// Arithmetic environment: rounding mode, maximum scale, traps and sticky status flags.
+///
+/// Every operation run through a context rounds its exact result once to
+/// `min(max_scale, type maximum)` places with `rounding`, and raises the [`Signal`]s
+/// that apply. Raised signals are accumulated in [`flags`](Self::flags) until
+/// [`clear_flags`](Self::clear_flags); a raised signal that is also in `traps` turns the
+/// result into `Err(signal)`. Untrapped overflow saturates to `±MAX`, untrapped division
+/// by zero returns the `saturating_div` value.
+///
+/// `RoundMode::Fract` truncates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Context {
+    /// Rounding mode applied when a result has more digits than `max_scale`.
+    pub rounding: RoundMode,
+    /// Maximum fractional digits of results, capped at each type's own maximum.
+    pub max_scale: u8,
+    /// Signals that make an operation return `Err`.
+    pub traps: Signals,
+    flags: Signals,
+}
+
+impl Default for Context {
+    /// `HalfEven` at each type's maximum scale, trapping `Overflow` and `DivisionByZero`.
+    fn default() -> Self {
+        Self::new(u8::MAX, RoundMode::HalfEven)
+    }
+}
+
+impl Context {
+    /// Creates a context trapping `Overflow` and `DivisionByZero`, with no flags set.
+    pub const fn new(max_scale: u8, rounding: RoundMode) -> Self {
+        Self {
+            rounding,
+            max_scale,
+            traps: Signals::EMPTY.with(Signal::Overflow).with(Signal::DivisionByZero),
+            flags: Signals::EMPTY,
+        }
+    }
+
+    /// Returns the context with `traps` replacing its trap set.
+    #[inline(always)]
+    pub const fn with_traps(mut self, traps: Signals) -> Self {
+        self.traps = traps;
+        self
+    }
+
+    /// Signals raised since the flags were last cleared (trapped or not).
+    #[inline(always)]
+    pub const fn flags(&self) -> Signals {
+        self.flags
+    }
+
+    /// Resets the sticky flags.
+    #[inline(always)]
+    pub fn clear_flags(&mut self) {
+        self.flags = Signals::EMPTY;
+    }
+
+    /// Adds `a + b`, rounding to the context's scale.
+    pub fn add<T: ContextDecimal>(&mut self, a: &T, b: &T) -> Result<T, Signal> {
+        match a.checked_add(b) {
+            Some(v) => self.fit(v, Signals::EMPTY),
+            // only same-sign operands overflow
+            None => self.overflow(a.is_neg()),
+        }
+    }
+
+    /// Subtracts `a - b`, rounding to the context's scale.
+    pub fn sub<T: ContextDecimal>(&mut self, a: &T, b: &T) -> Result<T, Signal> {
+        match a.checked_sub(b) {
+            Some(v) => self.fit(v, Signals::EMPTY),
+            // only opposite-sign operands overflow
+            None => self.overflow(a.is_neg()),
+        }
+    }
+
+    /// Multiplies `a * b`, rounding the exact product once to the context's scale.
+    pub fn mul<T: ContextDecimal>(&mut self, a: &T, b: &T) -> Result<T, Signal> {
+        let scale = self.scale::<T>();
+        match a.mul_round_exact(b, scale, self.rounding) {
+            Some((p, exact)) => {
+                self.raise(Self::discarded(a.scale() + b.scale() > scale, exact))?;
+                Ok(p)
+            }
+            None => self.overflow(a.is_neg() ^ b.is_neg()),
+        }
+    }
+
+    /// Divides `a / b`. Quotients that terminate within the context's scale are exact and
+    /// keep their minimal scale (`1 / 4` gives `0.25`); others are rounded to it.
+    pub fn div<T: ContextDecimal>(&mut self, a: &T, b: &T) -> Result<T, Signal> {
+        if b.is_zero() {
+            self.raise(Signal::DivisionByZero.into())?;
+            return Ok(a.saturating_div(b));
+        }
+        match a.div_round_exact(b, self.scale::<T>(), self.rounding) {
+            Some((q, true)) => Ok(q.normalize()),
+            Some((q, false)) => {
+                self.raise(Signal::Rounded | Signal::Inexact)?;
+                Ok(q)
+            }
+            None => self.overflow(a.is_neg() ^ b.is_neg()),
+        }
+    }
+
+    /// Rounds `a` to the context's scale.
+    pub fn round<T: ContextDecimal>(&mut self, a: &T) -> Result<T, Signal> {
+        self.fit(*a, Signals::EMPTY)
+    }
+
+    /// Parses `s`, raising `Clamped` for fractional digits beyond the type's maximum
+    /// scale, then rounds to the context's scale. A trapped signal is returned as
+    /// [`ParseError::Trapped`].
+    pub fn parse<T: ContextDecimal>(&mut self, s: &str) -> Result<T, ParseError> {
+        let (v, signals) = T::parse_str_signals(s)?;
+        self.fit(v, signals).map_err(ParseError::Trapped)
+    }
+
+    /// Effective scale for `T`
+    #[inline(always)]
+    fn scale<T: ContextDecimal>(&self) -> u8 {
+        self.max_scale.min(T::MAX_SCALE)
+    }
+
+    /// Rounds an exact value to the context's scale, raising `signals` along with the
+    /// rounding signals
+    fn fit<T: ContextDecimal>(&mut self, v: T, signals: Signals) -> Result<T, Signal> {
+        let scale = self.scale::<T>();
+        if v.scale() <= scale {
+            self.raise(signals)?;
+            return Ok(v);
+        }
+        // v * 1 rounds the exact value once, with overflow (e.g. 9.99 -> 10) detected
+        match v.mul_round_exact(&T::ONE, scale, self.rounding) {
+            Some((r, exact)) => {
+                self.raise(signals | Self::discarded(true, exact))?;
+                Ok(r)
+            }
+            None => self.overflow(v.is_neg()),
+        }
+    }
+
+    /// `Overflow` (with `Inexact` and `Rounded`, as in Python) and the saturated value
+    fn overflow<T: ContextDecimal>(&mut self, neg: bool) -> Result<T, Signal> {
+        self.raise(Signal::Overflow | Signal::Inexact | Signal::Rounded)?;
+        Ok(T::saturated(neg))
+    }
+
+    #[inline(always)]
+    fn discarded(rounded: bool, exact: bool) -> Signals {
+        match (rounded, exact) {
+            (_, false) => Signal::Rounded | Signal::Inexact,
+            (true, true) => Signal::Rounded.into(),
+            (false, true) => Signals::EMPTY,
+        }
+    }
+
+    /// Records `signals` in the flags; `Err` with the first one that is trapped
+    #[inline(always)]
+    fn raise(&mut self, signals: Signals) -> Result<(), Signal> {
+        self.flags |= signals;
+        match (signals & self.traps).first() {
+            Some(trapped) => Err(trapped),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Decimal types that can run through a [`Context`] (all four types). Sealed.
+pub trait ContextDecimal: sealed::Sealed {}
+
+mod sealed {
+    use crate::error::ParseError;
+    use crate::RoundMode;
+    use super::Signals;
+
+    pub trait Sealed: Copy {
+        const MAX_SCALE: u8;
+        const ONE: Self;
+        fn scale(&self) -> u8;
+        fn is_neg(&self) -> bool;
+        fn is_zero(&self) -> bool;
+        fn checked_add(&self, other: &Self) -> Option<Self>;
+        fn checked_sub(&self, other: &Self) -> Option<Self>;
+        fn mul_round_exact(&self, other: &Self, scale: u8, mode: RoundMode) -> Option<(Self, bool)>;
+        fn div_round_exact(&self, other: &Self, scale: u8, mode: RoundMode) -> Option<(Self, bool)>;
+        fn saturating_div(&self, other: &Self) -> Self;
+        fn normalize(&self) -> Self;
+        fn saturated(neg: bool) -> Self;
+        fn parse_str_signals(s: &str) -> Result<(Self, Signals), ParseError>;
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_context_decimal {
+    ($T:ty, $max_scale:expr, $feat:literal) => {
+        #[cfg(feature = $feat)]
+        impl sealed::Sealed for $T {
+            const MAX_SCALE: u8 = $max_scale;
+            const ONE: Self = <$T>::ONE;
+            #[inline(always)]
+            fn scale(&self) -> u8 {
+                self.scale
+            }
+            #[inline(always)]
+            fn is_neg(&self) -> bool {
+                self.neg
+            }
+            #[inline(always)]
+            fn is_zero(&self) -> bool {
+                <$T>::is_zero(self)
+            }
+            #[inline(always)]
+            fn checked_add(&self, other: &Self) -> Option<Self> {
+                <$T>::checked_add(self, other)
+            }
+            #[inline(always)]
+            fn checked_sub(&self, other: &Self) -> Option<Self> {
+                <$T>::checked_sub(self, other)
+            }
+            #[inline(always)]
+            fn mul_round_exact(&self, other: &Self, scale: u8, mode: RoundMode) -> Option<(Self, bool)> {
+                <$T>::mul_round_exact(self, other, scale, mode)
+            }
+            #[inline(always)]
+            fn div_round_exact(&self, other: &Self, scale: u8, mode: RoundMode) -> Option<(Self, bool)> {
+                <$T>::div_round_exact(self, other, scale, mode)
+            }
+            #[inline(always)]
+            fn saturating_div(&self, other: &Self) -> Self {
+                <$T>::saturating_div(self, other)
+            }
+            #[inline(always)]
+            fn normalize(&self) -> Self {
+                <$T>::normalize(&self)
+            }
+            #[inline(always)]
+            fn saturated(neg: bool) -> Self {
+                <$T>::saturated(neg)
+            }
+            #[inline(always)]
+            fn parse_str_signals(s: &str) -> Result<(Self, Signals), ParseError> {
+                <$T>::parse_str_signals(s)
+            }
+        }
+
+        #[cfg(feature = $feat)]
+        impl ContextDecimal for $T {}
+    };
+}
+
+impl_context_decimal!(crate::AncDec8, crate::util::TARGET_SCALE_8, "dec8");
+impl_context_decimal!(crate::AncDec32, crate::util::TARGET_SCALE_32, "dec32");
+impl_context_decimal!(crate::AncDec, crate::util::TARGET_SCALE, "dec64");
+impl_context_decimal!(crate::AncDec128, crate::util::TARGET_SCALE_128, "dec128");
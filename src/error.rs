@@ -1,7 +1,12 @@
 use core::fmt;
 
+use crate::context::Signal;
+
 /// Error returned when parsing a string into a decimal type fails.
+///
+/// Non-exhaustive: new failure kinds may be added in minor releases.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum ParseError {
     /// Input string is empty.
     Empty,
@@ -13,6 +18,10 @@ pub enum ParseError {
     InvalidFloat,
     /// Integer part overflows the target type's range.
     Overflow,
+    /// Nonzero fractional digits beyond the type's maximum scale (`parse_exact` only).
+    Inexact,
+    /// A signal trapped by the [`Context`](crate::Context) used for parsing.
+    Trapped(Signal),
 }
 
 impl fmt::Display for ParseError {
@@ -23,6 +32,74 @@ impl fmt::Display for ParseError {
             Self::TrailingChars => f.write_str("trailing characters"),
             Self::InvalidFloat => f.write_str("invalid float (NaN or Infinity)"),
             Self::Overflow => f.write_str("integer overflow during parsing"),
+            Self::Inexact => f.write_str("nonzero digits beyond the maximum scale"),
+            Self::Trapped(signal) => write!(f, "trapped signal: {}", signal),
+        }
+    }
+}
+
+/// Error returned by the fallible `try_*` arithmetic operations.
+///
+/// Non-exhaustive: new failure kinds may be added in minor releases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ArithmeticError {
+    /// Integer part of the result overflows the target type's range.
+    Overflow,
+    /// Divisor is zero (including zero raised to a negative power).
+    DivisionByZero,
+    /// Square root (or other even root) of a negative number.
+    NegativeSqrt,
+    /// Scale exceeds the type's maximum, or the fractional part does not fit in it.
+    ScaleOverflow,
+    /// Exact result needs nonzero digits beyond the type's maximum scale (`*_exact` only).
+    Inexact,
+    /// Argument outside the function's domain (`ln` of a non-positive number, ...).
+    OutOfDomain,
+}
+
+impl fmt::Display for ArithmeticError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow => f.write_str("arithmetic overflow"),
+            Self::DivisionByZero => f.write_str("division by zero"),
+            Self::NegativeSqrt => f.write_str("square root of negative number"),
+            Self::ScaleOverflow => f.write_str("scale overflow"),
+            Self::Inexact => f.write_str("inexact result"),
+            Self::OutOfDomain => f.write_str("argument out of domain"),
+        }
+    }
+}
+
+impl ArithmeticError {
+    /// Single panic site behind every panicking operation (operators, `div`, `sqrt`, ...).
+    #[cold]
+    #[inline(never)]
+    #[track_caller]
+    #[cfg(not(feature = "panic-free"))]
+    pub(crate) fn panic(self) -> ! {
+        panic!("{}", self)
+    }
+}
+
+/// Unwraps the result of a `try_*` operation for the APIs that cannot return `Result`.
+///
+/// Panics on error by default. With the `panic-free` feature the error is replaced by
+/// `fallback()` (the saturated value), so no panic path is compiled in.
+#[inline(always)]
+#[track_caller]
+pub(crate) fn unwrap_or_saturate<T>(
+    result: Result<T, ArithmeticError>,
+    fallback: impl FnOnce() -> T,
+) -> T {
+    match result {
+        Ok(v) => v,
+        #[cfg(not(feature = "panic-free"))]
+        Err(e) => {
+            let _ = fallback;
+            e.panic()
         }
+        #[cfg(feature = "panic-free")]
+        Err(_) => fallback(),
     }
 }
@@ -0,0 +1,1036 @@
+// Binary fixed-point engine behind the transcendental functions (`exp`, `ln`, `log`, trig)
+//
+// A `Fixed` is a sign and a 384-bit magnitude in six little-endian u64 limbs, read as
+// `mag / 2^320`: 64 integer bits and 320 fraction bits (about 96 decimal digits). That
+// covers the 77 significant digits of AncDec128 with room for guard digits. Everything is
+// integer arithmetic, so results are bit-identical on every target.
+//
+// Like `wide.rs`, preconditions (nonzero divisors, magnitudes below 2^64) are established
+// by the callers; the helpers never index out of bounds, so release builds carry no panic path.
+
+use crate::util::pow10_128;
+use crate::wide::{bit_len, cmp_limbs, div_small};
+use core::cmp::Ordering;
+
+/// Limbs of a `Fixed` magnitude
+const L: usize = 6;
+/// Double width: products and shifted dividends
+const W: usize = 12;
+/// Fraction bits, five whole limbs
+const FRAC_BITS: u32 = 320;
+
+/// Upper bound on series terms; every series here converges well before it
+const MAX_TERMS: u64 = 256;
+
+// ============ Limb Helpers ============
+
+/// `a += b` over `a`'s length (missing limbs of `b` read as zero). Returns the carry out.
+#[inline]
+fn add_limbs(a: &mut [u64], b: &[u64]) -> bool {
+    let mut carry = false;
+    for (i, x) in a.iter_mut().enumerate() {
+        let (s, c1) = x.overflowing_add(b.get(i).copied().unwrap_or(0));
+        let (s, c2) = s.overflowing_add(carry as u64);
+        *x = s;
+        carry = c1 | c2;
+    }
+    carry
+}
+
+/// `a -= b` over `a`'s length. Returns the borrow out.
+#[inline]
+fn sub_limbs(a: &mut [u64], b: &[u64]) -> bool {
+    let mut borrow = false;
+    for (i, x) in a.iter_mut().enumerate() {
+        let (d, b1) = x.overflowing_sub(b.get(i).copied().unwrap_or(0));
+        let (d, b2) = d.overflowing_sub(borrow as u64);
+        *x = d;
+        borrow = b1 | b2;
+    }
+    borrow
+}
+
+/// Sum of two sign-magnitude numbers `(magnitude, negative)`
+#[inline]
+fn add_signed(a: ([u64; W], bool), b: ([u64; W], bool)) -> ([u64; W], bool) {
+    let ((mut x, x_neg), (mut y, y_neg)) = (a, b);
+    if x_neg == y_neg {
+        add_limbs(&mut x, &y);
+        (x, x_neg)
+    } else if cmp_limbs(&x, &y) != Ordering::Less {
+        sub_limbs(&mut x, &y);
+        (x, x_neg)
+    } else {
+        sub_limbs(&mut y, &x);
+        (y, y_neg)
+    }
+}
+
+/// Bit `i` of `a` (false past the end)
+#[inline]
+fn bit(a: &[u64], i: u32) -> bool {
+    a.get((i / 64) as usize).is_some_and(|x| (x >> (i % 64)) & 1 == 1)
+}
+
+/// Whether any bit below index `n` is set
+#[inline]
+fn any_below(a: &[u64], n: u32) -> bool {
+    a.iter().enumerate().any(|(i, x)| {
+        let start = i as u32 * 64;
+        match n.saturating_sub(start) {
+            0 => false,
+            k if k >= 64 => *x != 0,
+            k => x & ((1u64 << k) - 1) != 0,
+        }
+    })
+}
+
+/// Schoolbook product into `W` limbs (the product must fit)
+#[inline]
+fn mul_limbs(a: &[u64], b: &[u64]) -> [u64; W] {
+    let mut out = [0u64; W];
+    for (i, &x) in a.iter().enumerate() {
+        if x == 0 {
+            continue;
+        }
+        let mut carry = 0u128;
+        for (j, &y) in b.iter().enumerate() {
+            if let Some(o) = out.get_mut(i + j) {
+                let t = (x as u128) * (y as u128) + *o as u128 + carry;
+                *o = t as u64;
+                carry = t >> 64;
+            }
+        }
+        // earlier rows end below i + b.len(), so this limb is still zero
+        if let Some(o) = out.get_mut(i + b.len()) {
+            *o = carry as u64;
+        }
+    }
+    out
+}
+
+/// `a >> shift` for any shift
+#[inline]
+fn shr_limbs<const N: usize>(a: &[u64; N], shift: u32) -> [u64; N] {
+    let mut out = [0u64; N];
+    let (limbs, bits) = ((shift / 64) as usize, shift % 64);
+    for (i, o) in out.iter_mut().enumerate() {
+        let lo = a.get(i + limbs).copied().unwrap_or(0);
+        let hi = a.get(i + limbs + 1).copied().unwrap_or(0);
+        *o = if bits == 0 { lo } else { (lo >> bits) | (hi << (64 - bits)) };
+    }
+    out
+}
+
+/// `a << shift`, dropping the bits shifted out
+#[inline]
+fn shl_limbs<const N: usize>(a: &[u64; N], shift: u32) -> [u64; N] {
+    let mut out = [0u64; N];
+    let (limbs, bits) = ((shift / 64) as usize, shift % 64);
+    for (i, o) in out.iter_mut().enumerate().skip(limbs) {
+        let cur = a.get(i - limbs).copied().unwrap_or(0);
+        let prev = match i - limbs {
+            0 => 0,
+            k => a.get(k - 1).copied().unwrap_or(0),
+        };
+        *o = if bits == 0 { cur } else { (cur << bits) | (prev >> (64 - bits)) };
+    }
+    out
+}
+
+/// `num / den` by shift-and-subtract (`den` nonzero, at most `L` limbs). Returns the
+/// truncated quotient and the remainder.
+fn div_limbs(num: &[u64; W], den: &[u64]) -> ([u64; W], [u64; L + 1]) {
+    let mut q = [0u64; W];
+    // rem < den before each shift, so 2 * rem + 1 fits L + 1 limbs
+    let mut rem = [0u64; L + 1];
+    for i in (0..bit_len(num)).rev() {
+        rem = shl_limbs(&rem, 1);
+        rem[0] |= bit(num, i) as u64;
+        if cmp_limbs(&rem, den) != Ordering::Less {
+            sub_limbs(&mut rem, den);
+            if let Some(x) = q.get_mut((i / 64) as usize) {
+                *x |= 1 << (i % 64);
+            }
+        }
+    }
+    (q, rem)
+}
+
+/// `a >> shift` (shift >= 1) rounded half-even
+#[inline]
+fn round_shr(a: &[u64; W], shift: u32) -> [u64; W] {
+    let mut q = shr_limbs(a, shift);
+    if bit(a, shift - 1) && (any_below(a, shift - 1) || q[0] & 1 == 1) {
+        add_limbs(&mut q, &[1]);
+    }
+    q
+}
+
+#[inline]
+fn widen(a: &[u64; L]) -> [u64; W] {
+    let mut out = [0u64; W];
+    out[..L].copy_from_slice(a);
+    out
+}
+
+/// The low `L` limbs, if the rest is zero
+#[inline]
+fn narrow(a: &[u64; W]) -> Option<[u64; L]> {
+    if a[L..].iter().any(|x| *x != 0) {
+        return None;
+    }
+    let mut out = [0u64; L];
+    out.copy_from_slice(&a[..L]);
+    Some(out)
+}
+
+/// u256 (hi, lo) as four limbs
+#[inline]
+fn u256_limbs(n: (u128, u128)) -> [u64; 4] {
+    [n.1 as u64, (n.1 >> 64) as u64, n.0 as u64, (n.0 >> 64) as u64]
+}
+
+/// The low four limbs as u256 (hi, lo), if the rest is zero
+#[inline]
+fn to_u256(a: &[u64; W]) -> Option<(u128, u128)> {
+    if a[4..].iter().any(|x| *x != 0) {
+        return None;
+    }
+    Some((
+        (a[3] as u128) << 64 | a[2] as u128,
+        (a[1] as u128) << 64 | a[0] as u128,
+    ))
+}
+
+// ============ Fixed ============
+
+/// Signed binary fixed-point number: `±mag / 2^320`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Fixed {
+    mag: [u64; L],
+    neg: bool,
+}
+
+impl Fixed {
+    pub(crate) const ZERO: Fixed = Fixed::new([0; L]);
+    pub(crate) const ONE: Fixed = Fixed::from_u64(1);
+
+    const fn new(mag: [u64; L]) -> Self {
+        Self { mag, neg: false }
+    }
+
+    pub(crate) const fn from_u64(n: u64) -> Self {
+        let mut mag = [0u64; L];
+        mag[L - 1] = n;
+        Self::new(mag)
+    }
+
+    /// Normalizes the sign of zero
+    #[inline]
+    fn signed(mag: [u64; L], neg: bool) -> Self {
+        Self {
+            mag,
+            neg: neg && mag != [0; L],
+        }
+    }
+
+    /// `±c / 10^scale` for a u256 combined magnitude. `None` if the result is 2^64 or more.
+    pub(crate) fn from_decimal(c: (u128, u128), scale: u8, neg: bool) -> Option<Self> {
+        let mut num = [0u64; W];
+        num[5..9].copy_from_slice(&u256_limbs(c));
+        let p = pow10_128(scale);
+        let (q, _) = div_limbs(&num, &[p as u64, (p >> 64) as u64]);
+        Some(Self::signed(narrow(&q)?, neg))
+    }
+
+    /// `self * 2^exp2` rounded half-even to `scale` decimal places: the combined magnitude
+    /// and its sign. `None` if the magnitude does not fit u256.
+    pub(crate) fn to_decimal(self, exp2: i32, scale: u8) -> Option<((u128, u128), bool)> {
+        let p = pow10_128(scale);
+        let prod = mul_limbs(&self.mag, &[p as u64, (p >> 64) as u64]);
+        let shift = FRAC_BITS as i32 - exp2;
+        if shift < 1 {
+            return None;
+        }
+        let n = to_u256(&round_shr(&prod, shift as u32))?;
+        Some((n, self.neg && n != (0, 0)))
+    }
+
+    #[inline]
+    pub(crate) fn is_zero(&self) -> bool {
+        self.mag == [0; L]
+    }
+
+    #[inline]
+    pub(crate) fn is_neg(&self) -> bool {
+        self.neg
+    }
+
+    #[inline]
+    pub(crate) fn neg(self) -> Self {
+        Self::signed(self.mag, !self.neg)
+    }
+
+    #[inline]
+    pub(crate) fn abs(self) -> Self {
+        Self::new(self.mag)
+    }
+
+    /// Applies a sign to the magnitude
+    #[inline]
+    pub(crate) fn with_sign(self, neg: bool) -> Self {
+        Self::signed(self.mag, neg)
+    }
+
+    /// Truncating `self / 2^bits`
+    #[inline]
+    pub(crate) fn shr(self, bits: u32) -> Self {
+        Self::signed(shr_limbs(&self.mag, bits), self.neg)
+    }
+
+    /// Compares magnitudes
+    #[inline]
+    pub(crate) fn cmp_abs(&self, other: &Self) -> Ordering {
+        cmp_limbs(&self.mag, &other.mag)
+    }
+
+    pub(crate) fn add(self, other: Self) -> Self {
+        let mut mag = self.mag;
+        if self.neg == other.neg {
+            add_limbs(&mut mag, &other.mag);
+            Self::signed(mag, self.neg)
+        } else if self.cmp_abs(&other) != Ordering::Less {
+            sub_limbs(&mut mag, &other.mag);
+            Self::signed(mag, self.neg)
+        } else {
+            let mut mag = other.mag;
+            sub_limbs(&mut mag, &self.mag);
+            Self::signed(mag, other.neg)
+        }
+    }
+
+    #[inline]
+    pub(crate) fn sub(self, other: Self) -> Self {
+        self.add(other.neg())
+    }
+
+    /// Product rounded to the nearest multiple of 2^-320. `None` if it is 2^64 or more.
+    pub(crate) fn checked_mul(self, other: Self) -> Option<Self> {
+        let mut p = mul_limbs(&self.mag, &other.mag);
+        // half of the last kept bit: 2^319
+        add_limbs(&mut p, &[0, 0, 0, 0, 1 << 63]);
+        Some(Self::signed(narrow(&shr_limbs(&p, FRAC_BITS))?, self.neg ^ other.neg))
+    }
+
+    /// [`checked_mul`](Self::checked_mul) for operands whose product is known to fit
+    #[inline]
+    pub(crate) fn mul(self, other: Self) -> Self {
+        self.checked_mul(other).unwrap_or(Self::ZERO)
+    }
+
+    pub(crate) fn mul_u64(self, n: u64) -> Self {
+        let p = mul_limbs(&self.mag, &[n]);
+        let mut mag = [0u64; L];
+        mag.copy_from_slice(&p[..L]);
+        Self::signed(mag, self.neg)
+    }
+
+    #[inline]
+    pub(crate) fn mul_i64(self, n: i64) -> Self {
+        let p = self.mul_u64(n.unsigned_abs());
+        if n < 0 {
+            p.neg()
+        } else {
+            p
+        }
+    }
+
+    /// Truncating division by a nonzero integer
+    #[inline]
+    pub(crate) fn div_u64(self, n: u64) -> Self {
+        let mut mag = self.mag;
+        div_small(&mut mag, n);
+        Self::signed(mag, self.neg)
+    }
+
+    /// Truncating division. `None` if `other` is zero or the quotient is 2^64 or more.
+    pub(crate) fn div(self, other: Self) -> Option<Self> {
+        if other.is_zero() {
+            return None;
+        }
+        let num = shl_limbs(&widen(&self.mag), FRAC_BITS);
+        let (q, _) = div_limbs(&num, &other.mag);
+        Some(Self::signed(narrow(&q)?, self.neg ^ other.neg))
+    }
+
+    /// Nearest integer, ties away from zero (`|self| < 2^63`)
+    pub(crate) fn round_i64(self) -> i64 {
+        let mut mag = self.mag;
+        add_limbs(&mut mag, &[0, 0, 0, 0, 1 << 63]);
+        let n = mag[L - 1] as i64;
+        if self.neg {
+            -n
+        } else {
+            n
+        }
+    }
+}
+
+// ============ Constants ============
+// value * 2^320, rounded to nearest
+
+/// ln 2
+pub(crate) const LN2: Fixed = Fixed::new([
+    0xe7b8_7620_6deb_ac98,
+    0x8a0d_175b_8baa_fa2b,
+    0x40f3_4326_7298_b62d,
+    0xc9e3_b398_03f2_f6af,
+    0xb172_17f7_d1cf_79ab,
+    0x0000_0000_0000_0000,
+]);
+
+/// ln 10
+#[cfg(any(feature = "dec64", feature = "dec128"))]
+pub(crate) const LN10: Fixed = Fixed::new([
+    0x58bc_0b5e_c6a0_4173,
+    0x0f18_7a08_07c0_b5ca,
+    0x8a3f_b3e7_6977_e43a,
+    0xa95b_58ae_0b4c_28a3,
+    0x4d76_3776_aaa2_b05b,
+    0x0000_0000_0000_0002,
+]);
+
+/// 1 / ln 2
+pub(crate) const INV_LN2: Fixed = Fixed::new([
+    0xbc38_87ee_aa2e_d9ac,
+    0x164a_2cd9_a342_648f,
+    0xd6ae_f551_bad2_b4b1,
+    0x7d0f_fda0_d23a_7d11,
+    0x7154_7652_b82f_e177,
+    0x0000_0000_0000_0001,
+]);
+
+/// 1 / ln 10
+#[cfg(any(feature = "dec64", feature = "dec128"))]
+pub(crate) const INV_LN10: Fixed = Fixed::new([
+    0x3aa1_277d_0a01_79f9,
+    0x1d1f_96a2_7bc7_529e,
+    0x1f71_a301_22e4_d101,
+    0x9aad_d557_d699_ee19,
+    0x6f2d_ec54_9b94_38ca,
+    0x0000_0000_0000_0000,
+]);
+
+/// π / 2
+const HALF_PI: Fixed = Fixed::new([
+    0xa294_10f3_1c68_09bc,
+    0x0417_7d4c_7627_3644,
+    0x5204_9c11_14cf_98e8,
+    0x898c_c517_01b8_39a2,
+    0x921f_b544_42d1_8469,
+    0x0000_0000_0000_0001,
+]);
+
+/// `HALF_PI - π/2`, times 2^640: the next 320 bits of π/2, for [`pole_offset`]
+const HALF_PI_LO: [u64; 5] = [
+    0x1767_7a2c_b390_252a,
+    0x36f4_9513_3b43_0272,
+    0x603d_9525_255c_7b74,
+    0x9fa9_eb24_1b41_d791,
+    0x20d5_cc98_658b_79c9,
+];
+
+/// √2
+#[cfg(any(feature = "dec64", feature = "dec128"))]
+const SQRT2: Fixed = Fixed::new([
+    0x95f9_0608_7571_4587,
+    0xda2f_590b_0667_322a,
+    0x3ade_c175_1277_5099,
+    0xb2fb_1366_ea95_7d3e,
+    0x6a09_e667_f3bc_c908,
+    0x0000_0000_0000_0001,
+]);
+
+/// 2 / √π
+#[cfg(any(feature = "dec64", feature = "dec128"))]
+const FRAC_2_SQRT_PI: Fixed = Fixed::new([
+    0x80eb_39f0_b24e_1e23,
+    0x7342_b061_99cc_4161,
+    0x2813_41d7_587c_ea2e,
+    0xe3a9_14fe_d7fd_8688,
+    0x20dd_7504_29b6_d11a,
+    0x0000_0000_0000_0001,
+]);
+
+/// 1 / √(2π)
+#[cfg(any(feature = "dec64", feature = "dec128"))]
+const FRAC_1_SQRT_2PI: Fixed = Fixed::new([
+    0x9df8_ac55_447d_3db7,
+    0xc047_1b48_0263_9d2e,
+    0xd382_9f30_5127_06d8,
+    0x3f2c_f140_2eae_38bf,
+    0x6621_14cf_50d9_4234,
+    0x0000_0000_0000_0000,
+]);
+
+// ============ Exponential and Logarithm ============
+
+/// `e^x` as a mantissa in `[1/√2, √2]` and a binary exponent, `e^x = m * 2^k`.
+/// Requires `|x| < 2^40`; callers bound it far lower.
+pub(crate) fn exp(x: Fixed) -> (Fixed, i32) {
+    let k = x.mul(INV_LN2).round_i64();
+    let r = x.sub(LN2.mul_i64(k));
+
+    // Taylor series; |r| <= ln2 / 2, so the terms drop below 2^-320 within ~60 steps
+    let (mut sum, mut term) = (Fixed::ONE, Fixed::ONE);
+    for n in 1..MAX_TERMS {
+        term = term.mul(r).div_u64(n);
+        if term.is_zero() {
+            break;
+        }
+        sum = sum.add(term);
+    }
+    (sum, k as i32)
+}
+
+/// `e^x` rounded half-even to `scale` decimal places (see [`Fixed::to_decimal`]).
+/// `None` if the result does not fit u256.
+#[cfg(any(feature = "dec64", feature = "dec128"))]
+pub(crate) fn exp_to_decimal(x: Fixed, scale: u8) -> Option<((u128, u128), bool)> {
+    // e^200 overflows every type and e^-200 rounds to zero at every scale
+    if x.cmp_abs(&Fixed::from_u64(200)) != Ordering::Less {
+        return if x.is_neg() { Some(((0, 0), false)) } else { None };
+    }
+    let (m, k) = exp(x);
+    m.to_decimal(k, scale)
+}
+
+/// `e^x` for `±c / 10^scale`, as in [`exp_to_decimal`]
+#[cfg(any(feature = "dec64", feature = "dec128"))]
+pub(crate) fn exp_decimal(
+    c: (u128, u128),
+    scale: u8,
+    neg: bool,
+    out_scale: u8,
+) -> Option<((u128, u128), bool)> {
+    match Fixed::from_decimal(c, scale, neg) {
+        Some(x) => exp_to_decimal(x, out_scale),
+        None if neg => Some(((0, 0), false)),
+        None => None,
+    }
+}
+
+/// `ln(c / 10^scale)` for `c > 0`
+#[cfg(any(feature = "dec64", feature = "dec128"))]
+pub(crate) fn ln_decimal(c: (u128, u128), scale: u8) -> Fixed {
+    let limbs = u256_limbs(c);
+    let b = bit_len(&limbs).saturating_sub(1);
+    // m = c / 2^b in [1, 2), exact: c has at most 256 bits
+    let mut wide = [0u64; W];
+    wide[..4].copy_from_slice(&limbs);
+    let mut m = Fixed::new(narrow(&shl_limbs(&wide, FRAC_BITS - b)).unwrap_or([0; L]));
+    let mut e2 = b as i64;
+    if m.cmp_abs(&SQRT2) == Ordering::Greater {
+        // the low bits of m are zero, so halving is exact
+        m = Fixed::new(shr_limbs(&m.mag, 1));
+        e2 += 1;
+    }
+    ln_mantissa(m)
+        .add(LN2.mul_i64(e2))
+        .sub(LN10.mul_u64(scale as u64))
+}
+
+/// `ln(m)` for `m` in `[1/√2, √2]` as `2 atanh(z)`, `z = (m - 1) / (m + 1)`, `|z| <= 0.172`
+#[cfg(any(feature = "dec64", feature = "dec128"))]
+fn ln_mantissa(m: Fixed) -> Fixed {
+    let z = m.sub(Fixed::ONE).div(m.add(Fixed::ONE)).unwrap_or(Fixed::ZERO);
+    let z2 = z.mul(z);
+    let (mut sum, mut p) = (Fixed::ZERO, z);
+    for k in 0..MAX_TERMS {
+        let t = p.div_u64(2 * k + 1);
+        if t.is_zero() {
+            break;
+        }
+        sum = sum.add(t);
+        p = p.mul(z2);
+    }
+    sum.mul_u64(2)
+}
+
+/// `a / b` rounded half-even to `scale` decimal places, as in [`Fixed::to_decimal`].
+/// `None` if `b` is zero or the result does not fit u256.
+pub(crate) fn div_to_decimal(a: Fixed, b: Fixed, scale: u8) -> Option<((u128, u128), bool)> {
+    if b.is_zero() {
+        return None;
+    }
+    let p = pow10_128(scale);
+    let num = mul_limbs(&a.mag, &[p as u64, (p >> 64) as u64]);
+    let (mut q, rem) = div_limbs(&num, &b.mag);
+    // half-even: compare 2 * rem against b
+    match cmp_limbs(&shl_limbs(&rem, 1), &b.mag) {
+        Ordering::Greater => {
+            add_limbs(&mut q, &[1]);
+        }
+        Ordering::Equal if q[0] & 1 == 1 => {
+            add_limbs(&mut q, &[1]);
+        }
+        _ => {}
+    }
+    let n = to_u256(&q)?;
+    Some((n, (a.neg ^ b.neg) && n != (0, 0)))
+}
+
+/// `x / ln(b / 10^scale)` rounded half-even to `out_scale` places, given `ln_b` from
+/// [`ln_decimal`]. For a base within 2^-32 of one, `ln_b` keeps too few significant bits, so
+/// `ln(1 + e) = e * g(e)` with `g(e) = 1 - e/2 + e^2/3 - ...` near one, and the division by
+/// the exact decimal `e = b - 1` is done in integers. `None` as in [`div_to_decimal`].
+#[cfg(feature = "dec128")]
+pub(crate) fn log_decimal(
+    x: Fixed,
+    ln_b: Fixed,
+    b: (u128, u128),
+    scale: u8,
+    out_scale: u8,
+) -> Option<((u128, u128), bool)> {
+    let p = pow10_128(scale);
+    let e = b.1.abs_diff(p);
+    if b.0 != 0 || e == 0 || e.checked_mul(1 << 32).is_none_or(|e| e >= p) {
+        return div_to_decimal(x, ln_b, out_scale);
+    }
+    let eps = Fixed::from_decimal((0, e), scale, b.1 < p)?;
+    // |e| < 2^-32, so the terms drop below 2^-320 within ten steps
+    let (mut g, mut pow) = (Fixed::ONE, Fixed::ONE);
+    for k in 2..MAX_TERMS {
+        pow = pow.mul(eps).neg();
+        let t = pow.div_u64(k);
+        if t.is_zero() {
+            break;
+        }
+        g = g.add(t);
+    }
+    // x / g, over e / 10^scale: (x / g) * 10^(scale + out_scale) / e, still times 2^320
+    let a = x.div(g)?;
+    let q = pow10_128(out_scale);
+    let num = mul_limbs(&mul_limbs(&a.mag, &[p as u64, (p >> 64) as u64]), &[q as u64, (q >> 64) as u64]);
+    let (mut quot, rem) = div_limbs(&num, &u256_limbs((0, e)));
+    // a nonzero remainder breaks a tie in the dropped bits
+    quot[0] |= (rem != [0; L + 1]) as u64;
+    let n = to_u256(&round_shr(&quot, FRAC_BITS))?;
+    Some((n, (a.neg ^ eps.neg) && n != (0, 0)))
+}
+
+// ============ Trigonometric ============
+
+/// Reduces the magnitude `c / 10^scale` modulo π/2: the remainder in `[0, π/2)` and the
+/// quadrant (quotient mod 4). The error of `HALF_PI` grows with the quotient, up to 2^-193
+/// for the largest AncDec128, still far below the last stored digit.
+fn reduce_half_pi(c: (u128, u128), scale: u8) -> (Fixed, u64) {
+    let mut num = [0u64; W];
+    num[5..9].copy_from_slice(&u256_limbs(c));
+    let p = pow10_128(scale);
+    let (x, _) = div_limbs(&num, &[p as u64, (p >> 64) as u64]);
+    let (k, r) = div_limbs(&x, &HALF_PI.mag);
+    let mut mag = [0u64; L];
+    mag.copy_from_slice(&r[..L]);
+    (Fixed::new(mag), k[0] & 3)
+}
+
+/// `(sin r, cos r)` for `0 <= r < π/2` by their Taylor series (about 80 terms)
+fn sin_cos(r: Fixed) -> (Fixed, Fixed) {
+    let (mut sin, mut cos) = (Fixed::ZERO, Fixed::ZERO);
+    // term = r^n / n!
+    let mut term = Fixed::ONE;
+    for n in 0..MAX_TERMS {
+        if term.is_zero() {
+            break;
+        }
+        match n % 4 {
+            0 => cos = cos.add(term),
+            1 => sin = sin.add(term),
+            2 => cos = cos.sub(term),
+            _ => sin = sin.sub(term),
+        }
+        term = term.mul(r).div_u64(n + 1);
+    }
+    (sin, cos)
+}
+
+/// `sin(±c / 10^scale)`
+pub(crate) fn sin_decimal(c: (u128, u128), scale: u8, neg: bool) -> Fixed {
+    let (r, q) = reduce_half_pi(c, scale);
+    let (sin, cos) = sin_cos(r);
+    let v = if q % 2 == 0 { sin } else { cos };
+    v.with_sign(neg ^ (q >= 2))
+}
+
+/// `cos(c / 10^scale)` (cosine is even, so the sign of the argument does not matter)
+pub(crate) fn cos_decimal(c: (u128, u128), scale: u8) -> Fixed {
+    let (r, q) = reduce_half_pi(c, scale);
+    let (sin, cos) = sin_cos(r);
+    let v = if q % 2 == 0 { cos } else { sin };
+    v.with_sign(q == 1 || q == 2)
+}
+
+/// `(cos r, sin r / r)` by their Taylor series in `r^2`, so `sin r = r * sinc` keeps its
+/// relative precision however small `r` is (about 45 terms up to π/2)
+fn cos_sinc(r: Fixed) -> (Fixed, Fixed) {
+    let r2 = r.mul(r);
+    let (mut cos, mut sinc) = (Fixed::ZERO, Fixed::ZERO);
+    // term = ±r^2n / (2n)!
+    let mut term = Fixed::ONE;
+    for n in 0..MAX_TERMS {
+        if term.is_zero() {
+            break;
+        }
+        cos = cos.add(term);
+        sinc = sinc.add(term.div_u64(2 * n + 1));
+        term = term.mul(r2).div_u64((2 * n + 1) * (2 * n + 2)).neg();
+    }
+    (cos, sinc)
+}
+
+/// Offset `e = c / 10^scale - m·π/2` of the magnitude from the pole `m·π/2` (`m` odd) bounding
+/// its quadrant: `|e| * 2^exp2` in `[1/2, 2)`, the sign of `e`, and `exp2` (at most 256).
+/// With π/2 to 640 bits the error stays below `m * 2^-641`, so next to a pole of the largest
+/// AncDec128, where tan nears 2^128, `|e|` still carries about 380 significant bits.
+fn pole_offset(c: (u128, u128), scale: u8) -> Option<(Fixed, bool, u32)> {
+    let mut num = [0u64; W];
+    num[5..9].copy_from_slice(&u256_limbs(c));
+    let p = pow10_128(scale);
+    let p = [p as u64, (p >> 64) as u64];
+    let (x, _) = div_limbs(&num, &p);
+    let (k, _) = div_limbs(&x, &HALF_PI.mag);
+    // the pole above an even quadrant, below an odd one; m * 10^scale < 2^255
+    let m = mul_limbs(&[k[0] | 1, k[1]], &p);
+    // c * 2^320 - m * 10^scale * HALF_PI is exact and below 2^448 (|e| < 1.6)
+    let a = add_signed((num, false), (mul_limbs(&m[..4], &HALF_PI.mag), true));
+    // e * 10^scale * 2^640, HALF_PI being above π/2 by HALF_PI_LO / 2^640
+    let (d, e_neg) = add_signed((shl_limbs(&a.0, FRAC_BITS), a.1), (mul_limbs(&m[..4], &HALF_PI_LO), false));
+    let exp2 = (2 * FRAC_BITS + bit_len(&p)).saturating_sub(bit_len(&d)).min(256);
+    let (e, _) = div_limbs(&shr_limbs(&d, FRAC_BITS - exp2), &p);
+    Some((Fixed::new(narrow(&e)?), e_neg, exp2))
+}
+
+/// `tan(±c / 10^scale)` rounded half-even to `out_scale` places, as in [`Fixed::to_decimal`].
+/// Evaluated as `tan(m·π/2 + e) = -cot e` from [`pole_offset`], whose scaled `|e|` keeps the
+/// relative precision of the result however close the argument lies to a pole.
+pub(crate) fn tan_decimal(
+    c: (u128, u128),
+    scale: u8,
+    neg: bool,
+    out_scale: u8,
+) -> Option<((u128, u128), bool)> {
+    let (e, e_neg, exp2) = pole_offset(c, scale)?;
+    let (cos, sinc) = cos_sinc(e.shr(exp2));
+    // cot|e| = cos|e| / (|e| * sinc|e|), with |e| = e / 2^exp2
+    let t = cos.div(e.mul(sinc))?;
+    t.with_sign(t.is_neg() ^ !e_neg ^ neg).to_decimal(exp2 as i32, out_scale)
+}
+
+/// `atan(t)` for `0 <= t <= 1`. Above 1/2 through `atan(t) = π/4 + atan((t - 1) / (t + 1))`,
+/// so the Taylor series always runs with `|t| <= 1/2` (about 160 terms).
+fn atan_unit(t: Fixed) -> Fixed {
+    let half = Fixed::ONE.shr(1);
+    let (base, t) = match t.cmp_abs(&half) {
+        Ordering::Greater => {
+            let u = t.sub(Fixed::ONE).div(t.add(Fixed::ONE)).unwrap_or(Fixed::ZERO);
+            (HALF_PI.shr(1), u)
+        }
+        _ => (Fixed::ZERO, t),
+    };
+    let t2 = t.mul(t);
+    let (mut sum, mut p) = (Fixed::ZERO, t);
+    for k in 0..MAX_TERMS {
+        let term = p.div_u64(2 * k + 1);
+        if term.is_zero() {
+            break;
+        }
+        sum = if k % 2 == 0 { sum.add(term) } else { sum.sub(term) };
+        p = p.mul(t2);
+    }
+    base.add(sum)
+}
+
+/// First-quadrant angle `[0, π/2]` of a point from `min(|x|, |y|) / max(|x|, |y|)` and
+/// whether `|y|` is the larger
+#[inline]
+fn first_quadrant(ratio: Fixed, y_major: bool) -> Fixed {
+    let t = atan_unit(ratio);
+    if y_major {
+        HALF_PI.sub(t)
+    } else {
+        t
+    }
+}
+
+/// Places a first-quadrant angle in `(-π, π]` by the signs of the coordinates
+#[inline]
+fn place(theta: Fixed, y_neg: bool, x_neg: bool) -> Fixed {
+    let t = if x_neg {
+        HALF_PI.mul_u64(2).sub(theta)
+    } else {
+        theta
+    };
+    t.with_sign(y_neg)
+}
+
+/// `atan2(y, x)` for decimal coordinates `(c, scale, neg)`; `atan2(0, 0)` is zero.
+/// The ratio of the magnitudes is formed exactly before the single division, so the
+/// coordinates may be as large as AncDec128 allows.
+pub(crate) fn atan2_decimal(y: ((u128, u128), u8, bool), x: ((u128, u128), u8, bool)) -> Fixed {
+    // |y| * 10^sx and |x| * 10^sy share a scale; both stay below 2^383
+    let py = pow10_128(x.1);
+    let px = pow10_128(y.1);
+    let a = mul_limbs(&u256_limbs(y.0), &[py as u64, (py >> 64) as u64]);
+    let b = mul_limbs(&u256_limbs(x.0), &[px as u64, (px >> 64) as u64]);
+    let y_major = cmp_limbs(&a, &b) == Ordering::Greater;
+    let (lo, hi) = if y_major { (&b, &a) } else { (&a, &b) };
+    if bit_len(hi) == 0 {
+        return Fixed::ZERO;
+    }
+    let (q, _) = div_limbs(&shl_limbs(lo, FRAC_BITS), &hi[..L]);
+    let ratio = Fixed::new(narrow(&q).unwrap_or([0; L]));
+    place(first_quadrant(ratio, y_major), y.2, x.2)
+}
+
+/// `√v` for `0 <= v <= 1` by Newton's method from 1, which descends monotonically
+fn sqrt_unit(v: Fixed) -> Fixed {
+    let mut y = Fixed::ONE;
+    if v.is_zero() {
+        return Fixed::ZERO;
+    }
+    // halving down to at least 2^-160 and then a few quadratic steps
+    for _ in 0..MAX_TERMS {
+        let next = y.add(v.div(y).unwrap_or(Fixed::ZERO)).shr(1);
+        if next.cmp_abs(&y) != Ordering::Less {
+            break;
+        }
+        y = next;
+    }
+    y
+}
+
+/// `(|x|, √(1 - x²))` for `|x| <= 1`: the legs whose angle gives `asin` and `acos`
+#[inline]
+fn legs(x: Fixed) -> (Fixed, Fixed) {
+    let x = x.abs();
+    (x, sqrt_unit(Fixed::ONE.sub(x.mul(x))))
+}
+
+/// First-quadrant angle of the point `(x, y)` with `x, y >= 0`
+#[inline]
+fn angle(y: Fixed, x: Fixed) -> Fixed {
+    match y.cmp_abs(&x) {
+        Ordering::Greater => first_quadrant(x.div(y).unwrap_or(Fixed::ZERO), true),
+        _ => first_quadrant(y.div(x).unwrap_or(Fixed::ZERO), false),
+    }
+}
+
+/// `asin(x)` for `|x| <= 1`
+pub(crate) fn asin(x: Fixed) -> Fixed {
+    let (a, b) = legs(x);
+    angle(a, b).with_sign(x.is_neg())
+}
+
+/// `acos(x)` for `|x| <= 1`
+pub(crate) fn acos(x: Fixed) -> Fixed {
+    let (a, b) = legs(x);
+    place(angle(b, a), false, x.is_neg())
+}
+
+// ============ Hyperbolic ============
+
+/// `e^|x| ∓ e^-|x|`, both scaled by `2^-k`, and `k`
+fn exp_pair(x: Fixed) -> (Fixed, Fixed, i32) {
+    let (m, k) = exp(x.abs());
+    // e^-|x| = m^-1 * 2^-k, so relative to 2^k it is m^-1 * 2^-2k
+    let inv = Fixed::ONE.div(m).unwrap_or(Fixed::ZERO).shr(2 * k.max(0) as u32);
+    (m.sub(inv), m.add(inv), k)
+}
+
+/// `sinh(±c / 10^scale)` rounded half-even to `out_scale` places. `None` on overflow.
+pub(crate) fn sinh_decimal(
+    c: (u128, u128),
+    scale: u8,
+    neg: bool,
+    out_scale: u8,
+) -> Option<((u128, u128), bool)> {
+    let x = Fixed::from_decimal(c, scale, neg)?;
+    if x.cmp_abs(&Fixed::from_u64(200)) != Ordering::Less {
+        return None;
+    }
+    let (d, _, k) = exp_pair(x);
+    d.with_sign(neg).to_decimal(k - 1, out_scale)
+}
+
+/// `cosh(c / 10^scale)` rounded half-even to `out_scale` places. `None` on overflow.
+pub(crate) fn cosh_decimal(c: (u128, u128), scale: u8, out_scale: u8) -> Option<((u128, u128), bool)> {
+    let x = Fixed::from_decimal(c, scale, false)?;
+    if x.cmp_abs(&Fixed::from_u64(200)) != Ordering::Less {
+        return None;
+    }
+    let (_, s, k) = exp_pair(x);
+    s.to_decimal(k - 1, out_scale)
+}
+
+/// `tanh(±c / 10^scale)` rounded half-even to `out_scale` places
+pub(crate) fn tanh_decimal(
+    c: (u128, u128),
+    scale: u8,
+    neg: bool,
+    out_scale: u8,
+) -> Option<((u128, u128), bool)> {
+    // 1 - tanh(64) < 2^-180 rounds away at every scale
+    match Fixed::from_decimal(c, scale, neg) {
+        Some(x) if x.cmp_abs(&Fixed::from_u64(64)) == Ordering::Less => {
+            let (d, s, _) = exp_pair(x);
+            div_to_decimal(d.with_sign(neg), s, out_scale)
+        }
+        _ => Fixed::ONE.with_sign(neg).to_decimal(0, out_scale),
+    }
+}
+
+// ============ Error Function ============
+
+/// `(erf z, m, k)` for `z >= 0`, with `erfc z = m * 2^k`. Below 6 through the series
+/// `erf z = 2/√π e^-z² Σ 2^n z^(2n+1) / (2n+1)!!`: with `e^z² = m * 2^k` the complement
+/// `m - 2/√π Σ 2^-k` is free of cancellation, so erfc keeps its relative precision deep in
+/// the tail. From 6 on through the continued fraction of `e^z² erfc z`.
+#[cfg(any(feature = "dec64", feature = "dec128"))]
+fn erf_parts(z: Fixed) -> (Fixed, Fixed, i32) {
+    // erfc(16) < 10^-112 rounds to zero at every scale
+    if z.cmp_abs(&Fixed::from_u64(16)) != Ordering::Less {
+        return (Fixed::ONE, Fixed::ZERO, 0);
+    }
+    let z2 = z.mul(z);
+    if z.cmp_abs(&Fixed::from_u64(6)) == Ordering::Less {
+        // the terms peak near n = z², below 2^57; about 240 of them at z = 6
+        let step = z2.mul_u64(2);
+        let (mut sum, mut term) = (z, z);
+        for n in 1..MAX_TERMS {
+            term = term.mul(step).div_u64(2 * n + 1);
+            if term.is_zero() {
+                break;
+            }
+            sum = sum.add(term);
+        }
+        let (m, k) = exp(z2);
+        let s = FRAC_2_SQRT_PI.mul(sum).shr(k as u32);
+        let erfc = m.sub(s).div(m).unwrap_or(Fixed::ZERO);
+        return (Fixed::ONE.sub(erfc), erfc, 0);
+    }
+    // e^z² erfc z = 1/√π / t, t = z + (1/2) / (z + (2/2) / (z + (3/2) / ...)), evaluated
+    // from the bottom as a ratio p / q (one division in all), rescaled to stay below 2^64.
+    // 1260 / z levels bring the relative error below 2^-300.
+    let levels = MAX_TERMS.min(1260 / (z.round_i64() as u64).max(6));
+    let (mut p, mut q) = (z, Fixed::ONE);
+    for n in (1..=levels).rev() {
+        (p, q) = (z.mul(p).add(q.mul_u64(n).shr(1)), p);
+        if p.cmp_abs(&Fixed::from_u64(1 << 32)) != Ordering::Less {
+            (p, q) = (p.shr(32), q.shr(32));
+        }
+    }
+    let (m, k) = exp(z2.neg());
+    let erfc = m.mul(FRAC_2_SQRT_PI.shr(1)).mul(q).div(p).unwrap_or(Fixed::ZERO);
+    (Fixed::ONE.sub(erfc.shr(k.unsigned_abs())), erfc, k)
+}
+
+/// The parts of [`erf_parts`] for the magnitude `c / 10^scale`, times `factor`
+#[cfg(any(feature = "dec64", feature = "dec128"))]
+#[inline]
+fn erf_parts_decimal(c: (u128, u128), scale: u8, factor: Fixed) -> (Fixed, Fixed, i32) {
+    match Fixed::from_decimal(c, scale, false) {
+        Some(z) => erf_parts(z.mul(factor)),
+        None => (Fixed::ONE, Fixed::ZERO, 0),
+    }
+}
+
+/// `erf(±c / 10^scale)` rounded half-even to `out_scale` places
+#[cfg(any(feature = "dec64", feature = "dec128"))]
+pub(crate) fn erf_decimal(
+    c: (u128, u128),
+    scale: u8,
+    neg: bool,
+    out_scale: u8,
+) -> Option<((u128, u128), bool)> {
+    let (erf, _, _) = erf_parts_decimal(c, scale, Fixed::ONE);
+    erf.with_sign(neg).to_decimal(0, out_scale)
+}
+
+/// `erfc(±c / 10^scale)` rounded half-even to `out_scale` places; `erfc(-z) = 1 + erf z`
+#[cfg(any(feature = "dec64", feature = "dec128"))]
+pub(crate) fn erfc_decimal(
+    c: (u128, u128),
+    scale: u8,
+    neg: bool,
+    out_scale: u8,
+) -> Option<((u128, u128), bool)> {
+    let (erf, m, k) = erf_parts_decimal(c, scale, Fixed::ONE);
+    if neg {
+        Fixed::ONE.add(erf).to_decimal(0, out_scale)
+    } else {
+        m.to_decimal(k, out_scale)
+    }
+}
+
+/// Standard normal CDF `Φ(±c / 10^scale) = erfc(∓x / √2) / 2`, rounded half-even to
+/// `out_scale` places
+#[cfg(any(feature = "dec64", feature = "dec128"))]
+pub(crate) fn norm_cdf_decimal(
+    c: (u128, u128),
+    scale: u8,
+    neg: bool,
+    out_scale: u8,
+) -> Option<((u128, u128), bool)> {
+    let (erf, m, k) = erf_parts_decimal(c, scale, SQRT2.shr(1));
+    if neg {
+        m.to_decimal(k - 1, out_scale)
+    } else {
+        Fixed::ONE.add(erf).to_decimal(-1, out_scale)
+    }
+}
+
+/// `Φ(x)` for `x <= 0`
+#[cfg(any(feature = "dec64", feature = "dec128"))]
+fn norm_cdf_lower(x: Fixed) -> Fixed {
+    let (_, m, k) = erf_parts(x.abs().mul(SQRT2.shr(1)));
+    m.shr(k.unsigned_abs() + 1)
+}
+
+/// Inverse standard normal CDF of `p = c / 10^scale`, `0 < p < 1`. For `p <= 1/2`,
+/// bisection on `[-16, 0]` down to a 2^-6 bracket, then Newton's method from its right
+/// end: `Φ` is convex on the negative half-line, so the iterates descend monotonically
+/// onto the root. The upper half follows from `Φ⁻¹(1 - p) = -Φ⁻¹(p)`.
+#[cfg(any(feature = "dec64", feature = "dec128"))]
+pub(crate) fn norm_inv_cdf_decimal(c: (u128, u128), scale: u8) -> Fixed {
+    let p = Fixed::from_decimal(c, scale, false).unwrap_or(Fixed::ZERO);
+    let (q, upper) = match p.cmp_abs(&Fixed::ONE.shr(1)) {
+        Ordering::Greater => (Fixed::ONE.sub(p), true),
+        _ => (p, false),
+    };
+    let (mut lo, mut hi) = (Fixed::from_u64(16).neg(), Fixed::ZERO);
+    for _ in 0..10 {
+        let mid = lo.add(hi).shr(1);
+        match norm_cdf_lower(mid).cmp_abs(&q) {
+            Ordering::Less => lo = mid,
+            _ => hi = mid,
+        }
+    }
+    // steps below 2^-200 no longer reach the last stored digit
+    let tiny = Fixed::ONE.shr(200);
+    let mut x = hi;
+    for _ in 0..MAX_TERMS {
+        let (m, k) = exp(x.mul(x).shr(1).neg());
+        let pdf = m.mul(FRAC_1_SQRT_2PI).shr(k.unsigned_abs());
+        let step = norm_cdf_lower(x).sub(q).div(pdf).unwrap_or(Fixed::ZERO);
+        if step.is_neg() || step.is_zero() {
+            break;
+        }
+        x = x.sub(step);
+        if step.cmp_abs(&tiny) == Ordering::Less {
+            break;
+        }
+    }
+    x.with_sign(!upper)
+}
@@ -15,6 +15,24 @@
 //! - **`dec8`** / **`dec32`** / **`dec64`** / **`dec128`** -- enable individual types (all on by default)
 //! - **`serde`** -- string-based `Serialize`/`Deserialize`
 //! - **`sqlx`** -- PostgreSQL `NUMERIC` support (implies `std` + `dec64`)
+//! - **`panic-free`** -- operators and other non-`Result` APIs saturate instead of panicking
+//! - **`rand`** -- `round_stochastic` driven by any [`rand_core`](https://docs.rs/rand_core) RNG
+//!
+//! # Error handling
+//!
+//! Every operation that can fail has a `try_*` form returning [`ArithmeticError`]
+//! (and a `checked_*` form returning `Option`). The operators and plain methods
+//! (`+`, `/`, `div`, `sqrt`, `pow`, ...) panic on the same conditions,
+//! unless the `panic-free` feature is enabled, in which case they return the
+//! saturated value (`MAX` / `-MAX` with the exact result's sign, `ZERO` where no
+//! sign applies) and a release build contains no explicit panic (debug builds keep the
+//! internal `debug_assert!` invariant checks). Float conversions
+//! (`TryFrom<f64>`) still go through `core`'s float formatter and its internal checks.
+//!
+//! To audit precision loss, run operations through a [`Context`]: it rounds every result
+//! with one rounding mode and scale, records sticky [`Signal`] flags (`Inexact`,
+//! `Rounded`, `Clamped` for digits dropped while parsing, ...) and returns `Err` for the
+//! signals it traps.
 
 #![no_std]
 
@@ -22,17 +40,23 @@
 extern crate std;
 
 // Shared modules (always compiled)
+mod context;
 mod error;
 mod round_mode;
 mod util;
 
-pub use error::ParseError;
-pub use round_mode::RoundMode;
+pub use context::{Context, ContextDecimal, Signal, Signals};
+pub use error::{ArithmeticError, ParseError};
+pub use round_mode::{ParseRoundModeError, RoundMode};
 
-// Wide arithmetic: needed by dec32 (isqrt_u128), dec64 and dec128
-#[cfg(any(feature = "dec32", feature = "dec64", feature = "dec128"))]
+// Wide arithmetic: needed by dec32 (isqrt_u128), dec64, dec128 and every type's nth_root
+#[cfg(any(feature = "dec8", feature = "dec32", feature = "dec64", feature = "dec128"))]
 pub(crate) mod wide;
 
+// Binary fixed-point engine for exp/ln/log and trig
+#[cfg(any(feature = "dec32", feature = "dec64", feature = "dec128"))]
+pub(crate) mod fixed;
+
 // ============ AncDec8 (u8) ============
 #[cfg(feature = "dec8")]
 mod ancdec8;
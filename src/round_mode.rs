@@ -1,18 +1,236 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::str::FromStr;
+
 /// Rounding modes for decimal operations.
-#[derive(Debug, Clone, Copy, PartialEq)]
+///
+/// `Display` writes the variant name (`HalfEven`) and `FromStr` reads it back
+/// case-insensitively, ignoring `_`/`-` and a leading `ROUND_`, so the Python `decimal`
+/// (`ROUND_HALF_EVEN`, `ROUND_05UP`) and Java `RoundingMode` (`HALF_EVEN`, `UP`, `DOWN`)
+/// names parse as well. With the `serde` feature the mode (de)serializes as that string.
+///
+/// Non-exhaustive: new rounding modes may be added in minor releases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum RoundMode {
     /// Round toward negative infinity.
     Floor,
     /// Round toward positive infinity.
     Ceil,
-    /// Round toward zero (truncate).
+    /// Round toward zero (truncate). Also parsed from `Down`.
     Truncate,
+    /// Round away from zero: any discarded nonzero digit rounds up.
+    Up,
     /// Round half away from zero (>= 0.5 rounds up).
     HalfUp,
     /// Round half toward zero (> 0.5 rounds up).
     HalfDown,
     /// Banker's rounding: half rounds to nearest even.
     HalfEven,
+    /// Half rounds to nearest odd.
+    HalfOdd,
+    /// Round away from zero if the last kept digit is 0 or 5, otherwise toward zero
+    /// (`ROUND_05UP`). Keeps re-rounding at a coarser scale free of double-rounding errors.
+    ZeroFiveUp,
     /// Return the fractional part only.
     Fract,
 }
+
+/// Where a discarded remainder falls relative to half a unit of the last kept digit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Residue {
+    Zero,
+    BelowHalf,
+    Half,
+    AboveHalf,
+}
+
+impl Residue {
+    /// Classifies remainder `r` of a division by `d` (`r < d`).
+    /// Compares `r` against `d - r`, so no doubling overflow is possible.
+    #[inline(always)]
+    pub(crate) fn new(r: u128, d: u128) -> Self {
+        if r == 0 {
+            return Self::Zero;
+        }
+        Self::from_cmp(r.cmp(&(d - r)))
+    }
+
+    /// `new` for u256 (hi, lo) remainders and divisors.
+    #[cfg(any(feature = "dec64", feature = "dec128"))]
+    #[inline(always)]
+    pub(crate) fn new_wide(r: (u128, u128), d: (u128, u128)) -> Self {
+        if r == (0, 0) {
+            return Self::Zero;
+        }
+        let (lo, borrow) = d.1.overflowing_sub(r.1);
+        let hi = d.0.wrapping_sub(r.0).wrapping_sub(borrow as u128);
+        Self::from_cmp(r.cmp(&(hi, lo)))
+    }
+
+    /// Folds in whether an earlier step (a division, or an integer square root) left a
+    /// remainder. Chaining `n / c / k` gives the exact quotient; the exact residue then lies
+    /// strictly above the last step's one, which keeps the classification exact when `k` is
+    /// even (a power of 10).
+    #[inline(always)]
+    pub(crate) fn with_sticky(self, sticky: bool) -> Self {
+        match self {
+            Self::Zero if sticky => Self::BelowHalf,
+            Self::Half if sticky => Self::AboveHalf,
+            r => r,
+        }
+    }
+
+    #[inline(always)]
+    fn from_cmp(r_vs_rest: Ordering) -> Self {
+        match r_vs_rest {
+            Ordering::Less => Self::BelowHalf,
+            Ordering::Equal => Self::Half,
+            Ordering::Greater => Self::AboveHalf,
+        }
+    }
+}
+
+impl RoundMode {
+    /// Whether a truncated magnitude must be bumped by one unit in the last kept digit.
+    ///
+    /// `neg` is the sign of the exact value, `last_digit` the last kept decimal digit of
+    /// the truncated magnitude. `Fract` never rounds (it is handled by `round` itself).
+    #[inline(always)]
+    pub(crate) fn rounds_up(self, residue: Residue, neg: bool, last_digit: u8) -> bool {
+        if residue == Residue::Zero {
+            return false;
+        }
+        match self {
+            Self::Floor => neg,
+            Self::Ceil => !neg,
+            Self::Truncate | Self::Fract => false,
+            Self::Up => true,
+            Self::HalfUp => residue >= Residue::Half,
+            Self::HalfDown => residue == Residue::AboveHalf,
+            Self::HalfEven => {
+                residue == Residue::AboveHalf || (residue == Residue::Half && last_digit % 2 == 1)
+            }
+            Self::HalfOdd => {
+                residue == Residue::AboveHalf || (residue == Residue::Half && last_digit % 2 == 0)
+            }
+            Self::ZeroFiveUp => last_digit == 0 || last_digit == 5,
+        }
+    }
+
+    // indexed by discriminant (`Display`)
+    const NAMES: [(&'static str, RoundMode); 10] = [
+        ("Floor", Self::Floor),
+        ("Ceil", Self::Ceil),
+        ("Truncate", Self::Truncate),
+        ("Up", Self::Up),
+        ("HalfUp", Self::HalfUp),
+        ("HalfDown", Self::HalfDown),
+        ("HalfEven", Self::HalfEven),
+        ("HalfOdd", Self::HalfOdd),
+        ("ZeroFiveUp", Self::ZeroFiveUp),
+        ("Fract", Self::Fract),
+    ];
+
+    // accepted aliases (after normalization): Python/Java spellings
+    const ALIASES: [(&'static str, RoundMode); 3] = [
+        ("ceiling", Self::Ceil),
+        ("down", Self::Truncate),
+        ("05up", Self::ZeroFiveUp),
+    ];
+}
+
+impl fmt::Display for RoundMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (name, _) = Self::NAMES[*self as usize];
+        f.write_str(name)
+    }
+}
+
+/// Error returned when a string does not name a [`RoundMode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseRoundModeError;
+
+impl fmt::Display for ParseRoundModeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("unknown rounding mode")
+    }
+}
+
+impl FromStr for RoundMode {
+    type Err = ParseRoundModeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // lowercase without separators: "ROUND_HALF_EVEN" -> "roundhalfeven"
+        let mut buf = [0u8; 16];
+        let mut len = 0;
+        for &c in s.as_bytes() {
+            if c == b'_' || c == b'-' {
+                continue;
+            }
+            *buf.get_mut(len).ok_or(ParseRoundModeError)? = c.to_ascii_lowercase();
+            len += 1;
+        }
+        let name = &buf[..len];
+        let name = name.strip_prefix(b"round").unwrap_or(name);
+
+        Self::NAMES
+            .iter()
+            .find(|(n, _)| n.as_bytes().eq_ignore_ascii_case(name))
+            .or_else(|| Self::ALIASES.iter().find(|(n, _)| n.as_bytes() == name))
+            .map(|&(_, mode)| mode)
+            .ok_or(ParseRoundModeError)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for RoundMode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for RoundMode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct V;
+        impl<'de> serde::de::Visitor<'de> for V {
+            type Value = RoundMode;
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("rounding mode name")
+            }
+            fn visit_str<E: serde::de::Error>(self, s: &str) -> Result<Self::Value, E> {
+                s.parse().map_err(|e| E::custom(e))
+            }
+        }
+        deserializer.deserialize_str(V)
+    }
+}
+
+/// Stochastic rounding: whether to round a remainder `r` of a division by `d` (`r < d`) up,
+/// with probability exactly `r / d`. Draws a uniform value below `d` by rejection, so
+/// there is no modulo bias.
+#[cfg(feature = "rand")]
+#[cfg(any(feature = "dec8", feature = "dec32", feature = "dec64", feature = "dec128"))]
+pub(crate) fn stochastic_rounds_up(r: u128, d: u128, rng: &mut impl rand_core::RngCore) -> bool {
+    if r == 0 {
+        return false;
+    }
+    // largest multiple of d that fits, minus one: draws above it are rejected
+    if let Ok(d) = u64::try_from(d) {
+        let zone = u64::MAX - (u64::MAX - d + 1) % d;
+        loop {
+            let v = rng.next_u64();
+            if v <= zone {
+                return ((v % d) as u128) < r;
+            }
+        }
+    }
+    let zone = u128::MAX - (u128::MAX - d + 1) % d;
+    loop {
+        let v = ((rng.next_u64() as u128) << 64) | rng.next_u64() as u128;
+        if v <= zone {
+            return v % d < r;
+        }
+    }
+}
@@ -20,6 +20,27 @@ pub(crate) const SCALE38: u128 = 100_000_000_000_000_000_000_000_000_000_000_000
 #[cfg(feature = "dec128")]
 pub(crate) const TARGET_SCALE_128: u8 = 38; // max fractional digits for AncDec128
 
+/// Applies a sign to a u128 magnitude. `None` if the result is outside `i128` range.
+#[cfg(any(feature = "dec8", feature = "dec32", feature = "dec64", feature = "dec128"))]
+#[inline(always)]
+pub(crate) fn signed_i128(mag: u128, neg: bool) -> Option<i128> {
+    if neg {
+        0i128.checked_sub_unsigned(mag)
+    } else {
+        i128::try_from(mag).ok()
+    }
+}
+
+/// `signed_i128` for a u256 (hi, lo) magnitude
+#[cfg(feature = "dec128")]
+#[inline(always)]
+pub(crate) fn signed_i128_wide(mag: (u128, u128), neg: bool) -> Option<i128> {
+    if mag.0 != 0 {
+        return None;
+    }
+    signed_i128(mag.1, neg)
+}
+
 /// Heap-free buffer for Display -> &str. Safety: only write_str can write, which guarantees UTF-8.
 pub(crate) struct StackBuf<const N: usize> {
     buf: [u8; N],
@@ -38,8 +59,9 @@ impl<const N: usize> StackBuf<N> {
     #[inline(always)]
     pub(crate) fn as_str(&self) -> &str {
         // SAFETY: write_str only accepts &str (valid UTF-8), so buf[..pos] is always valid
-        debug_assert!(core::str::from_utf8(&self.buf[..self.pos]).is_ok());
-        unsafe { core::str::from_utf8_unchecked(&self.buf[..self.pos]) }
+        let bytes = self.buf.get(..self.pos).unwrap_or_default();
+        debug_assert!(core::str::from_utf8(bytes).is_ok());
+        unsafe { core::str::from_utf8_unchecked(bytes) }
     }
 }
 
@@ -48,7 +70,7 @@ impl<const N: usize> Write for StackBuf<N> {
     #[inline(always)]
     fn write_str(&mut self, s: &str) -> fmt::Result {
         let bytes = s.as_bytes();
-        let remaining = N - self.pos;
+        let remaining = N.saturating_sub(self.pos);
 
         // truncate at UTF-8 boundary if buffer full
         let len = if bytes.len() <= remaining {
@@ -61,13 +83,17 @@ impl<const N: usize> Write for StackBuf<N> {
             i
         };
 
-        self.buf[self.pos..self.pos + len].copy_from_slice(&bytes[..len]);
+        match (self.buf.get_mut(self.pos..self.pos + len), bytes.get(..len)) {
+            (Some(dst), Some(src)) => dst.copy_from_slice(src),
+            _ => return Err(fmt::Error),
+        }
         self.pos += len;
         Ok(())
     }
 }
 
 /// Power of 10 lookup for u8 (0-2)
+/// Panics past the table (saturates at the largest entry with `panic-free`)
 #[cfg(feature = "dec8")]
 #[inline(always)]
 pub(crate) const fn pow10_u8(exp: u8) -> u8 {
@@ -75,11 +101,15 @@ pub(crate) const fn pow10_u8(exp: u8) -> u8 {
         0 => 1,
         1 => 10,
         2 => 100,
+        #[cfg(not(feature = "panic-free"))]
         _ => panic!("scale overflow"),
+        #[cfg(feature = "panic-free")]
+        _ => 100,
     }
 }
 
 /// Power of 10 lookup for u16 (0-4)
+/// Panics past the table (saturates at the largest entry with `panic-free`)
 #[cfg(feature = "dec8")]
 #[inline(always)]
 pub(crate) const fn pow10_16(exp: u8) -> u16 {
@@ -89,11 +119,15 @@ pub(crate) const fn pow10_16(exp: u8) -> u16 {
         2 => 100,
         3 => 1_000,
         4 => 10_000,
+        #[cfg(not(feature = "panic-free"))]
         _ => panic!("scale overflow"),
+        #[cfg(feature = "panic-free")]
+        _ => 10_000,
     }
 }
 
 /// Power of 10 lookup for u32 (0-9)
+/// Panics past the table (saturates at the largest entry with `panic-free`)
 #[cfg(any(feature = "dec8", feature = "dec32"))]
 #[inline(always)]
 pub(crate) const fn pow10_32(exp: u8) -> u32 {
@@ -108,11 +142,15 @@ pub(crate) const fn pow10_32(exp: u8) -> u32 {
         7 => 10_000_000,
         8 => 100_000_000,
         9 => 1_000_000_000,
+        #[cfg(not(feature = "panic-free"))]
         _ => panic!("scale overflow"),
+        #[cfg(feature = "panic-free")]
+        _ => 1_000_000_000,
     }
 }
 
 /// Power of 10 lookup for u64 (0-19)
+/// Panics past the table (saturates at the largest entry with `panic-free`)
 #[cfg(any(feature = "dec32", feature = "dec64", feature = "dec128"))]
 #[inline(always)]
 pub(crate) const fn pow10(exp: u8) -> u64 {
@@ -137,11 +175,16 @@ pub(crate) const fn pow10(exp: u8) -> u64 {
         17 => 100_000_000_000_000_000,
         18 => 1_000_000_000_000_000_000,
         19 => 10_000_000_000_000_000_000,
+        #[cfg(not(feature = "panic-free"))]
         _ => panic!("scale overflow"),
+        // a hand-built `AncDec` with `scale > 19` gets here (see `AncDec::scale`)
+        #[cfg(feature = "panic-free")]
+        _ => 10_000_000_000_000_000_000,
     }
 }
 
 /// Power of 10 lookup for u128 (0-38)
+/// Panics past the table (saturates at the largest entry with `panic-free`)
 #[cfg(any(feature = "dec32", feature = "dec64", feature = "dec128"))]
 #[inline(always)]
 pub(crate) const fn pow10_128(exp: u8) -> u128 {
@@ -185,8 +228,45 @@ pub(crate) const fn pow10_128(exp: u8) -> u128 {
         36 => 1_000_000_000_000_000_000_000_000_000_000_000_000,
         37 => 10_000_000_000_000_000_000_000_000_000_000_000_000,
         38 => 100_000_000_000_000_000_000_000_000_000_000_000_000,
+        #[cfg(not(feature = "panic-free"))]
         _ => panic!("scale overflow"),
+        // a hand-built `AncDec` with `scale > 19` gets here (see `AncDec::scale`)
+        #[cfg(feature = "panic-free")]
+        _ => 100_000_000_000_000_000_000_000_000_000_000_000_000,
+    }
+}
+
+/// Number of decimal digits in a u8 (0 for zero), counted against `pow10_u8`
+#[cfg(feature = "dec8")]
+#[inline(always)]
+pub(crate) fn digits_u8(n: u8) -> u8 {
+    let mut d = 0;
+    while d <= 2 && n >= pow10_u8(d) {
+        d += 1;
+    }
+    d
+}
+
+/// Number of decimal digits in a u64 (0 for zero), counted against `pow10`
+#[cfg(any(feature = "dec32", feature = "dec64"))]
+#[inline(always)]
+pub(crate) fn digits_64(n: u64) -> u8 {
+    let mut d = 0;
+    while d <= 19 && n >= pow10(d) {
+        d += 1;
+    }
+    d
+}
+
+/// Number of decimal digits in a u128 (0 for zero), counted against `pow10_128`
+#[cfg(feature = "dec128")]
+#[inline(always)]
+pub(crate) fn digits_128(n: u128) -> u8 {
+    let mut d = 0;
+    while d <= 38 && n >= pow10_128(d) {
+        d += 1;
     }
+    d
 }
 
 /// Power of 10 as u256 (0-76), returns (high_u128, low_u128)
@@ -1,9 +1,26 @@
 // Wide arithmetic helpers (internal use only)
+//
+// Preconditions (non-zero divisor, quotient fits) are established by the callers, which
+// return `None`/`Err` first. Violations panic by default; with `panic-free` the checks are
+// debug asserts or saturating early returns, so release builds carry no panic path.
+
+use core::cmp::Ordering;
+
+/// Internal invariant: `assert!` by default, `debug_assert!` with `panic-free`
+#[cfg(any(feature = "dec64", feature = "dec128"))]
+macro_rules! invariant {
+    ($($arg:tt)*) => {
+        #[cfg(not(feature = "panic-free"))]
+        assert!($($arg)*);
+        #[cfg(feature = "panic-free")]
+        debug_assert!($($arg)*);
+    };
+}
 
 // ============ u256 Arithmetic (dec64 + dec128) ============
 
 /// u128 * u128 -> (high, low)
-#[cfg(any(feature = "dec64", feature = "dec128"))]
+/// Also used by every type's approximate_fraction
 #[inline]
 pub(crate) fn mul_wide(a: u128, b: u128) -> (u128, u128) {
     let a_lo = a as u64 as u128;
@@ -29,7 +46,7 @@ pub(crate) fn mul_wide(a: u128, b: u128) -> (u128, u128) {
 #[cfg(any(feature = "dec64", feature = "dec128"))]
 #[inline]
 fn shl_u256(high: u128, low: u128, shift: u32) -> (u64, u64, u64, u64) {
-    assert!(shift < 128, "shl_u256: shift must be < 128");
+    invariant!(shift < 128, "shl_u256: shift must be < 128");
     if shift == 0 {
         return (
             (high >> 64) as u64,
@@ -71,7 +88,7 @@ fn div_wide_by_u64(high: u128, low: u128, divisor: u64) -> u128 {
     let (q1, r1) = div_u128_by_u64((r2 << 64) | (n1 as u128), divisor);
     let (q0, _) = div_u128_by_u64((r1 << 64) | (n0 as u128), divisor);
 
-    assert!(q3 == 0 && q2 == 0);
+    invariant!(q3 == 0 && q2 == 0);
     (q1 << 64) | q0
 }
 
@@ -142,13 +159,18 @@ fn div_4by2(n3: u64, n2: u64, n1: u64, n0: u64, d1: u64, d0: u64) -> (u64, u64)
 #[cfg(any(feature = "dec64", feature = "dec128"))]
 #[inline]
 pub(crate) fn div_wide(high: u128, low: u128, divisor: u128) -> u128 {
+    #[cfg(not(feature = "panic-free"))]
     assert!(divisor != 0, "division by zero");
+    #[cfg(feature = "panic-free")]
+    if divisor == 0 {
+        return u128::MAX; // unreachable: callers reject zero divisors
+    }
 
     if high == 0 {
         return low / divisor;
     }
 
-    assert!(high < divisor, "quotient overflow");
+    invariant!(high < divisor, "quotient overflow");
 
     let d_hi = (divisor >> 64) as u64;
 
@@ -167,6 +189,47 @@ pub(crate) fn div_wide(high: u128, low: u128, divisor: u128) -> u128 {
     ((q1 as u128) << 64) | (q0 as u128)
 }
 
+/// u256 divmod u128 -> (quotient_u256, remainder_u128)
+/// Used by from_combined to split u256 by pow10_128(scale), and by mul_div
+#[cfg(any(feature = "dec64", feature = "dec128"))]
+#[inline]
+pub(crate) fn divmod_u256(
+    hi: u128,
+    lo: u128,
+    divisor: u128,
+) -> ((u128, u128), u128) {
+    #[cfg(not(feature = "panic-free"))]
+    assert!(divisor != 0, "division by zero");
+    #[cfg(feature = "panic-free")]
+    if divisor == 0 {
+        return ((u128::MAX, u128::MAX), 0); // unreachable: callers reject zero divisors
+    }
+
+    if hi == 0 {
+        return ((0, lo / divisor), lo % divisor);
+    }
+
+    // hi < divisor: quotient fits in u128
+    if hi < divisor {
+        let q = div_wide(hi, lo, divisor);
+        let (ph, pl) = mul_wide(q, divisor);
+        let (rem_lo, borrow) = lo.overflowing_sub(pl);
+        let rem_hi = hi.wrapping_sub(ph).wrapping_sub(borrow as u128);
+        invariant!(rem_hi == 0);
+        return ((0, q), rem_lo);
+    }
+
+    // hi >= divisor: quotient is u256
+    let q_hi = hi / divisor;
+    let r_hi = hi % divisor;
+    let q_lo = div_wide(r_hi, lo, divisor);
+    let (ph, pl) = mul_wide(q_lo, divisor);
+    let (rem_lo, borrow) = lo.overflowing_sub(pl);
+    let rem_hi = r_hi.wrapping_sub(ph).wrapping_sub(borrow as u128);
+    invariant!(rem_hi == 0);
+    ((q_hi, q_lo), rem_lo)
+}
+
 // ============ u256/u512 Arithmetic for AncDec128 ============
 
 /// u256 * u256 -> u512 (represented as 4 u128 limbs: w3..w0, highest first)
@@ -213,7 +276,12 @@ pub(crate) fn div_u512_by_u128(
     w0: u128,
     divisor: u128,
 ) -> (u128, u128) {
+    #[cfg(not(feature = "panic-free"))]
     assert!(divisor != 0, "division by zero");
+    #[cfg(feature = "panic-free")]
+    if divisor == 0 {
+        return (u128::MAX, u128::MAX); // unreachable: callers reject zero divisors
+    }
 
     // Long division: process two u128 digits at a time using div_wide
     // Each step: (remainder, next_digit) / divisor -> (quotient_digit, remainder)
@@ -228,7 +296,7 @@ pub(crate) fn div_u512_by_u128(
         // (r3, w2) - q2 * divisor
         let (sub_lo, borrow) = w2.overflowing_sub(pl);
         let sub_hi = r3.wrapping_sub(ph).wrapping_sub(borrow as u128);
-        assert!(sub_hi == 0);
+        invariant!(sub_hi == 0);
         sub_lo
     };
     let q1 = div_wide(r2_full, w1, divisor);
@@ -236,7 +304,7 @@ pub(crate) fn div_u512_by_u128(
         let (ph, pl) = mul_wide(q1, divisor);
         let (sub_lo, borrow) = w1.overflowing_sub(pl);
         let sub_hi = r2_full.wrapping_sub(ph).wrapping_sub(borrow as u128);
-        assert!(sub_hi == 0);
+        invariant!(sub_hi == 0);
         sub_lo
     };
     let q0 = div_wide(r1_full, w0, divisor);
@@ -247,40 +315,11 @@ pub(crate) fn div_u512_by_u128(
     (q1, q0)
 }
 
-/// u256 divmod u128 -> (quotient_u256, remainder_u128)
-/// Used by from_combined to split u256 by pow10_128(scale)
+/// Last decimal digit of a u256 (hi, lo): 2^128 ≡ 6 (mod 10)
 #[cfg(feature = "dec128")]
 #[inline]
-pub(crate) fn divmod_u256(
-    hi: u128,
-    lo: u128,
-    divisor: u128,
-) -> ((u128, u128), u128) {
-    assert!(divisor != 0, "division by zero");
-
-    if hi == 0 {
-        return ((0, lo / divisor), lo % divisor);
-    }
-
-    // hi < divisor: quotient fits in u128
-    if hi < divisor {
-        let q = div_wide(hi, lo, divisor);
-        let (ph, pl) = mul_wide(q, divisor);
-        let (rem_lo, borrow) = lo.overflowing_sub(pl);
-        let rem_hi = hi.wrapping_sub(ph).wrapping_sub(borrow as u128);
-        assert!(rem_hi == 0);
-        return ((0, q), rem_lo);
-    }
-
-    // hi >= divisor: quotient is u256
-    let q_hi = hi / divisor;
-    let r_hi = hi % divisor;
-    let q_lo = div_wide(r_hi, lo, divisor);
-    let (ph, pl) = mul_wide(q_lo, divisor);
-    let (rem_lo, borrow) = lo.overflowing_sub(pl);
-    let rem_hi = r_hi.wrapping_sub(ph).wrapping_sub(borrow as u128);
-    assert!(rem_hi == 0);
-    ((q_hi, q_lo), rem_lo)
+pub(crate) fn mod10_u256(n: (u128, u128)) -> u8 {
+    (((n.0 % 10) * 6 + n.1 % 10) % 10) as u8
 }
 
 /// Shift u512 left by `shift` bits (shift < 128)
@@ -294,7 +333,7 @@ fn shl_u512(
     w0: u128,
     shift: u32,
 ) -> (u128, u128, u128, u128) {
-    assert!(shift < 128, "shl_u512: shift must be < 128");
+    invariant!(shift < 128, "shl_u512: shift must be < 128");
     if shift == 0 {
         return (w3, w2, w1, w0);
     }
@@ -378,7 +417,12 @@ pub(crate) fn div_u512_by_u256(
     d_hi: u128,
     d_lo: u128,
 ) -> (u128, u128) {
+    #[cfg(not(feature = "panic-free"))]
     assert!(d_hi != 0 || d_lo != 0, "division by zero");
+    #[cfg(feature = "panic-free")]
+    if d_hi == 0 && d_lo == 0 {
+        return (u128::MAX, u128::MAX); // unreachable: callers reject zero divisors
+    }
 
     // If divisor fits in u128 (d_hi == 0), use simpler path
     if d_hi == 0 {
@@ -403,10 +447,39 @@ pub(crate) fn div_u512_by_u256(
     (q1, q0)
 }
 
+/// u512 divmod u256 -> (quotient_u512, remainder_u256)
+/// Long division by u256 halves, each step keeping the running remainder below the divisor
+#[cfg(feature = "dec128")]
+#[inline]
+pub(crate) fn divmod_u512(
+    n: (u128, u128, u128, u128),
+    d: (u128, u128),
+) -> ((u128, u128, u128, u128), (u128, u128)) {
+    let (q_hi, r_hi) = divmod_u512_step((0, 0, n.0, n.1), d);
+    let (q_lo, r) = divmod_u512_step((r_hi.0, r_hi.1, n.2, n.3), d);
+    ((q_hi.0, q_hi.1, q_lo.0, q_lo.1), r)
+}
+
+/// One divmod_u512 step: requires the top half of `n` below `d`, so the quotient fits in u256
+#[cfg(feature = "dec128")]
+#[inline]
+fn divmod_u512_step(
+    n: (u128, u128, u128, u128),
+    d: (u128, u128),
+) -> ((u128, u128), (u128, u128)) {
+    let q = div_u512_by_u256(n.0, n.1, n.2, n.3, d.0, d.1);
+    // remainder < d fits in u256: the low 256 bits of n - q * d
+    let (_, _, p1, p0) = mul_u256(q, d);
+    let (r_lo, borrow) = n.3.overflowing_sub(p0);
+    let r_hi = n.2.wrapping_sub(p1).wrapping_sub(borrow as u128);
+    (q, (r_hi, r_lo))
+}
+
 // ============ Integer Square Root ============
 
 /// Integer square root of u128
 /// Returns floor(sqrt(n))
+#[cfg(any(feature = "dec32", feature = "dec64", feature = "dec128"))]
 #[inline]
 pub(crate) fn isqrt_u128(n: u128) -> u128 {
     if n <= 1 {
@@ -417,7 +490,8 @@ pub(crate) fn isqrt_u128(n: u128) -> u128 {
     let mut x = 1u128 << bits.div_ceil(2);
 
     loop {
-        let q = n / x;
+        // x stays >= isqrt(n) >= 1; max(1) only lets the compiler drop the zero check
+        let q = n / x.max(1);
         let x_new = (x >> 1) + (q >> 1) + (x & q & 1);
         if x_new >= x {
             break;
@@ -521,3 +595,454 @@ pub(crate) fn isqrt_u512(w3: u128, w2: u128, w1: u128, w0: u128) -> (u128, u128)
 
     x
 }
+
+// ============ Limb Arithmetic (all types) ============
+// Little-endian u64 limb slices, shared with the fixed-point engine
+
+/// Compares two limb numbers of any lengths
+#[inline]
+pub(crate) fn cmp_limbs(a: &[u64], b: &[u64]) -> Ordering {
+    for i in (0..a.len().max(b.len())).rev() {
+        let x = a.get(i).copied().unwrap_or(0);
+        let y = b.get(i).copied().unwrap_or(0);
+        if x != y {
+            return x.cmp(&y);
+        }
+    }
+    Ordering::Equal
+}
+
+/// Number of significant bits (0 for zero)
+#[inline]
+pub(crate) fn bit_len(a: &[u64]) -> u32 {
+    for (i, x) in a.iter().enumerate().rev() {
+        if *x != 0 {
+            return i as u32 * 64 + 64 - x.leading_zeros();
+        }
+    }
+    0
+}
+
+/// Divides `a` in place by `d` (nonzero), returning the remainder
+#[inline]
+pub(crate) fn div_small(a: &mut [u64], d: u64) -> u64 {
+    // max(1) only lets the compiler drop the zero check
+    let d = (d as u128).max(1);
+    let mut rem = 0u128;
+    for x in a.iter_mut().rev() {
+        let cur = (rem << 64) | *x as u128;
+        *x = (cur / d) as u64;
+        rem = cur % d;
+    }
+    rem as u64
+}
+
+/// `a *= m` in place, returning the carry out
+#[inline]
+fn mul_small(a: &mut [u64], m: u64) -> u64 {
+    let mut carry = 0u128;
+    for x in a.iter_mut() {
+        let t = (*x as u128) * (m as u128) + carry;
+        *x = t as u64;
+        carry = t >> 64;
+    }
+    carry as u64
+}
+
+// ============ k-th Roots (all types) ============
+// Exact integer k-th roots over a 4096-bit limb number: `value * 10^(k * scale)` fits it for
+// every type at its full root scale up to k = 31 (AncDec128), 66 (AncDec) or beyond.
+
+/// Limbs of the k-th root working width (4096 bits)
+const ROOT_LIMBS: usize = 64;
+
+/// Decimal digits of `value * 10^(k * scale)` allowed: 1233 always fit 4096 bits, less the
+/// 78 a u256 combined value may take
+const ROOT_DIGITS: u32 = 1233 - 78;
+
+type Big = [u64; ROOT_LIMBS];
+
+/// `a * b`, or `None` if the product does not fit the working width
+fn big_mul(a: &Big, b: &Big) -> Option<Big> {
+    let (la, lb) = (bit_len(a).div_ceil(64) as usize, bit_len(b).div_ceil(64) as usize);
+    let mut wide = [0u64; 2 * ROOT_LIMBS];
+    for (i, &x) in a.iter().take(la).enumerate() {
+        let mut carry = 0u128;
+        for (j, &y) in b.iter().take(lb).enumerate() {
+            if let Some(o) = wide.get_mut(i + j) {
+                let t = (x as u128) * (y as u128) + *o as u128 + carry;
+                *o = t as u64;
+                carry = t >> 64;
+            }
+        }
+        if let Some(o) = wide.get_mut(i + lb) {
+            *o = carry as u64;
+        }
+    }
+    if wide[ROOT_LIMBS..].iter().any(|x| *x != 0) {
+        return None;
+    }
+    let mut out = [0u64; ROOT_LIMBS];
+    out.copy_from_slice(&wide[..ROOT_LIMBS]);
+    Some(out)
+}
+
+/// Whether `base^k <= limit` for `base >= 1`, stopping as soon as a partial power exceeds it
+fn pow_le(base: &Big, k: u32, limit: &Big) -> bool {
+    let mut result = [0u64; ROOT_LIMBS];
+    result[0] = 1;
+    let mut b = *base;
+    let mut e = k;
+    loop {
+        if e % 2 == 1 {
+            match big_mul(&result, &b) {
+                Some(r) if cmp_limbs(&r, limit) != Ordering::Greater => result = r,
+                _ => return false,
+            }
+        }
+        e /= 2;
+        if e == 0 {
+            return true;
+        }
+        // a remaining bit multiplies in at least b^2
+        match big_mul(&b, &b) {
+            Some(sq) if cmp_limbs(&sq, limit) != Ordering::Greater => b = sq,
+            _ => return false,
+        }
+    }
+}
+
+/// Integer k-th root `floor(n^(1/k))`, built bit by bit from the top (k >= 1)
+fn iroot(n: &Big, k: u32) -> Big {
+    let mut r = [0u64; ROOT_LIMBS];
+    let top = bit_len(n) / k.max(1);
+    for i in (0..=top).rev() {
+        let (idx, bit) = ((i / 64) as usize, 1u64 << (i % 64));
+        let mut cand = r;
+        if let Some(limb) = cand.get_mut(idx) {
+            *limb |= bit;
+        }
+        if pow_le(&cand, k, n) {
+            r = cand;
+        }
+    }
+    r
+}
+
+/// k-th root of the magnitude `c / 10^scale` truncated to `max_scale` places (k >= 1): the
+/// combined root and its scale. The scale drops below `max_scale` only when
+/// `value * 10^(k * max_scale)` would exceed the working width.
+pub(crate) fn nth_root_decimal(c: (u128, u128), scale: u8, k: u32, max_scale: u8) -> ((u128, u128), u8) {
+    let out_scale = ((ROOT_DIGITS + scale as u32) / k.max(1)).min(max_scale as u32);
+    let mut n = [0u64; ROOT_LIMBS];
+    n[..4].copy_from_slice(&[c.1 as u64, (c.1 >> 64) as u64, c.0 as u64, (c.0 >> 64) as u64]);
+    // n = c * 10^(k * out_scale - scale), a floor division when the exponent is negative
+    let (up, down) = (k * out_scale, scale as u32);
+    for _ in down..up {
+        mul_small(&mut n, 10);
+    }
+    for _ in up..down {
+        div_small(&mut n, 10);
+    }
+    let r = iroot(&n, k);
+    let hi = (r[3] as u128) << 64 | r[2] as u128;
+    let lo = (r[1] as u128) << 64 | r[0] as u128;
+    ((hi, lo), out_scale as u8)
+}
+
+// ============ Integer Powers (dec64 + dec128) ============
+// Exact `(c / 10^scale)^n` over the same 4096-bit width, rounded once at the end
+
+/// `base^k`, or `None` if a partial power leaves the working width (base >= 1)
+#[cfg(any(feature = "dec64", feature = "dec128"))]
+fn big_pow(base: &Big, mut k: u64) -> Option<Big> {
+    let mut result = [0u64; ROOT_LIMBS];
+    result[0] = 1;
+    let mut b = *base;
+    loop {
+        if k % 2 == 1 {
+            result = big_mul(&result, &b)?;
+        }
+        k /= 2;
+        if k == 0 {
+            return Some(result);
+        }
+        // a remaining bit multiplies in at least b^2
+        b = big_mul(&b, &b)?;
+    }
+}
+
+/// `a *= 10^k`, or `None` if the product leaves the working width
+#[cfg(any(feature = "dec64", feature = "dec128"))]
+fn big_mul_pow10(a: &mut Big, mut k: u64) -> Option<()> {
+    while k > 0 {
+        let step = k.min(19);
+        if mul_small(a, 10u64.pow(step as u32)) != 0 {
+            return None;
+        }
+        k -= step;
+    }
+    Some(())
+}
+
+/// `a += 1` when `round_up` (the caller knows it cannot overflow)
+#[cfg(any(feature = "dec64", feature = "dec128"))]
+#[inline]
+fn big_round_up(a: &mut Big, round_up: bool) {
+    if round_up {
+        for x in a.iter_mut() {
+            let (s, carry) = x.overflowing_add(1);
+            *x = s;
+            if !carry {
+                break;
+            }
+        }
+    }
+}
+
+/// `a / 10^k` rounded half-even (k >= 1)
+#[cfg(any(feature = "dec64", feature = "dec128"))]
+fn big_div_pow10_round(a: &Big, k: u64) -> Big {
+    // a < 2^4096 < 10^1234, so past that the quotient is below 1/10
+    if k > 1234 {
+        return [0u64; ROOT_LIMBS];
+    }
+    let mut q = *a;
+    let (mut rest, mut sticky) = (k - 1, false);
+    while rest > 0 {
+        let step = rest.min(19);
+        sticky |= div_small(&mut q, 10u64.pow(step as u32)) != 0;
+        rest -= step;
+    }
+    let digit = div_small(&mut q, 10);
+    let odd = q[0] & 1 == 1;
+    big_round_up(&mut q, digit > 5 || (digit == 5 && (sticky || odd)));
+    q
+}
+
+/// `num / den` rounded half-even (`den` nonzero), by shift-and-subtract
+#[cfg(any(feature = "dec64", feature = "dec128"))]
+fn big_div_round(num: &Big, den: &Big) -> Option<Big> {
+    let mut q = [0u64; ROOT_LIMBS];
+    let mut buf = [0u64; ROOT_LIMBS];
+    // rem < den, so 2 * rem + 1 needs at most one limb more than den; a bit shifted out of
+    // the full width still means rem >= den, and the wrapping subtraction stays exact
+    let rem = buf.get_mut(..(bit_len(den) as usize / 64 + 2).min(ROOT_LIMBS))?;
+    for i in (0..bit_len(num)).rev() {
+        let (idx, mask) = ((i / 64) as usize, 1u64 << (i % 64));
+        let top = shl1_limbs(rem, num.get(idx).is_some_and(|x| x & mask != 0));
+        if top || cmp_limbs(rem, den) != Ordering::Less {
+            sub_limbs_wrapping(rem, den);
+            if let Some(x) = q.get_mut(idx) {
+                *x |= mask;
+            }
+        }
+    }
+    // half-even: compare 2 * rem against den
+    let top = shl1_limbs(rem, false);
+    let odd = q[0] & 1 == 1;
+    let round_up = top
+        || match cmp_limbs(rem, den) {
+            Ordering::Greater => true,
+            Ordering::Equal => odd,
+            Ordering::Less => false,
+        };
+    big_round_up(&mut q, round_up);
+    Some(q)
+}
+
+/// `a = 2a + bit` over `a`'s length, returning the bit shifted out
+#[cfg(any(feature = "dec64", feature = "dec128"))]
+#[inline]
+fn shl1_limbs(a: &mut [u64], bit: bool) -> bool {
+    let mut carry = bit as u64;
+    for x in a.iter_mut() {
+        let out = *x >> 63;
+        *x = (*x << 1) | carry;
+        carry = out;
+    }
+    carry == 1
+}
+
+/// `a -= b` modulo `2^(64 * a.len())` (missing limbs of `b` read as zero)
+#[cfg(any(feature = "dec64", feature = "dec128"))]
+#[inline]
+fn sub_limbs_wrapping(a: &mut [u64], b: &[u64]) {
+    let mut borrow = false;
+    for (i, x) in a.iter_mut().enumerate() {
+        let (d, b1) = x.overflowing_sub(b.get(i).copied().unwrap_or(0));
+        let (d, b2) = d.overflowing_sub(borrow as u64);
+        *x = d;
+        borrow = b1 | b2;
+    }
+}
+
+/// `(c / 10^scale)^n`, or its reciprocal with `recip`, rounded half-even to `out_scale`
+/// places (c nonzero, n >= 1). `None` if an exact intermediate leaves the working width or
+/// the result does not fit u256.
+#[cfg(any(feature = "dec64", feature = "dec128"))]
+pub(crate) fn pow_decimal(
+    c: (u128, u128),
+    scale: u8,
+    n: u64,
+    recip: bool,
+    out_scale: u8,
+) -> Option<(u128, u128)> {
+    let mut base = [0u64; ROOT_LIMBS];
+    base[..4].copy_from_slice(&[c.1 as u64, (c.1 >> 64) as u64, c.0 as u64, (c.0 >> 64) as u64]);
+    let power = big_pow(&base, n)?;
+    // the power carries scale * n decimal places
+    let places = (scale as u64).checked_mul(n);
+    let r = match (recip, places) {
+        // 10^(places + out_scale) / c^n
+        (true, Some(places)) => {
+            let mut num = [0u64; ROOT_LIMBS];
+            num[0] = 1;
+            big_mul_pow10(&mut num, places.checked_add(out_scale as u64)?)?;
+            big_div_round(&num, &power)?
+        }
+        (true, None) => return None,
+        (false, Some(places)) if places <= out_scale as u64 => {
+            let mut r = power;
+            big_mul_pow10(&mut r, out_scale as u64 - places)?;
+            r
+        }
+        (false, places) => big_div_pow10_round(&power, places.map_or(u64::MAX, |p| p - out_scale as u64)),
+    };
+    if r[4..].iter().any(|x| *x != 0) {
+        return None;
+    }
+    Some(((r[3] as u128) << 64 | r[2] as u128, (r[1] as u128) << 64 | r[0] as u128))
+}
+
+// ============ GCD (all types) ============
+
+/// Binary GCD of two u128 values; `gcd(n, 0) = n`
+pub(crate) fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+    if a == 0 || b == 0 {
+        return a | b;
+    }
+    let shift = (a | b).trailing_zeros();
+    a >>= a.trailing_zeros();
+    loop {
+        b >>= b.trailing_zeros();
+        if a > b {
+            core::mem::swap(&mut a, &mut b);
+        }
+        b -= a;
+        if b == 0 {
+            return a << shift;
+        }
+    }
+}
+
+/// Binary GCD of two u256 values (hi, lo); `gcd(n, 0) = n`
+#[cfg(feature = "dec128")]
+pub(crate) fn gcd_u256(mut a: (u128, u128), mut b: (u128, u128)) -> (u128, u128) {
+    let trailing = |n: (u128, u128)| match n.1 {
+        0 => 128 + n.0.trailing_zeros(),
+        lo => lo.trailing_zeros(),
+    };
+    let shr = |n: (u128, u128), s: u32| match s {
+        0 => n,
+        s if s >= 128 => (0, n.0 >> (s - 128)),
+        s => (n.0 >> s, (n.1 >> s) | (n.0 << (128 - s))),
+    };
+    if a == (0, 0) || b == (0, 0) {
+        return (a.0 | b.0, a.1 | b.1);
+    }
+    let shift = trailing(a).min(trailing(b));
+    a = shr(a, trailing(a));
+    // the tuples compare as (hi, lo), i.e. numerically
+    while a.0 != 0 || b.0 != 0 {
+        b = shr(b, trailing(b));
+        if a > b {
+            core::mem::swap(&mut a, &mut b);
+        }
+        let (lo, borrow) = b.1.overflowing_sub(a.1);
+        b = (b.0.wrapping_sub(a.0).wrapping_sub(borrow as u128), lo);
+        if b == (0, 0) {
+            return shl_u256_pair(a, shift);
+        }
+    }
+    shl_u256_pair((0, gcd_u128(a.1, b.1)), shift)
+}
+
+/// `n << shift` for a u256 (hi, lo) known not to overflow
+#[cfg(feature = "dec128")]
+#[inline]
+fn shl_u256_pair(n: (u128, u128), shift: u32) -> (u128, u128) {
+    match shift {
+        0 => n,
+        s if s >= 128 => (n.1 << (s - 128), 0),
+        s => ((n.0 << s) | (n.1 >> (128 - s)), n.1 << s),
+    }
+}
+
+// ============ Fractions (all types) ============
+
+/// `int + frac / den` (`den` a power of ten) in lowest terms, as a (numerator, denominator)
+/// magnitude. `None` if the numerator overflows u128.
+pub(crate) fn exact_fraction(int: u128, frac: u128, den: u128) -> Option<(u128, u128)> {
+    let g = gcd_u128(frac, den);
+    let den = den.checked_div(g)?;
+    Some((int.checked_mul(den)?.checked_add(frac / g)?, den))
+}
+
+/// Closest fraction to the signed value `±(int + frac / den)` with a denominator of at most
+/// `max_den`, as a lowest-terms (numerator magnitude, denominator). Mirrors Python's
+/// `Fraction.limit_denominator` on the signed value: a negative value is expanded from its
+/// floor `-(int + 1)`, so ties go to the convergent of that expansion. `None` only if the
+/// chosen numerator overflows u128.
+pub(crate) fn approximate_fraction(
+    int: u128,
+    frac: u128,
+    den: u128,
+    neg: bool,
+    max_den: u128,
+) -> Option<(u128, u128)> {
+    if neg && frac != 0 {
+        // -(int + frac / den) = -(int + 1) + (den - frac) / den
+        let (p, q) = approximate_unit(den - frac, den, max_den);
+        return Some((int.checked_add(1)?.checked_mul(q)?.checked_sub(p)?, q));
+    }
+    let (p, q) = approximate_unit(frac, den, max_den);
+    Some((int.checked_mul(q)?.checked_add(p)?, q))
+}
+
+/// Closest fraction p / q to `frac / den` in [0, 1) with `q <= max_den` (0 acts as 1): the
+/// last continued-fraction convergent within the bound or the best semiconvergent past it,
+/// whichever is nearer (ties to the convergent). Since p <= q <= max_den nothing overflows,
+/// and adding an integer part keeps the result in lowest terms.
+fn approximate_unit(frac: u128, den: u128, max_den: u128) -> (u128, u128) {
+    let max_den = max_den.max(1);
+    // den is a nonzero power of ten; max(1) only lets the compiler drop the zero checks
+    let g = gcd_u128(frac, den).max(1);
+    if den / g <= max_den {
+        return (frac / g, den / g);
+    }
+    // convergents p0/q0, p1/q1 of x = frac / den; x' = n / d is the complete quotient
+    let (mut p0, mut q0, mut p1, mut q1) = (1u128, 0u128, 0u128, 1u128);
+    let (mut n, mut d) = (den, frac);
+    while d != 0 {
+        let a = n / d;
+        match a.checked_mul(q1).and_then(|x| x.checked_add(q0)) {
+            Some(q2) if q2 <= max_den => {
+                (p0, q0, p1, q1) = (p1, q1, a * p1 + p0, q2);
+                (n, d) = (d, n % d);
+            }
+            _ => break,
+        }
+    }
+    // semiconvergent (p0 + k p1) / (q0 + k q1) with the largest k in bounds. Its distance to
+    // x is (x' - k) / ((q0 + k q1)(q1 x' + q0)) against 1 / (q1 (q1 x' + q0)) for p1 / q1,
+    // so the convergent wins iff (q0 + 2k q1) d <= q1 n. Here max_den < den <= 10^38, so the
+    // left factor stays below 2^128.
+    let k = (max_den - q0) / q1.max(1);
+    let kq1 = k * q1;
+    if mul_wide(q0 + 2 * kq1, d) <= mul_wide(q1, n) {
+        (p1, q1)
+    } else {
+        (k * p1 + p0, q0 + kq1)
+    }
+}
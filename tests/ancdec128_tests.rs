@@ -1,7 +1,9 @@
 // tests/ancdec128_tests.rs
 #![cfg(feature = "dec128")]
+// Reference-operator and float-literal tests are intentional
+#![allow(clippy::op_ref, clippy::approx_constant)]
 
-use ancdec::{AncDec128, RoundMode};
+use ancdec::{ArithmeticError, AncDec128, Context, ParseError, RoundMode, Signal, Signals};
 
 #[cfg(feature = "dec64")]
 use ancdec::AncDec;
@@ -485,6 +487,29 @@ fn test_round_decimal_places() {
     assert_eq!(a.round(3, RoundMode::HalfUp), "3.142".parse().unwrap());
 }
 
+#[test]
+fn test_round_wide_combined() {
+    // int * 10^scale exceeds u128: rounding must keep the high limb
+    let a: AncDec128 = "1000000000000000000000.00000000000000000000000000000000000005".parse().unwrap();
+    assert_eq!(
+        a.round(37, RoundMode::HalfUp).to_string(),
+        "1000000000000000000000.0000000000000000000000000000000000001"
+    );
+    assert_eq!(a.round(0, RoundMode::Ceil).to_string(), "1000000000000000000001");
+}
+
+#[test]
+fn test_round_with_residual() {
+    let a: AncDec128 = "1.23456789012345678901234567890123456789".parse().unwrap();
+    let (r, res) = a.round_with_residual(30, RoundMode::HalfUp);
+    assert_eq!(r.to_string(), "1.234567890123456789012345678901");
+    assert_eq!(res.to_string(), "0.00000000000000000000000000000023456789");
+    assert_eq!(r + res, a);
+    let (r, res) = (-a).round_with_residual(1, RoundMode::HalfDown);
+    assert_eq!((r.to_string(), res.to_string()), ("-1.2".into(), "-0.03456789012345678901234567890123456789".into()));
+    assert_eq!(r + res, -a);
+}
+
 // ============ Floor/Ceil/Trunc/Fract ============
 #[test]
 fn test_floor() {
@@ -549,6 +574,19 @@ fn test_pow_negative() {
     assert_eq!(a.pow(-1), "0.5".parse().unwrap());
 }
 
+#[test]
+fn test_powi_large_exponent() {
+    let two: AncDec128 = "2".parse().unwrap();
+    assert_eq!(two.powi(3), "8".parse().unwrap());
+    assert_eq!(two.checked_powi(i64::MAX), None);
+    assert_eq!(two.try_powi(i64::MAX), Err(ArithmeticError::Overflow));
+    let half: AncDec128 = "0.5".parse().unwrap();
+    assert_eq!(half.checked_powi(i64::MAX), Some(AncDec128::ZERO));
+    assert_eq!((-AncDec128::ONE).powi(i64::MAX), -AncDec128::ONE);
+    assert_eq!(AncDec128::ONE.powi(i64::MIN), AncDec128::ONE);
+    assert_eq!(AncDec128::ZERO.try_powi(-1), Err(ArithmeticError::DivisionByZero));
+}
+
 // ============ Square Root ============
 #[test]
 fn test_sqrt_perfect_squares_128() {
@@ -604,12 +642,359 @@ fn test_sqrt_large_128() {
 }
 
 #[test]
+#[cfg(not(feature = "panic-free"))]
 #[should_panic(expected = "square root of negative")]
 fn test_sqrt_negative_panics_128() {
     let neg: AncDec128 = "-4".parse().unwrap();
     neg.sqrt();
 }
 
+#[test]
+fn test_sqrt_round() {
+    let two: AncDec128 = "2".parse().unwrap();
+    assert_eq!(two.sqrt_round(38, RoundMode::HalfUp).to_string(), "1.41421356237309504880168872420969807857");
+    assert_eq!(two.sqrt_round(38, RoundMode::Truncate).to_string(), "1.41421356237309504880168872420969807856");
+    assert_eq!(two.sqrt_round(37, RoundMode::HalfEven).to_string(), "1.4142135623730950488016887242096980786");
+    let big: AncDec128 = "340282366920938463463374607431768211455".parse().unwrap();
+    assert_eq!(big.sqrt_round(38, RoundMode::HalfUp).to_string(), "18446744073709551615.99999999999999999997289494568786238915");
+    let tiny: AncDec128 = "0.00000000000000000000000000000000000001".parse().unwrap();
+    assert_eq!(tiny.sqrt_round(19, RoundMode::Up).to_string(), "0.0000000000000000001");
+    assert_eq!(tiny.sqrt_round(18, RoundMode::HalfUp).to_string(), "0.000000000000000000");
+    assert_eq!((-two).checked_sqrt_round(2, RoundMode::HalfUp), None);
+}
+
+#[test]
+fn test_cbrt_nth_root() {
+    let a: AncDec128 = "27".parse().unwrap();
+    assert_eq!(a.cbrt().to_string(), "3.0000000000000000000000000000000000000");
+    let b: AncDec128 = "-8".parse().unwrap();
+    assert_eq!(b.cbrt().to_string(), "-2.0000000000000000000000000000000000000");
+    assert_eq!(AncDec128::TWO.nth_root(5).to_string(), "1.1486983549970350067986269467779275894");
+    assert_eq!(AncDec128::ONE.nth_root(4_000_000_000), AncDec128::ONE);
+    assert_eq!(AncDec128::ZERO.nth_root(7), AncDec128::ZERO);
+}
+
+#[test]
+fn test_nth_root_domain() {
+    let neg: AncDec128 = "-16".parse().unwrap();
+    assert_eq!(neg.try_nth_root(4), Err(ArithmeticError::NegativeSqrt));
+    assert_eq!(AncDec128::TWO.try_nth_root(0), Err(ArithmeticError::OutOfDomain));
+    assert_eq!(neg.checked_nth_root(4), None);
+}
+
+#[test]
+#[cfg(not(feature = "panic-free"))]
+#[should_panic(expected = "square root of negative")]
+fn test_even_root_negative_panics() {
+    let neg: AncDec128 = "-16".parse().unwrap();
+    neg.nth_root(4);
+}
+
+// ============ Exponential and Logarithm ============
+#[test]
+fn test_exp() {
+    assert_eq!(AncDec128::ZERO.exp(), AncDec128::ONE);
+    assert_eq!(AncDec128::ONE.exp().to_string(), "2.71828182845904523536028747135266249776");
+    assert_eq!((-AncDec128::ONE).exp().to_string(), "0.36787944117144232159552377016146086745");
+    let big: AncDec128 = "89".parse().unwrap();
+    assert_eq!(big.checked_exp(), None);
+    assert_eq!(big.try_exp(), Err(ArithmeticError::Overflow));
+    let small: AncDec128 = "-1000".parse().unwrap();
+    assert_eq!(small.exp(), AncDec128::ZERO);
+}
+
+#[test]
+fn test_ln() {
+    assert_eq!(AncDec128::ONE.ln(), AncDec128::ZERO);
+    let two: AncDec128 = "2".parse().unwrap();
+    assert_eq!(two.ln().to_string(), "0.69314718055994530941723212145817656808");
+    let half: AncDec128 = "0.5".parse().unwrap();
+    assert_eq!(half.ln().to_string(), "-0.69314718055994530941723212145817656808");
+    assert_eq!(AncDec128::ONE.exp().ln(), AncDec128::ONE);
+    assert_eq!(AncDec128::ZERO.checked_ln(), None);
+    assert_eq!((-two).try_ln(), Err(ArithmeticError::OutOfDomain));
+}
+
+#[test]
+fn test_log10_log2() {
+    let x: AncDec128 = "1000".parse().unwrap();
+    assert_eq!(x.log10().to_string(), "3");
+    let x: AncDec128 = "0.001".parse().unwrap();
+    assert_eq!(x.log10().to_string(), "-3");
+    let two: AncDec128 = "2".parse().unwrap();
+    assert_eq!(two.log10().to_string(), "0.30102999566398119521373889472449302677");
+    let x: AncDec128 = "1024".parse().unwrap();
+    assert_eq!(x.log2().to_string(), "10");
+    assert_eq!(AncDec128::ZERO.checked_log10(), None);
+    assert_eq!((-two).checked_log2(), None);
+}
+
+#[test]
+fn test_log_base() {
+    let x: AncDec128 = "8".parse().unwrap();
+    let two: AncDec128 = "2".parse().unwrap();
+    assert_eq!(x.log(&two).to_string(), "3");
+    let x: AncDec128 = "100".parse().unwrap();
+    let tenth: AncDec128 = "0.1".parse().unwrap();
+    assert_eq!(x.log(&tenth).to_string(), "-2");
+    assert_eq!(x.checked_log(&AncDec128::ONE), None);
+    assert_eq!(x.try_log(&AncDec128::ZERO), Err(ArithmeticError::OutOfDomain));
+    let near_one: AncDec128 = "1.00000000000000000000000000000000000001".parse().unwrap();
+    assert_eq!(x.try_log(&near_one), Err(ArithmeticError::Overflow));
+    // a base this close to one still gives all 38 places
+    let five: AncDec128 = "5".parse().unwrap();
+    assert_eq!(
+        five.log(&near_one).to_string(),
+        "160943791243410037460075933322618763953.36485438306882237856516881403099259053"
+    );
+    let below_one: AncDec128 = "0.99999999999999999999999999999999999999".parse().unwrap();
+    assert_eq!(
+        five.log(&below_one).to_string(),
+        "-160943791243410037460075933322618763951.755416470634722003964409480804804951"
+    );
+}
+
+#[test]
+fn test_powd() {
+    let two: AncDec128 = "2".parse().unwrap();
+    let half: AncDec128 = "0.5".parse().unwrap();
+    assert_eq!(two.powd(&half).to_string(), "1.41421356237309504880168872420969807857");
+    let x: AncDec128 = "4".parse().unwrap();
+    assert_eq!(x.powd(&half), two);
+    assert_eq!(x.powd(&-half), half);
+    // integer exponents keep exact results
+    let three: AncDec128 = "3".parse().unwrap();
+    assert_eq!((-two).powd(&three), "-8".parse().unwrap());
+    // integer exponents round the exact power once: 8.81...^4 used to come out 131 ulps low
+    let y: AncDec128 = "8.81063418503402040471428".parse().unwrap();
+    let four: AncDec128 = "4.0".parse().unwrap();
+    assert_eq!(y.powd(&four).to_string(), "6025.99378394604094099066902402269352286806");
+    let z: AncDec128 = "1.1".parse().unwrap();
+    assert_eq!(z.powd(&-three).to_string(), "0.75131480090157776108189331329827197596");
+    // 2^-39 ends in a 5 at the 39th place: an exact tie, kept even
+    let e: AncDec128 = "-39".parse().unwrap();
+    assert_eq!(two.powd(&e).to_string(), "0.00000000000181898940354585647583007812");
+    assert_eq!((-two).checked_powd(&half), None);
+    assert_eq!((-two).try_powd(&half), Err(ArithmeticError::OutOfDomain));
+    assert_eq!(AncDec128::ZERO.powd(&half), AncDec128::ZERO);
+    assert_eq!(AncDec128::ZERO.try_powd(&-half), Err(ArithmeticError::DivisionByZero));
+    let big: AncDec128 = "128.5".parse().unwrap();
+    assert_eq!(two.try_powd(&big), Err(ArithmeticError::Overflow));
+}
+
+#[test]
+fn test_powd_annualization() {
+    // monthly rate from a 5% annual rate: 1.05^(1/12)
+    let annual: AncDec128 = "1.05".parse().unwrap();
+    let twelfth = AncDec128::ONE / "12".parse::<AncDec128>().unwrap();
+    assert_eq!(annual.powd(&twelfth).to_string(), "1.00407412378364830160541960267210716359");
+}
+
+#[test]
+#[cfg(not(feature = "panic-free"))]
+#[should_panic(expected = "argument out of domain")]
+fn test_ln_negative_panics() {
+    (-AncDec128::ONE).ln();
+}
+
+// ============ Trigonometric and Hyperbolic ============
+#[test]
+fn test_sin_cos_tan() {
+    assert_eq!(AncDec128::ZERO.sin(), AncDec128::ZERO);
+    assert_eq!(AncDec128::ZERO.cos(), AncDec128::ONE);
+    assert_eq!(AncDec128::ONE.sin().to_string(), "0.84147098480789650665250232163029899962");
+    assert_eq!((-AncDec128::ONE).sin().to_string(), "-0.84147098480789650665250232163029899962");
+    assert_eq!((-AncDec128::ONE).cos().to_string(), "0.54030230586813971740093660744297660373");
+    assert_eq!(AncDec128::ONE.tan().to_string(), "1.55740772465490223050697480745836017309");
+    // reduced modulo an internal high-precision π
+    let x: AncDec128 = "1000000".parse().unwrap();
+    assert_eq!(x.cos().to_string(), "0.93675212753314478693853253507491877571");
+    let near_half_pi: AncDec128 = "1.57079632679489661923132169163975144210".parse().unwrap();
+    assert_eq!(near_half_pi.checked_tan(), None);
+    assert_eq!(near_half_pi.try_tan(), Err(ArithmeticError::Overflow));
+}
+
+#[test]
+fn test_tan_near_pole() {
+    // correctly rounded all the way to the overflow boundary (references from mpmath)
+    let x: AncDec128 = "1.5707963267948966192313216916".parse().unwrap();
+    assert_eq!(x.tan().to_string(), "25156320052992586843308997626.59190862638404283170154710044225040174");
+    let x: AncDec128 = "1.5707963267948966192313216916397514".parse().unwrap();
+    assert_eq!(x.tan().to_string(), "23753767665434648105213424092128020.17872717301747436058009461973173050843");
+    let x: AncDec128 = "1.57079632679489661923132169163975144209".parse().unwrap();
+    assert_eq!(x.tan().to_string(), "116486311274221455109769244967140839828.57589880275995834824253847842669464373");
+    let x: AncDec128 = "-4.71238898038468985769396507491925432629".parse().unwrap();
+    assert_eq!(x.tan().to_string(), "-173789152586806754103862699969511009524.26554616272972136948867519453769234978");
+    // next to the pole (2^126 + 1) * π/2, where the 320-bit π alone leaves 2^-193 of error
+    let x: AncDec128 = "133628573008120843482460046645233847914.15089203615937".parse().unwrap();
+    assert_eq!(x.tan().to_string(), "-392454932234503.21788523209804429330253941789357541871");
+}
+
+#[test]
+fn test_inverse_trig() {
+    assert_eq!(AncDec128::ONE.asin().to_string(), "1.5707963267948966192313216916397514421");
+    assert_eq!((-AncDec128::ONE).acos().to_string(), "3.1415926535897932384626433832795028842");
+    assert_eq!(AncDec128::ONE.acos(), AncDec128::ZERO);
+    assert_eq!(AncDec128::ONE.atan().to_string(), "0.78539816339744830961566084581987572105");
+    let half: AncDec128 = "0.5".parse().unwrap();
+    assert_eq!(half.asin().to_string(), "0.52359877559829887307710723054658381403");
+    let x: AncDec128 = "1.5".parse().unwrap();
+    assert_eq!(x.checked_asin(), None);
+    assert_eq!((-x).try_acos(), Err(ArithmeticError::OutOfDomain));
+}
+
+#[test]
+fn test_atan2() {
+    assert_eq!(AncDec128::ONE.atan2(&-AncDec128::ONE).to_string(), "2.35619449019234492884698253745962716315");
+    assert_eq!((-AncDec128::ONE).atan2(&-AncDec128::ONE).to_string(), "-2.35619449019234492884698253745962716315");
+    assert_eq!(AncDec128::ZERO.atan2(&-AncDec128::ONE).to_string(), "3.1415926535897932384626433832795028842");
+    assert_eq!(AncDec128::ONE.atan2(&AncDec128::ZERO).to_string(), "1.5707963267948966192313216916397514421");
+    assert_eq!(AncDec128::ZERO.atan2(&AncDec128::ZERO), AncDec128::ZERO);
+}
+
+#[test]
+fn test_hyperbolic() {
+    assert_eq!(AncDec128::ZERO.sinh(), AncDec128::ZERO);
+    assert_eq!(AncDec128::ZERO.cosh(), AncDec128::ONE);
+    assert_eq!(AncDec128::ONE.sinh().to_string(), "1.17520119364380145688238185059560081516");
+    assert_eq!((-AncDec128::ONE).sinh().to_string(), "-1.17520119364380145688238185059560081516");
+    assert_eq!((-AncDec128::ONE).cosh().to_string(), "1.5430806348152437784779056207570616826");
+    assert_eq!(AncDec128::ONE.tanh().to_string(), "0.76159415595576488811945828260479359041");
+    let big: AncDec128 = "90".parse().unwrap();
+    assert_eq!(big.tanh(), AncDec128::ONE);
+    assert_eq!((-big).tanh(), -AncDec128::ONE);
+    assert_eq!(big.checked_cosh(), None);
+    assert_eq!((-big).try_sinh(), Err(ArithmeticError::Overflow));
+}
+
+#[test]
+#[cfg(not(feature = "panic-free"))]
+#[should_panic(expected = "argument out of domain")]
+fn test_asin_out_of_domain_panics() {
+    "2".parse::<AncDec128>().unwrap().asin();
+}
+
+// ============ Error Function and Normal Distribution ============
+#[test]
+fn test_erf_erfc() {
+    let one = AncDec128::ONE;
+    assert_eq!(one.erf().to_string(), "0.8427007929497148693412206350826092593");
+    assert_eq!((-one).erf().to_string(), "-0.8427007929497148693412206350826092593");
+    assert_eq!(one.erfc().to_string(), "0.1572992070502851306587793649173907407");
+    assert_eq!(AncDec128::ZERO.erfc(), AncDec128::ONE);
+    // deep in the tail erfc keeps the digits 1 - erf would lose
+    assert_eq!(AncDec128::from(9).erfc().to_string(), "0.00000000000000000000000000000000000041");
+    assert_eq!(AncDec128::from(9).erf().to_string(), "0.99999999999999999999999999999999999959");
+    assert_eq!(AncDec128::from(12).erf(), AncDec128::ONE);
+}
+
+#[test]
+fn test_norm_cdf() {
+    let x: AncDec128 = "1.96".parse().unwrap();
+    assert_eq!(x.norm_cdf().to_string(), "0.97500210485177956586341573095916280998");
+    assert_eq!(AncDec128::ZERO.norm_cdf().to_string(), "0.5");
+    assert_eq!(AncDec128::MIN.norm_cdf(), AncDec128::ZERO);
+}
+
+#[test]
+fn test_norm_inv_cdf() {
+    let p: AncDec128 = "0.975".parse().unwrap();
+    assert_eq!(p.norm_inv_cdf().to_string(), "1.95996398454005423552459443052055152796");
+    assert_eq!((AncDec128::ONE - p).norm_inv_cdf(), -p.norm_inv_cdf());
+    assert_eq!(
+        AncDec128::MIN_POSITIVE.norm_inv_cdf().to_string(),
+        "-12.96235933479686192862311220374641299995"
+    );
+    let neg: AncDec128 = "-0.5".parse().unwrap();
+    assert_eq!(neg.try_norm_inv_cdf(), Err(ArithmeticError::OutOfDomain));
+    assert_eq!(AncDec128::ONE.checked_norm_inv_cdf(), None);
+}
+
+#[test]
+#[cfg(not(feature = "panic-free"))]
+#[should_panic(expected = "argument out of domain")]
+fn test_norm_inv_cdf_out_of_domain_panics() {
+    AncDec128::ZERO.norm_inv_cdf();
+}
+
+// ============ Number Theory ============
+#[test]
+fn test_gcd_lcm() {
+    let a: AncDec128 = "0.25".parse().unwrap();
+    let b: AncDec128 = "0.1".parse().unwrap();
+    assert_eq!(a.gcd(&b).to_string(), "0.05");
+    assert_eq!(a.lcm(&b).to_string(), "0.50");
+    assert_eq!((-a).gcd(&b), a.gcd(&b));
+    assert_eq!(a.gcd(&AncDec128::ZERO), a);
+    assert_eq!(a.lcm(&AncDec128::ZERO), AncDec128::ZERO);
+    let x: AncDec128 = "12".parse().unwrap();
+    let y: AncDec128 = "18".parse().unwrap();
+    assert_eq!(x.gcd(&y).to_string(), "6");
+    assert_eq!(x.lcm(&y).to_string(), "36");
+}
+
+#[test]
+fn test_lcm_overflow() {
+    let a: AncDec128 = "300000000000000000000000000000000000000".parse().unwrap();
+    let b = a - AncDec128::ONE;
+    assert_eq!(a.checked_lcm(&b), None);
+    assert_eq!(a.try_lcm(&b), Err(ArithmeticError::Overflow));
+}
+
+#[test]
+fn test_factorial_binomial() {
+    assert_eq!(AncDec128::factorial(0), AncDec128::ONE);
+    assert_eq!(AncDec128::factorial(20).to_string(), "2432902008176640000");
+    assert_eq!(AncDec128::factorial(34).to_string(), "295232799039604140847618609643520000000");
+    assert_eq!(AncDec128::checked_factorial(35), None);
+    assert_eq!(AncDec128::binomial(52, 5).to_string(), "2598960");
+    assert_eq!(AncDec128::binomial(10, 11), AncDec128::ZERO);
+    assert_eq!(AncDec128::binomial(1_000_000, 1_000_000), AncDec128::ONE);
+    // C(130, 65) ~ 9.5e37 fits, C(132, 66) ~ 3.8e38 does not
+    assert_eq!(AncDec128::binomial(130, 65).to_string(), "95067625827960698145584333020095113100");
+    assert_eq!(AncDec128::try_binomial(132, 66), Err(ArithmeticError::Overflow));
+}
+
+#[test]
+fn test_to_fraction() {
+    let a: AncDec128 = "0.375".parse().unwrap();
+    assert_eq!(a.to_fraction(), (3, 8));
+    let b: AncDec128 = "-2.50".parse().unwrap();
+    assert_eq!(b.to_fraction(), (-5, 2));
+    assert_eq!(AncDec128::from(7).to_fraction(), (7, 1));
+    assert_eq!(AncDec128::ZERO.to_fraction(), (0, 1));
+}
+
+#[test]
+fn test_approximate_fraction() {
+    let a: AncDec128 = "101.53125".parse().unwrap();
+    assert_eq!(a.approximate_fraction(32), (3249, 32));
+    assert_eq!(AncDec128::PI.approximate_fraction(113), (355, 113));
+    assert_eq!(AncDec128::PI.approximate_fraction(1_000_000), (3126535, 995207));
+    assert_eq!((-AncDec128::PI).approximate_fraction(1000), (-355, 113));
+    let d: AncDec128 = "1.5".parse().unwrap();
+    assert_eq!(d.approximate_fraction(1), (1, 1));
+    assert_eq!((-d).approximate_fraction(1), (-2, 1));
+    let e: AncDec128 = "-0.5".parse().unwrap();
+    assert_eq!(e.approximate_fraction(1), (-1, 1));
+    // the exact numerator overflows u128, the bounded result does not
+    let f: AncDec128 = "4.33333333333333333333333333333333333333".parse().unwrap();
+    assert_eq!(f.approximate_fraction(3), (13, 3));
+    let g: AncDec128 = "101.15625000000000000000000000000000000001".parse().unwrap();
+    assert_eq!(g.approximate_fraction(32), (3237, 32));
+    assert_eq!((-g).approximate_fraction(32), (-3237, 32));
+}
+
+#[test]
+fn test_fraction_overflow() {
+    let a: AncDec128 = "200000000000000000000000000000000000000".parse().unwrap();
+    assert_eq!(a.try_to_fraction(), Err(ArithmeticError::Overflow));
+    assert_eq!(a.try_approximate_fraction(1), Err(ArithmeticError::Overflow));
+    // -2^127 is exactly i128::MIN
+    let b: AncDec128 = "-170141183460469231731687303715884105728".parse().unwrap();
+    assert_eq!(b.to_fraction(), (i128::MIN, 1));
+}
+
 // ============ Conversion ============
 #[test]
 fn test_to_f64() {
@@ -685,6 +1070,39 @@ fn test_constants() {
     assert_eq!(AncDec128::TEN.int(), 10);
 }
 
+#[test]
+fn test_min_epsilon_constants() {
+    assert_eq!(AncDec128::MIN, -AncDec128::MAX);
+    assert_eq!(AncDec128::MIN_POSITIVE.to_string(), "0.00000000000000000000000000000000000001");
+    assert_eq!(AncDec128::ONE.next_up() - AncDec128::ONE, AncDec128::EPSILON);
+}
+
+#[test]
+fn test_ulp_next_up_down() {
+    let a: AncDec128 = "0.123".parse().unwrap();
+    assert_eq!(a.ulp().to_string(), "0.001");
+    assert_eq!(a.next_up().to_string(), "0.12300000000000000000000000000000000001");
+    assert_eq!(a.next_down().to_string(), "0.12299999999999999999999999999999999999");
+    // next_up/next_down step by EPSILON, not by the value's own ulp
+    assert_eq!(a.next_up() - a, AncDec128::EPSILON);
+    assert_eq!(a - a.next_down(), AncDec128::EPSILON);
+    assert_ne!(a.next_up() - a, a.ulp());
+    assert!((-AncDec128::MIN_POSITIVE).next_up().is_zero());
+    assert_eq!(AncDec128::MAX.next_down().next_up(), AncDec128::MAX);
+}
+
+#[test]
+fn test_math_constants() {
+    assert_eq!(AncDec128::PI.to_string(), "3.14159265358979323846264338327950288420");
+    assert_eq!(AncDec128::PI.round(37, RoundMode::HalfEven), (-AncDec128::ONE).acos());
+    assert_eq!(AncDec128::E, AncDec128::ONE.exp());
+    assert_eq!(AncDec128::LN_2, AncDec128::TWO.ln());
+    assert_eq!(AncDec128::LN_10, AncDec128::TEN.ln());
+    assert_eq!(AncDec128::SQRT_2, AncDec128::TWO.sqrt_round(38, RoundMode::HalfEven));
+    let half: AncDec128 = "0.5".parse().unwrap();
+    assert_eq!(AncDec128::FRAC_1_SQRT_2, half.sqrt_round(38, RoundMode::HalfEven));
+}
+
 // ============ Edge Cases ============
 #[test]
 fn test_zero_operations() {
@@ -717,6 +1135,7 @@ fn test_frac_truncates_at_38() {
 }
 
 #[test]
+#[cfg(not(feature = "panic-free"))]
 #[should_panic]
 fn test_div_by_zero_panics() {
     let a: AncDec128 = "5".parse().unwrap();
@@ -729,6 +1148,543 @@ fn test_negative_zero_equals_zero() {
     assert_eq!(neg_zero, AncDec128::ZERO);
 }
 
+// ============ Checked Arithmetic ============
+#[test]
+fn test_checked_div() {
+    let a: AncDec128 = "10".parse().unwrap();
+    let b: AncDec128 = "4".parse().unwrap();
+    assert_eq!(a.checked_div(&b), Some("2.5".parse().unwrap()));
+    assert_eq!(a.checked_div(&AncDec128::ZERO), None);
+    let tiny: AncDec128 = "0.00000000000000000000000000000000000001".parse().unwrap();
+    assert_eq!(AncDec128::MAX.checked_div(&tiny), None);
+    // u256 divisor slow path
+    let big: AncDec128 = "12345678901234567890123.45678901234567890123456789".parse().unwrap();
+    assert_eq!(AncDec128::MAX.checked_div(&big.pow(-1)), None);
+}
+
+#[test]
+fn test_checked_rem() {
+    let a: AncDec128 = "10.5".parse().unwrap();
+    let b: AncDec128 = "3".parse().unwrap();
+    assert_eq!(a.checked_rem(&b), Some("1.5".parse().unwrap()));
+    assert_eq!(a.checked_rem(&AncDec128::ZERO), None);
+}
+
+#[test]
+fn test_checked_pow() {
+    let a: AncDec128 = "1.5".parse().unwrap();
+    assert_eq!(a.checked_pow(3), Some("3.375".parse().unwrap()));
+    assert_eq!(AncDec128::TWO.checked_pow(-3), Some("0.125".parse().unwrap()));
+    assert_eq!(AncDec128::TWO.checked_pow(128), None);
+    assert_eq!(AncDec128::ZERO.checked_pow(-2), None);
+    assert_eq!(AncDec128::TWO.checked_pow(127), Some(AncDec128::from(1u128 << 127)));
+}
+
+#[test]
+fn test_checked_sqrt_and_neg() {
+    let a: AncDec128 = "9".parse().unwrap();
+    assert_eq!(a.checked_sqrt(), Some("3".parse().unwrap()));
+    assert_eq!((-a).checked_sqrt(), None);
+    assert_eq!(a.checked_neg(), Some(-a));
+}
+
+// ============ Saturating / Overflowing ============
+#[test]
+fn test_saturating_add_sub() {
+    let one = AncDec128::ONE;
+    assert_eq!(AncDec128::MAX.saturating_add(&one), AncDec128::MAX);
+    assert_eq!((-AncDec128::MAX).saturating_add(&-one), -AncDec128::MAX);
+    assert_eq!((-AncDec128::MAX).saturating_sub(&one), -AncDec128::MAX);
+    assert_eq!(AncDec128::MAX.saturating_sub(&-one), AncDec128::MAX);
+    assert_eq!(one.saturating_add(&one), AncDec128::TWO);
+}
+
+#[test]
+fn test_checked_sub_opposite_signs_overflow() {
+    assert_eq!(AncDec128::MAX.checked_sub(&-AncDec128::ONE), None);
+}
+
+#[test]
+fn test_saturating_mul_div() {
+    let tiny: AncDec128 = "0.00000000000000000000000000000000000001".parse().unwrap();
+    assert_eq!(AncDec128::MAX.saturating_mul(&AncDec128::TWO), AncDec128::MAX);
+    assert_eq!(AncDec128::MAX.saturating_mul(&-AncDec128::TWO), -AncDec128::MAX);
+    assert_eq!((-AncDec128::MAX).saturating_div(&tiny), -AncDec128::MAX);
+    assert_eq!(AncDec128::ONE.saturating_div(&AncDec128::ZERO), AncDec128::MAX);
+    assert_eq!(AncDec128::ZERO.saturating_div(&AncDec128::ZERO), AncDec128::ZERO);
+}
+
+#[test]
+fn test_saturating_pow() {
+    assert_eq!(AncDec128::TEN.saturating_pow(41), AncDec128::MAX);
+    assert_eq!((-AncDec128::TEN).saturating_pow(41), -AncDec128::MAX);
+    assert_eq!((-AncDec128::TEN).saturating_pow(42), AncDec128::MAX);
+    assert_eq!(AncDec128::ZERO.saturating_pow(-1), AncDec128::MAX);
+    assert_eq!(AncDec128::TWO.saturating_pow(2), "4".parse().unwrap());
+}
+
+#[test]
+fn test_overflowing_ops() {
+    let two = AncDec128::TWO;
+    assert_eq!(two.overflowing_add(&two), ("4".parse().unwrap(), false));
+    assert_eq!(AncDec128::MAX.overflowing_add(&two), (AncDec128::MAX, true));
+    assert_eq!(AncDec128::MAX.overflowing_sub(&-two), (AncDec128::MAX, true));
+    assert_eq!(AncDec128::MAX.overflowing_mul(&two), (AncDec128::MAX, true));
+    assert_eq!(two.overflowing_div(&AncDec128::ZERO), (AncDec128::MAX, true));
+    assert_eq!(two.overflowing_pow(2), ("4".parse().unwrap(), false));
+    assert_eq!(two.overflowing_pow(-1), ("0.5".parse().unwrap(), false));
+}
+
+// ============ Try Operations ============
+#[test]
+fn test_try_arithmetic() {
+    let a: AncDec128 = "1.5".parse().unwrap();
+    assert_eq!(a.try_add(&a), Ok(AncDec128::from(3)));
+    assert_eq!(a.try_sub(&a), Ok(AncDec128::ZERO));
+    assert_eq!(AncDec128::TWO.try_mul(&a), Ok(AncDec128::from(3)));
+    assert_eq!(a.try_div(&a), Ok(AncDec128::ONE));
+    assert_eq!(AncDec128::from(7).try_rem(&AncDec128::TWO), Ok(AncDec128::ONE));
+}
+
+#[test]
+fn test_try_arithmetic_errors() {
+    assert_eq!(AncDec128::MAX.try_add(&AncDec128::ONE), Err(ArithmeticError::Overflow));
+    assert_eq!((-AncDec128::MAX).try_sub(&AncDec128::ONE), Err(ArithmeticError::Overflow));
+    assert_eq!(AncDec128::MAX.try_mul(&AncDec128::TWO), Err(ArithmeticError::Overflow));
+    assert_eq!(AncDec128::ONE.try_div(&AncDec128::ZERO), Err(ArithmeticError::DivisionByZero));
+    assert_eq!(AncDec128::ONE.try_rem(&AncDec128::ZERO), Err(ArithmeticError::DivisionByZero));
+}
+
+#[test]
+fn test_try_pow_sqrt() {
+    assert_eq!(AncDec128::TWO.try_pow(3), Ok(AncDec128::from(8)));
+    assert_eq!(AncDec128::ZERO.try_pow(-1), Err(ArithmeticError::DivisionByZero));
+    assert_eq!(AncDec128::MAX.try_pow(2), Err(ArithmeticError::Overflow));
+    assert_eq!(AncDec128::from(4).try_sqrt().unwrap(), AncDec128::TWO);
+    assert_eq!((-AncDec128::ONE).try_sqrt(), Err(ArithmeticError::NegativeSqrt));
+}
+
+#[test]
+fn test_try_new() {
+    assert_eq!(AncDec128::try_new(1, 5, 1, false), Ok(AncDec128::new(1, 5, 1, false)));
+    assert_eq!(AncDec128::try_new(1, 0, 39, false), Err(ArithmeticError::ScaleOverflow));
+    assert_eq!(AncDec128::try_new(1, 10, 1, false), Err(ArithmeticError::ScaleOverflow));
+}
+
+#[test]
+fn test_try_to_i64_i128() {
+    assert_eq!(AncDec128::from(i64::MIN).try_to_i64(), Ok(i64::MIN));
+    assert_eq!(AncDec128::from(u64::MAX).try_to_i64(), Err(ArithmeticError::Overflow));
+    assert_eq!(AncDec128::from(i128::MIN).try_to_i128(), Ok(i128::MIN));
+    assert_eq!(AncDec128::MAX.try_to_i128(), Err(ArithmeticError::Overflow));
+}
+
+#[test]
+fn test_arithmetic_error_display() {
+    assert_eq!(ArithmeticError::DivisionByZero.to_string(), "division by zero");
+    assert_eq!(ArithmeticError::Overflow.to_string(), "arithmetic overflow");
+}
+
+#[test]
+#[cfg(feature = "panic-free")]
+fn test_panic_free_saturates() {
+    assert_eq!(AncDec128::MAX + AncDec128::ONE, AncDec128::MAX);
+    assert_eq!(AncDec128::MAX * -AncDec128::TWO, -AncDec128::MAX);
+    assert_eq!(AncDec128::ONE / AncDec128::ZERO, AncDec128::MAX);
+    assert_eq!(AncDec128::ONE % AncDec128::ZERO, AncDec128::ZERO);
+    assert_eq!((-AncDec128::ONE).sqrt(), AncDec128::ZERO);
+}
+
+#[test]
+fn test_round_up_half_odd_05up() {
+    let a: AncDec128 = "7.00000000000000000000000000000000000001".parse().unwrap();
+    assert_eq!(a.round(0, RoundMode::Up).to_string(), "8");
+    let b: AncDec128 = "0.00000000000000000000000000000000000025".parse().unwrap();
+    assert_eq!(b.round(37, RoundMode::HalfOdd).to_string(), "0.0000000000000000000000000000000000003");
+    assert_eq!(b.round(37, RoundMode::HalfEven).to_string(), "0.0000000000000000000000000000000000002");
+    assert_eq!(b.round(36, RoundMode::ZeroFiveUp).to_string(), "0.000000000000000000000000000000000001");
+    assert_eq!(a.round(1, RoundMode::ZeroFiveUp).to_string(), "7.1");
+}
+
+/// xorshift64: deterministic RNG for the stochastic rounding tests
+#[cfg(feature = "rand")]
+struct XorShift(u64);
+
+#[cfg(feature = "rand")]
+impl rand_core::RngCore for XorShift {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        rand_core::impls::fill_bytes_via_next(self, dst)
+    }
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_round_stochastic() {
+    let mut rng = XorShift(0x9E37_79B9_7F4A_7C15);
+    let a: AncDec128 = "0.00000000000000000000000000000000000075".parse().unwrap();
+    let (lo, hi): (AncDec128, AncDec128) = ("0".parse().unwrap(), "0.000000000000000000000000000000000001".parse().unwrap());
+    let mut ups = 0;
+    for _ in 0..4000 {
+        let r = a.round_stochastic(36, &mut rng);
+        assert!(r == lo || r == hi);
+        ups += (r == hi) as u32;
+    }
+    // rounds up three quarters of the time; the bounds are about 4.5 standard deviations
+    assert!((2880..=3120).contains(&ups), "{}", ups);
+    // nothing discarded: never rounds
+    let b: AncDec128 = "0.5".parse().unwrap();
+    assert_eq!(b.round_stochastic(36, &mut rng), b.round(36, RoundMode::Truncate));
+    // divisor 10^38 exceeds u64: drawn from two words
+    let half: AncDec128 = "0.50000000000000000000000000000000000000".parse().unwrap();
+    let ups = (0..1000).filter(|_| half.round_stochastic(0, &mut rng) == AncDec128::ONE).count();
+    assert!((430..=570).contains(&ups), "{}", ups);
+}
+
+// ============ Rounded Arithmetic ============
+#[test]
+fn test_mul_round() {
+    let a: AncDec128 = "1.25".parse().unwrap();
+    let b: AncDec128 = "1.5".parse().unwrap();
+    assert_eq!(a.mul_round(&b, 2, RoundMode::HalfEven).to_string(), "1.88");
+    assert_eq!(a.mul_round(&b, 2, RoundMode::HalfDown).to_string(), "1.87");
+    assert_eq!((-a).mul_round(&b, 1, RoundMode::Floor).to_string(), "-1.9");
+    // exact products keep their scale
+    assert_eq!(b.mul_round(&b, 4, RoundMode::HalfUp).to_string(), "2.25");
+}
+
+#[test]
+fn test_mul_round_half_below_target() {
+    // 5e-20 * 1e-19 = 5e-39, exactly half a unit at 38 places
+    let a: AncDec128 = "0.00000000000000000005".parse().unwrap();
+    let b: AncDec128 = "0.0000000000000000001".parse().unwrap();
+    assert_eq!(
+        a.mul_round(&b, 38, RoundMode::HalfUp).to_string(),
+        "0.00000000000000000000000000000000000001"
+    );
+    assert_eq!(a.mul_round(&b, 38, RoundMode::HalfEven), AncDec128::ZERO);
+}
+
+#[test]
+fn test_div_round() {
+    let two = AncDec128::TWO;
+    let three = AncDec128::from(3);
+    assert_eq!(two.div_round(&three, 4, RoundMode::HalfEven).to_string(), "0.6667");
+    assert_eq!(AncDec128::ONE.div_round(&three, 2, RoundMode::Ceil).to_string(), "0.34");
+    assert_eq!((-AncDec128::ONE).div_round(&three, 2, RoundMode::Floor).to_string(), "-0.34");
+    assert_eq!(
+        two.div_round(&three, 38, RoundMode::HalfUp).to_string(),
+        "0.66666666666666666666666666666666666667"
+    );
+    let x: AncDec128 = "2.50000000000000000000000000000000000001".parse().unwrap();
+    assert_eq!(x.div_round(&AncDec128::ONE, 0, RoundMode::HalfEven).to_string(), "3");
+    // large operands take the u512 path
+    let big = AncDec128::from(u128::MAX);
+    assert_eq!(
+        big.div_round(&three, 0, RoundMode::HalfUp).to_string(),
+        "113427455640312821154458202477256070485"
+    );
+}
+
+#[test]
+fn test_checked_mul_div_round() {
+    assert_eq!(AncDec128::MAX.checked_mul_round(&AncDec128::TWO, 2, RoundMode::HalfUp), None);
+    assert_eq!(AncDec128::MAX.checked_div_round(&AncDec128::ZERO, 2, RoundMode::HalfUp), None);
+    assert_eq!(
+        AncDec128::ONE.checked_div_round(&AncDec128::TWO, 0, RoundMode::HalfUp),
+        Some(AncDec128::ONE)
+    );
+}
+
+#[test]
+#[cfg(not(feature = "panic-free"))]
+#[should_panic(expected = "division by zero")]
+fn test_div_round_by_zero() {
+    AncDec128::ONE.div_round(&AncDec128::ZERO, 2, RoundMode::HalfUp);
+}
+
+// ============ Fused Multiply-Divide ============
+#[test]
+fn test_mul_div() {
+    let price: AncDec128 = "19.99".parse().unwrap();
+    let qty = AncDec128::from(3);
+    let fx: AncDec128 = "1.2345".parse().unwrap();
+    assert_eq!(
+        price.mul_div(&qty, &fx, RoundMode::HalfEven).unwrap().to_string(),
+        "48.57837181044957472660996354799513973269"
+    );
+    let x: AncDec128 = "1234567890123.4567890123456789".parse().unwrap();
+    assert_eq!(x.mul_div(&x, &x, RoundMode::HalfUp), Some(x));
+    let three = AncDec128::from(3);
+    assert_eq!(
+        (-AncDec128::TWO).mul_div(&AncDec128::ONE, &three, RoundMode::Floor).unwrap().to_string(),
+        "-0.66666666666666666666666666666666666667"
+    );
+}
+
+#[test]
+fn test_mul_div_wide_intermediate() {
+    // MAX * MAX needs the full u512 product
+    assert_eq!(
+        AncDec128::MAX.mul_div(&AncDec128::MAX, &AncDec128::MAX, RoundMode::HalfUp),
+        Some(AncDec128::MAX)
+    );
+    assert_eq!(AncDec128::MAX.mul_div(&AncDec128::TWO, &AncDec128::ONE, RoundMode::HalfUp), None);
+    assert_eq!(AncDec128::ONE.mul_div(&AncDec128::ONE, &AncDec128::ZERO, RoundMode::HalfUp), None);
+}
+
+#[test]
+fn test_mul_div_rounds_once() {
+    // product has 39 places, so the quotient is reduced by a further 10
+    let a: AncDec128 = "0.00000000000000000000000000000000000045".parse().unwrap();
+    let b: AncDec128 = "0.1".parse().unwrap();
+    let three = AncDec128::from(3);
+    assert_eq!(
+        a.mul_div(&b, &three, RoundMode::HalfDown).unwrap().to_string(),
+        "0.00000000000000000000000000000000000001"
+    );
+    let a: AncDec128 = "0.00000000000000000000000000000000000046".parse().unwrap();
+    assert_eq!(
+        a.mul_div(&b, &three, RoundMode::HalfDown).unwrap().to_string(),
+        "0.00000000000000000000000000000000000002"
+    );
+}
+
+// ============ Integer Division ============
+#[test]
+fn test_divmod() {
+    let a: AncDec128 = "7.5".parse().unwrap();
+    let b = AncDec128::TWO;
+    let (q, r) = a.divmod(&b);
+    assert_eq!(q, AncDec128::from(3));
+    assert_eq!(r.to_string(), "1.5");
+    let (q, r) = (-a).divmod(&b);
+    assert_eq!(q.to_string(), "-3");
+    assert_eq!(r.to_string(), "-1.5");
+    assert_eq!(a.checked_divmod(&AncDec128::ZERO), None);
+}
+
+#[test]
+fn test_div_mod_floor() {
+    let a: AncDec128 = "-7.5".parse().unwrap();
+    let b = AncDec128::TWO;
+    assert_eq!(a.div_floor(&b).to_string(), "-4");
+    assert_eq!(a.mod_floor(&b).to_string(), "0.5");
+    assert_eq!((-a).mod_floor(&-b).to_string(), "-0.5");
+    assert_eq!(b.checked_mod_floor(&AncDec128::ZERO), None);
+}
+
+#[test]
+fn test_div_rem_euclid() {
+    let a: AncDec128 = "-7.5".parse().unwrap();
+    let b = AncDec128::TWO;
+    assert_eq!(a.div_euclid(&b).to_string(), "-4");
+    assert_eq!(a.rem_euclid(&b).to_string(), "0.5");
+    assert_eq!(a.div_euclid(&-b).to_string(), "4");
+    assert_eq!(a.rem_euclid(&-b).to_string(), "0.5");
+    // exact division leaves no adjustment
+    assert_eq!(AncDec128::from(6).rem_euclid(&-b), AncDec128::ZERO);
+}
+
+#[test]
+fn test_int_division_i128() {
+    let a: AncDec128 = "-7.5".parse().unwrap();
+    let b = AncDec128::TWO;
+    assert_eq!(a.divmod_i128(&b), Some((-3, "-1.5".parse().unwrap())));
+    assert_eq!(a.div_floor_i128(&b), Some(-4));
+    assert_eq!(a.div_euclid_i128(&-b), Some(4));
+    assert_eq!(a.div_floor_i128(&AncDec128::ZERO), None);
+}
+
+#[test]
+fn test_rem_large_quotient() {
+    // the quotient overflows, but the remainder is exact
+    let a = AncDec128::from(u128::MAX);
+    let b: AncDec128 = "0.7".parse().unwrap();
+    assert_eq!(a.checked_div_floor(&b), None);
+    assert_eq!(a.div_floor_i128(&b), None);
+    assert_eq!((a % b).to_string(), "0.2");
+    assert_eq!((-a).rem_euclid(&b).to_string(), "0.5");
+}
+
+// ============ Minimal-scale Division ============
+#[test]
+fn test_div_min_scale_terminating() {
+    let one = AncDec128::ONE;
+    let q = one.div_min_scale(&AncDec128::TWO, 0, RoundMode::HalfEven);
+    assert_eq!(q.to_string(), "0.5");
+    assert_eq!(q.scale(), 1);
+    let ten: AncDec128 = "10".parse().unwrap();
+    assert_eq!(ten.div_min_scale(&"4".parse().unwrap(), 0, RoundMode::HalfEven).to_string(), "2.5");
+    let six: AncDec128 = "6".parse().unwrap();
+    assert_eq!(six.div_min_scale(&"0.3".parse().unwrap(), 0, RoundMode::HalfEven).to_string(), "20");
+    let eight: AncDec128 = "8".parse().unwrap();
+    assert_eq!(one.div_min_scale(&eight, 2, RoundMode::HalfEven).to_string(), "0.125");
+    // the small scale keeps the following mul in the fast tiers
+    let x: AncDec128 = "12.34".parse().unwrap();
+    assert_eq!((q * x).scale(), 3);
+}
+
+#[test]
+fn test_div_min_scale_non_terminating() {
+    let three: AncDec128 = "3".parse().unwrap();
+    assert_eq!(AncDec128::ONE.div_min_scale(&three, 1, RoundMode::HalfUp).to_string(), "0.3");
+    assert_eq!(AncDec128::TWO.div_min_scale(&three, 2, RoundMode::HalfEven).to_string(), "0.67");
+    assert_eq!((-AncDec128::TWO).div_min_scale(&three, 1, RoundMode::Floor).to_string(), "-0.7");
+    assert_eq!(AncDec128::ONE.checked_div_min_scale(&AncDec128::ZERO, 2, RoundMode::HalfUp), None);
+}
+
+// ============ Exact Arithmetic ============
+#[test]
+fn test_mul_div_exact() {
+    let a: AncDec128 = "0.0000000000000000001".parse().unwrap();
+    assert_eq!(a.mul_exact(&a).unwrap().scale(), 38);
+    let b: AncDec128 = "0.00000000000000000001".parse().unwrap();
+    assert_eq!(b.mul_exact(&b), Err(ArithmeticError::Inexact));
+    let q = AncDec128::ONE.div_exact(&"1024".parse().unwrap()).unwrap();
+    assert_eq!(q.to_string(), "0.0009765625");
+    assert_eq!(AncDec128::ONE.div_exact(&"7".parse().unwrap()), Err(ArithmeticError::Inexact));
+    assert_eq!(AncDec128::MAX.mul_exact(&AncDec128::TWO), Err(ArithmeticError::Overflow));
+}
+
+#[test]
+fn test_add_parse_exact() {
+    assert_eq!(AncDec128::MAX.add_exact(&AncDec128::ONE), Err(ArithmeticError::Overflow));
+    let s = "0.123456789012345678901234567890123456789";
+    assert_eq!(AncDec128::parse_exact(s).map(|_| ()), Err(ParseError::Inexact));
+    assert_eq!(AncDec128::parse_exact("0.5000000000000000000000000000000000000000").unwrap().scale(), 38);
+    assert_eq!(ArithmeticError::Inexact.to_string(), "inexact result");
+}
+
+// ============ Scale Management ============
+#[test]
+fn test_normalize_trailing_zeros() {
+    let a: AncDec128 = "3.10000000000000000000000000000000000000".parse().unwrap();
+    assert_eq!(a.scale(), 38);
+    assert_eq!(a.trailing_zeros(), 37);
+    assert_eq!(a.normalize().to_string(), "3.1");
+    let b: AncDec128 = "0.00000000000000000000000000000000000001".parse().unwrap();
+    assert_eq!(b.trailing_zeros(), 0);
+    let c: AncDec128 = "5.000".parse().unwrap();
+    assert!(c.is_integer());
+    assert_eq!(c.normalize().scale(), 0);
+}
+
+#[test]
+fn test_rescale() {
+    let a: AncDec128 = "0.125".parse().unwrap();
+    assert_eq!(a.rescale(2, RoundMode::HalfEven).to_string(), "0.12");
+    assert_eq!(a.rescale(2, RoundMode::HalfUp).to_string(), "0.13");
+    assert_eq!(a.rescale(38, RoundMode::HalfUp).scale(), 38);
+    assert_eq!(a.with_scale(38).unwrap().normalize(), a);
+    assert_eq!(a.with_scale(1), None);
+    assert_eq!(a.checked_rescale(39, RoundMode::HalfUp), None);
+}
+
+// ============ Increment Rounding ============
+#[test]
+fn test_round_to_increment() {
+    let nickel: AncDec128 = "0.05".parse().unwrap();
+    let a: AncDec128 = "1234567890123456789012345.025".parse().unwrap();
+    assert_eq!(a.round_to_increment(&nickel, RoundMode::HalfUp).to_string(), "1234567890123456789012345.05");
+    assert_eq!(a.round_to_increment(&nickel, RoundMode::HalfEven).to_string(), "1234567890123456789012345.00");
+    assert_eq!((-a).round_to_increment(&nickel, RoundMode::Floor).to_string(), "-1234567890123456789012345.05");
+    // a 38-digit tick
+    let tick: AncDec128 = "0.00000000000000000000000000000000000005".parse().unwrap();
+    let b: AncDec128 = "1.23456789012345678901234567890123456789".parse().unwrap();
+    assert_eq!(
+        b.round_to_increment(&tick, RoundMode::HalfUp).to_string(),
+        "1.23456789012345678901234567890123456790"
+    );
+    assert_eq!(
+        b.round_to_increment(&tick, RoundMode::Truncate).to_string(),
+        "1.23456789012345678901234567890123456785"
+    );
+    let big: AncDec128 = "10000000000".parse().unwrap();
+    assert_eq!(AncDec128::MAX.checked_round_to_increment(&big, RoundMode::Up), None);
+    assert_eq!(b.checked_round_to_increment(&AncDec128::ZERO, RoundMode::HalfUp), None);
+}
+
+#[test]
+fn test_is_multiple_of() {
+    let tick: AncDec128 = "0.00000000000000000000000000000000000005".parse().unwrap();
+    assert!("1.23456789012345678901234567890123456785".parse::<AncDec128>().unwrap().is_multiple_of(&tick));
+    assert!(!"1.23456789012345678901234567890123456789".parse::<AncDec128>().unwrap().is_multiple_of(&tick));
+    assert!("-1234567890123456789012345.05".parse::<AncDec128>().unwrap().is_multiple_of(&"0.05".parse().unwrap()));
+    assert!(!AncDec128::ONE.is_multiple_of(&AncDec128::ZERO));
+}
+
+// ============ Significant Digits ============
+#[test]
+fn test_digit_introspection() {
+    let a: AncDec128 = "340282366920938463463374607431768211455.5".parse().unwrap();
+    assert_eq!((a.int_digits(), a.leading_frac_zeros(), a.precision()), (39, 0, 40));
+    let b: AncDec128 = "-0.00000000000000000000000000000000000120".parse().unwrap();
+    assert_eq!((b.int_digits(), b.leading_frac_zeros(), b.precision()), (0, 35, 3));
+    assert_eq!(b.mantissa_exponent(), (-12, -37));
+    assert_eq!("100000000000000000000000000000000000000".parse::<AncDec128>().unwrap().mantissa_exponent(), (1, 38));
+    assert_eq!(a.try_mantissa_exponent(), Err(ArithmeticError::Overflow));
+}
+
+#[test]
+fn test_round_sig() {
+    let a: AncDec128 = "12345678901234567890123456789.123456789".parse().unwrap();
+    assert_eq!(a.round_sig(5, RoundMode::HalfUp).to_string(), "12346000000000000000000000000");
+    assert_eq!(a.round_sig(32, RoundMode::HalfEven).to_string(), "12345678901234567890123456789.123");
+    let b: AncDec128 = "0.00000000000000000000000000000123456789".parse().unwrap();
+    assert_eq!(b.round_sig(4, RoundMode::HalfEven).to_string(), "0.000000000000000000000000000001235");
+    assert_eq!("0.999".parse::<AncDec128>().unwrap().round_sig(2, RoundMode::Up).to_string(), "1.0");
+}
+
+// ============ Context ============
+#[test]
+fn test_context_add_mul_div() {
+    let mut ctx = Context::new(20, RoundMode::HalfUp);
+    let a: AncDec128 = "0.000000000000000000005".parse().unwrap();
+    assert_eq!(ctx.add(&a, &AncDec128::ONE).unwrap().to_string(), "1.00000000000000000001");
+    assert_eq!(ctx.flags(), Signal::Rounded | Signal::Inexact);
+    ctx.clear_flags();
+    let q = ctx.div(&AncDec128::ONE, &"1024".parse().unwrap()).unwrap();
+    assert_eq!(q.to_string(), "0.0009765625");
+    assert!(ctx.flags().is_empty());
+    let x: AncDec128 = "1.1".parse().unwrap();
+    assert_eq!(ctx.mul(&x, &x).unwrap().to_string(), "1.21");
+    assert_eq!(ctx.div(&AncDec128::ONE, &"7".parse().unwrap()).unwrap().to_string(), "0.14285714285714285714");
+    assert_eq!(ctx.flags(), Signal::Rounded | Signal::Inexact);
+}
+
+#[test]
+fn test_context_traps() {
+    let mut ctx = Context::default();
+    let big: AncDec128 = "200000000000000000000000000000000000000".parse().unwrap();
+    assert_eq!(ctx.add(&big, &big), Err(Signal::Overflow));
+    assert_eq!(ctx.div(&big, &AncDec128::ZERO), Err(Signal::DivisionByZero));
+    ctx.traps = Signal::Inexact.into();
+    assert_eq!(ctx.sub(&big, &-big), Err(Signal::Inexact));
+    ctx.traps = Signals::EMPTY;
+    assert_eq!(ctx.sub(&-big, &big), Ok(-AncDec128::MAX));
+}
+
+#[test]
+fn test_context_parse_clamped() {
+    let mut ctx = Context::default();
+    let s = "0.123456789012345678901234567890123456789";
+    let a: AncDec128 = ctx.parse(s).unwrap();
+    assert_eq!(a.scale(), 38);
+    assert_eq!(ctx.flags(), Signal::Clamped | Signal::Inexact);
+    ctx.clear_flags();
+    let _: AncDec128 = ctx.parse("1.5").unwrap();
+    assert!(ctx.flags().is_empty());
+}
+
 // ============ Serde (only with feature) ============
 #[cfg(feature = "serde")]
 #[test]
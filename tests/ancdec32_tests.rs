@@ -1,7 +1,9 @@
 // tests/ancdec32_tests.rs
 #![cfg(feature = "dec32")]
+// Reference-operator and float-literal tests are intentional
+#![allow(clippy::op_ref, clippy::approx_constant)]
 
-use ancdec::{AncDec32, RoundMode};
+use ancdec::{ArithmeticError, AncDec32, Context, ParseError, RoundMode, Signal, Signals};
 
 // ============ Parsing ============
 #[test]
@@ -466,6 +468,19 @@ fn test_round_decimal_places() {
     assert_eq!(a.round(3, RoundMode::HalfUp), "3.142".parse().unwrap());
 }
 
+#[test]
+fn test_round_with_residual() {
+    // monthly interest rounded to cents, carrying the leftover forward
+    let interest: AncDec32 = "10.416666667".parse().unwrap();
+    let (paid, carry) = interest.round_with_residual(2, RoundMode::HalfEven);
+    assert_eq!((paid.to_string(), carry.to_string()), ("10.42".into(), "-0.003333333".into()));
+    let (paid2, carry2) = (interest + carry).round_with_residual(2, RoundMode::HalfEven);
+    assert_eq!((paid2.to_string(), carry2.to_string()), ("10.41".into(), "0.003333334".into()));
+    assert_eq!(paid + paid2 + carry2, interest + interest);
+    let (r, res) = "-2.5".parse::<AncDec32>().unwrap().round_with_residual(0, RoundMode::HalfEven);
+    assert_eq!((r.to_string(), res.to_string()), ("-2".into(), "-0.5".into()));
+}
+
 // ============ Floor/Ceil/Trunc/Fract ============
 #[test]
 fn test_floor() {
@@ -530,6 +545,19 @@ fn test_pow_negative() {
     assert_eq!(a.pow(-1), "0.5".parse().unwrap());
 }
 
+#[test]
+fn test_powi_large_exponent() {
+    let two: AncDec32 = "2".parse().unwrap();
+    assert_eq!(two.powi(3), "8".parse().unwrap());
+    assert_eq!(two.checked_powi(i64::MAX), None);
+    assert_eq!(two.try_powi(i64::MAX), Err(ArithmeticError::Overflow));
+    let half: AncDec32 = "0.5".parse().unwrap();
+    assert_eq!(half.checked_powi(i64::MAX), Some(AncDec32::ZERO));
+    assert_eq!((-AncDec32::ONE).powi(i64::MAX), -AncDec32::ONE);
+    assert_eq!(AncDec32::ONE.powi(i64::MIN), AncDec32::ONE);
+    assert_eq!(AncDec32::ZERO.try_powi(-1), Err(ArithmeticError::DivisionByZero));
+}
+
 // ============ Square Root ============
 #[test]
 fn test_sqrt_four() {
@@ -571,12 +599,164 @@ fn test_sqrt_zero() {
 }
 
 #[test]
+#[cfg(not(feature = "panic-free"))]
 #[should_panic(expected = "square root of negative")]
 fn test_sqrt_negative_panics() {
     let neg: AncDec32 = "-4".parse().unwrap();
     neg.sqrt();
 }
 
+#[test]
+fn test_sqrt_round() {
+    let two: AncDec32 = "2".parse().unwrap();
+    assert_eq!(two.sqrt_round(9, RoundMode::HalfUp).to_string(), "1.414213562");
+    assert_eq!(two.sqrt_round(9, RoundMode::Up).to_string(), "1.414213563");
+    let tiny: AncDec32 = "0.000000001".parse().unwrap();
+    assert_eq!(tiny.sqrt_round(9, RoundMode::HalfEven).to_string(), "0.000031623");
+    assert_eq!(tiny.sqrt_round(9, RoundMode::Floor).to_string(), "0.000031622");
+    let four: AncDec32 = "4".parse().unwrap();
+    assert_eq!(four.sqrt_round(3, RoundMode::Up).to_string(), "2.000");
+    assert_eq!((-four).checked_sqrt_round(3, RoundMode::HalfUp), None);
+}
+
+#[test]
+fn test_cbrt_nth_root() {
+    let a: AncDec32 = "27".parse().unwrap();
+    assert_eq!(a.cbrt().to_string(), "3.00000000");
+    let b: AncDec32 = "-8".parse().unwrap();
+    assert_eq!(b.cbrt().to_string(), "-2.00000000");
+    assert_eq!(AncDec32::TWO.nth_root(5).to_string(), "1.14869835");
+    assert_eq!(AncDec32::ONE.nth_root(4_000_000_000), AncDec32::ONE);
+    assert_eq!(AncDec32::ZERO.nth_root(7), AncDec32::ZERO);
+}
+
+#[test]
+fn test_nth_root_domain() {
+    let neg: AncDec32 = "-16".parse().unwrap();
+    assert_eq!(neg.try_nth_root(4), Err(ArithmeticError::NegativeSqrt));
+    assert_eq!(AncDec32::TWO.try_nth_root(0), Err(ArithmeticError::OutOfDomain));
+    assert_eq!(neg.checked_nth_root(4), None);
+}
+
+#[test]
+#[cfg(not(feature = "panic-free"))]
+#[should_panic(expected = "square root of negative")]
+fn test_even_root_negative_panics() {
+    let neg: AncDec32 = "-16".parse().unwrap();
+    neg.nth_root(4);
+}
+
+// ============ Trigonometric and Hyperbolic ============
+#[test]
+fn test_sin_cos_tan() {
+    assert_eq!(AncDec32::ZERO.sin(), AncDec32::ZERO);
+    assert_eq!(AncDec32::ZERO.cos(), AncDec32::ONE);
+    assert_eq!(AncDec32::ONE.sin().to_string(), "0.841470985");
+    assert_eq!((-AncDec32::ONE).sin().to_string(), "-0.841470985");
+    assert_eq!((-AncDec32::ONE).cos().to_string(), "0.540302306");
+    assert_eq!(AncDec32::ONE.tan().to_string(), "1.557407725");
+    // reduced modulo an internal high-precision π
+    let x: AncDec32 = "1000000".parse().unwrap();
+    assert_eq!(x.cos().to_string(), "0.936752128");
+    let near_half_pi: AncDec32 = "1.570796327".parse().unwrap();
+    assert_eq!(near_half_pi.checked_tan(), None);
+    assert_eq!(near_half_pi.try_tan(), Err(ArithmeticError::Overflow));
+}
+
+#[test]
+fn test_inverse_trig() {
+    assert_eq!(AncDec32::ONE.asin().to_string(), "1.570796327");
+    assert_eq!((-AncDec32::ONE).acos().to_string(), "3.141592654");
+    assert_eq!(AncDec32::ONE.acos(), AncDec32::ZERO);
+    assert_eq!(AncDec32::ONE.atan().to_string(), "0.785398163");
+    let half: AncDec32 = "0.5".parse().unwrap();
+    assert_eq!(half.asin().to_string(), "0.523598776");
+    let x: AncDec32 = "1.5".parse().unwrap();
+    assert_eq!(x.checked_asin(), None);
+    assert_eq!((-x).try_acos(), Err(ArithmeticError::OutOfDomain));
+}
+
+#[test]
+fn test_atan2() {
+    assert_eq!(AncDec32::ONE.atan2(&-AncDec32::ONE).to_string(), "2.35619449");
+    assert_eq!((-AncDec32::ONE).atan2(&-AncDec32::ONE).to_string(), "-2.35619449");
+    assert_eq!(AncDec32::ZERO.atan2(&-AncDec32::ONE).to_string(), "3.141592654");
+    assert_eq!(AncDec32::ONE.atan2(&AncDec32::ZERO).to_string(), "1.570796327");
+    assert_eq!(AncDec32::ZERO.atan2(&AncDec32::ZERO), AncDec32::ZERO);
+}
+
+#[test]
+fn test_hyperbolic() {
+    assert_eq!(AncDec32::ZERO.sinh(), AncDec32::ZERO);
+    assert_eq!(AncDec32::ZERO.cosh(), AncDec32::ONE);
+    assert_eq!(AncDec32::ONE.sinh().to_string(), "1.175201194");
+    assert_eq!((-AncDec32::ONE).sinh().to_string(), "-1.175201194");
+    assert_eq!((-AncDec32::ONE).cosh().to_string(), "1.543080635");
+    assert_eq!(AncDec32::ONE.tanh().to_string(), "0.761594156");
+    let big: AncDec32 = "23".parse().unwrap();
+    assert_eq!(big.tanh(), AncDec32::ONE);
+    assert_eq!((-big).tanh(), -AncDec32::ONE);
+    assert_eq!(big.checked_cosh(), None);
+    assert_eq!((-big).try_sinh(), Err(ArithmeticError::Overflow));
+}
+
+#[test]
+#[cfg(not(feature = "panic-free"))]
+#[should_panic(expected = "argument out of domain")]
+fn test_asin_out_of_domain_panics() {
+    "2".parse::<AncDec32>().unwrap().asin();
+}
+
+// ============ Number Theory ============
+#[test]
+fn test_gcd_lcm() {
+    let a: AncDec32 = "0.25".parse().unwrap();
+    let b: AncDec32 = "0.1".parse().unwrap();
+    assert_eq!(a.gcd(&b).to_string(), "0.05");
+    assert_eq!(a.lcm(&b).to_string(), "0.50");
+    assert_eq!((-a).gcd(&b), a.gcd(&b));
+    assert_eq!(a.gcd(&AncDec32::ZERO), a);
+    assert_eq!(a.lcm(&AncDec32::ZERO), AncDec32::ZERO);
+    let x: AncDec32 = "12".parse().unwrap();
+    let y: AncDec32 = "18".parse().unwrap();
+    assert_eq!(x.gcd(&y).to_string(), "6");
+    assert_eq!(x.lcm(&y).to_string(), "36");
+}
+
+#[test]
+fn test_lcm_overflow() {
+    let a: AncDec32 = "4000000000".parse().unwrap();
+    let b = a - AncDec32::ONE;
+    assert_eq!(a.checked_lcm(&b), None);
+    assert_eq!(a.try_lcm(&b), Err(ArithmeticError::Overflow));
+}
+
+#[test]
+fn test_to_fraction() {
+    let a: AncDec32 = "0.375".parse().unwrap();
+    assert_eq!(a.to_fraction(), (3, 8));
+    let b: AncDec32 = "-2.50".parse().unwrap();
+    assert_eq!(b.to_fraction(), (-5, 2));
+    assert_eq!(AncDec32::from(7).to_fraction(), (7, 1));
+    assert_eq!(AncDec32::ZERO.to_fraction(), (0, 1));
+}
+
+#[test]
+fn test_approximate_fraction() {
+    let a: AncDec32 = "101.53125".parse().unwrap();
+    assert_eq!(a.approximate_fraction(32), (3249, 32));
+    let b: AncDec32 = "-2.718281828".parse().unwrap();
+    assert_eq!(b.approximate_fraction(1000), (-1457, 536));
+    // exact when the reduced denominator fits; 0 acts as 1; ties go to the floor's expansion
+    let c: AncDec32 = "1.618034".parse().unwrap();
+    assert_eq!(c.approximate_fraction(100), (144, 89));
+    assert_eq!(c.approximate_fraction(1_000_000), (809017, 500000));
+    assert_eq!(c.approximate_fraction(0), (2, 1));
+    let d: AncDec32 = "1.5".parse().unwrap();
+    assert_eq!(d.approximate_fraction(1), (1, 1));
+    assert_eq!((-d).approximate_fraction(1), (-2, 1));
+}
+
 // ============ Conversion ============
 #[test]
 fn test_to_f64() {
@@ -652,6 +832,38 @@ fn test_constants() {
     assert_eq!(AncDec32::TEN.int(), 10);
 }
 
+#[test]
+fn test_min_epsilon_constants() {
+    assert_eq!(AncDec32::MIN, -AncDec32::MAX);
+    assert_eq!(AncDec32::MIN_POSITIVE.to_string(), "0.000000001");
+    assert_eq!(AncDec32::EPSILON, AncDec32::MIN_POSITIVE);
+    assert!(AncDec32::MIN < AncDec32::ZERO && AncDec32::ZERO < AncDec32::MIN_POSITIVE);
+}
+
+#[test]
+fn test_ulp_next_up_down() {
+    let a: AncDec32 = "-2.25".parse().unwrap();
+    assert_eq!(a.ulp().to_string(), "0.01");
+    assert_eq!(a + a.ulp(), "-2.24".parse().unwrap());
+    assert_eq!(a.next_up().to_string(), "-2.249999999");
+    assert_eq!(a.next_down().to_string(), "-2.250000001");
+    // next_up/next_down step by EPSILON, not by the value's own ulp
+    assert_eq!(a.next_up() - a, AncDec32::EPSILON);
+    assert_eq!(a - a.next_down(), AncDec32::EPSILON);
+    assert_ne!(a.next_up() - a, a.ulp());
+    assert_eq!(AncDec32::MIN.next_up().next_down(), AncDec32::MIN);
+}
+
+#[test]
+fn test_math_constants() {
+    assert_eq!(AncDec32::PI.to_string(), "3.141592654");
+    assert_eq!(AncDec32::PI, (-AncDec32::ONE).acos().round(9, RoundMode::HalfEven));
+    assert_eq!(AncDec32::LN_2.to_string(), "0.693147181");
+    assert_eq!(AncDec32::SQRT_2, AncDec32::TWO.sqrt_round(9, RoundMode::HalfEven));
+    let half: AncDec32 = "0.5".parse().unwrap();
+    assert_eq!(AncDec32::FRAC_1_SQRT_2, half.sqrt_round(9, RoundMode::HalfEven));
+}
+
 // ============ Edge Cases ============
 #[test]
 fn test_zero_operations() {
@@ -689,6 +901,7 @@ fn test_large_numbers() {
 }
 
 #[test]
+#[cfg(not(feature = "panic-free"))]
 #[should_panic]
 fn test_div_by_zero_panics() {
     let a: AncDec32 = "5".parse().unwrap();
@@ -744,6 +957,468 @@ fn test_from_ancdec8() {
     assert_eq!(b.scale(), 2);
 }
 
+// ============ Checked Arithmetic ============
+#[test]
+fn test_checked_div() {
+    let a: AncDec32 = "10".parse().unwrap();
+    let b: AncDec32 = "4".parse().unwrap();
+    assert_eq!(a.checked_div(&b), Some("2.5".parse().unwrap()));
+    assert_eq!(a.checked_div(&AncDec32::ZERO), None);
+    let tiny: AncDec32 = "0.000000001".parse().unwrap();
+    assert_eq!(AncDec32::MAX.checked_div(&tiny), None);
+}
+
+#[test]
+fn test_checked_rem() {
+    let a: AncDec32 = "10.5".parse().unwrap();
+    let b: AncDec32 = "3".parse().unwrap();
+    assert_eq!(a.checked_rem(&b), Some("1.5".parse().unwrap()));
+    assert_eq!(a.checked_rem(&AncDec32::ZERO), None);
+}
+
+#[test]
+fn test_checked_pow() {
+    assert_eq!(AncDec32::TWO.checked_pow(10), Some(AncDec32::from(1024u32)));
+    assert_eq!(AncDec32::TWO.checked_pow(-1), Some("0.5".parse().unwrap()));
+    assert_eq!(AncDec32::TWO.checked_pow(32), None);
+    assert_eq!(AncDec32::TWO.checked_pow(31), Some(AncDec32::from(1u32 << 31)));
+    assert_eq!(AncDec32::ZERO.checked_pow(-1), None);
+}
+
+#[test]
+fn test_checked_mul_reduced_overflow() {
+    // product exceeds u64 after scale reduction
+    assert_eq!(AncDec32::MAX.checked_mul(&AncDec32::MAX), None);
+}
+
+#[test]
+fn test_checked_sqrt_and_neg() {
+    let a: AncDec32 = "4".parse().unwrap();
+    assert_eq!(a.checked_sqrt(), Some(AncDec32::TWO));
+    assert_eq!((-a).checked_sqrt(), None);
+    assert_eq!(a.checked_neg(), Some(-a));
+}
+
+// ============ Saturating / Overflowing ============
+#[test]
+fn test_saturating_add_sub() {
+    let one = AncDec32::ONE;
+    assert_eq!(AncDec32::MAX.saturating_add(&one), AncDec32::MAX);
+    assert_eq!((-AncDec32::MAX).saturating_add(&-one), -AncDec32::MAX);
+    assert_eq!((-AncDec32::MAX).saturating_sub(&one), -AncDec32::MAX);
+    assert_eq!(AncDec32::MAX.saturating_sub(&-one), AncDec32::MAX);
+    assert_eq!(one.saturating_add(&one), AncDec32::TWO);
+}
+
+#[test]
+fn test_checked_sub_opposite_signs_overflow() {
+    assert_eq!(AncDec32::MAX.checked_sub(&-AncDec32::ONE), None);
+}
+
+#[test]
+fn test_saturating_mul_div() {
+    let tiny: AncDec32 = "0.000000001".parse().unwrap();
+    assert_eq!(AncDec32::MAX.saturating_mul(&AncDec32::TWO), AncDec32::MAX);
+    assert_eq!(AncDec32::MAX.saturating_mul(&-AncDec32::TWO), -AncDec32::MAX);
+    assert_eq!((-AncDec32::MAX).saturating_div(&tiny), -AncDec32::MAX);
+    assert_eq!(AncDec32::ONE.saturating_div(&AncDec32::ZERO), AncDec32::MAX);
+    assert_eq!(AncDec32::ZERO.saturating_div(&AncDec32::ZERO), AncDec32::ZERO);
+}
+
+#[test]
+fn test_saturating_pow() {
+    assert_eq!(AncDec32::TEN.saturating_pow(11), AncDec32::MAX);
+    assert_eq!((-AncDec32::TEN).saturating_pow(11), -AncDec32::MAX);
+    assert_eq!((-AncDec32::TEN).saturating_pow(12), AncDec32::MAX);
+    assert_eq!(AncDec32::ZERO.saturating_pow(-1), AncDec32::MAX);
+    assert_eq!(AncDec32::TWO.saturating_pow(2), "4".parse().unwrap());
+}
+
+#[test]
+fn test_overflowing_ops() {
+    let two = AncDec32::TWO;
+    assert_eq!(two.overflowing_add(&two), ("4".parse().unwrap(), false));
+    assert_eq!(AncDec32::MAX.overflowing_add(&two), (AncDec32::MAX, true));
+    assert_eq!(AncDec32::MAX.overflowing_sub(&-two), (AncDec32::MAX, true));
+    assert_eq!(AncDec32::MAX.overflowing_mul(&two), (AncDec32::MAX, true));
+    assert_eq!(two.overflowing_div(&AncDec32::ZERO), (AncDec32::MAX, true));
+    assert_eq!(two.overflowing_pow(2), ("4".parse().unwrap(), false));
+    assert_eq!(two.overflowing_pow(-1), ("0.5".parse().unwrap(), false));
+}
+
+// ============ Try Operations ============
+#[test]
+fn test_try_arithmetic() {
+    let a: AncDec32 = "1.5".parse().unwrap();
+    assert_eq!(a.try_add(&a), Ok(AncDec32::from(3)));
+    assert_eq!(a.try_sub(&a), Ok(AncDec32::ZERO));
+    assert_eq!(AncDec32::TWO.try_mul(&a), Ok(AncDec32::from(3)));
+    assert_eq!(a.try_div(&a), Ok(AncDec32::ONE));
+    assert_eq!(AncDec32::from(7).try_rem(&AncDec32::TWO), Ok(AncDec32::ONE));
+}
+
+#[test]
+fn test_try_arithmetic_errors() {
+    assert_eq!(AncDec32::MAX.try_add(&AncDec32::ONE), Err(ArithmeticError::Overflow));
+    assert_eq!((-AncDec32::MAX).try_sub(&AncDec32::ONE), Err(ArithmeticError::Overflow));
+    assert_eq!(AncDec32::MAX.try_mul(&AncDec32::TWO), Err(ArithmeticError::Overflow));
+    assert_eq!(AncDec32::ONE.try_div(&AncDec32::ZERO), Err(ArithmeticError::DivisionByZero));
+    assert_eq!(AncDec32::ONE.try_rem(&AncDec32::ZERO), Err(ArithmeticError::DivisionByZero));
+}
+
+#[test]
+fn test_try_pow_sqrt() {
+    assert_eq!(AncDec32::TWO.try_pow(3), Ok(AncDec32::from(8)));
+    assert_eq!(AncDec32::ZERO.try_pow(-1), Err(ArithmeticError::DivisionByZero));
+    assert_eq!(AncDec32::MAX.try_pow(2), Err(ArithmeticError::Overflow));
+    assert_eq!(AncDec32::from(4).try_sqrt().unwrap(), AncDec32::TWO);
+    assert_eq!((-AncDec32::ONE).try_sqrt(), Err(ArithmeticError::NegativeSqrt));
+}
+
+#[test]
+fn test_try_new() {
+    assert_eq!(AncDec32::try_new(1, 5, 1, false), Ok(AncDec32::new(1, 5, 1, false)));
+    assert_eq!(AncDec32::try_new(1, 0, 10, false), Err(ArithmeticError::ScaleOverflow));
+    assert_eq!(AncDec32::try_new(1, 10, 1, false), Err(ArithmeticError::ScaleOverflow));
+}
+
+#[test]
+fn test_arithmetic_error_display() {
+    assert_eq!(ArithmeticError::DivisionByZero.to_string(), "division by zero");
+    assert_eq!(ArithmeticError::Overflow.to_string(), "arithmetic overflow");
+}
+
+#[test]
+#[cfg(feature = "panic-free")]
+fn test_panic_free_saturates() {
+    assert_eq!(AncDec32::MAX + AncDec32::ONE, AncDec32::MAX);
+    assert_eq!(AncDec32::MAX * -AncDec32::TWO, -AncDec32::MAX);
+    assert_eq!(AncDec32::ONE / AncDec32::ZERO, AncDec32::MAX);
+    assert_eq!(AncDec32::ONE % AncDec32::ZERO, AncDec32::ZERO);
+    assert_eq!((-AncDec32::ONE).sqrt(), AncDec32::ZERO);
+}
+
+#[test]
+fn test_round_up_half_odd_05up() {
+    let a: AncDec32 = "1.000000001".parse().unwrap();
+    assert_eq!(a.round(0, RoundMode::Up).to_string(), "2");
+    assert_eq!(AncDec32::ONE.round(0, RoundMode::Up).to_string(), "1");
+    let b: AncDec32 = "0.125".parse().unwrap();
+    assert_eq!(b.round(2, RoundMode::HalfOdd).to_string(), "0.13");
+    assert_eq!((-b).round(2, RoundMode::HalfOdd).to_string(), "-0.13");
+    assert_eq!(b.round(1, RoundMode::ZeroFiveUp).to_string(), "0.1");
+    assert_eq!(b.mul_round(&AncDec32::TWO, 1, RoundMode::ZeroFiveUp).to_string(), "0.2");
+}
+
+/// xorshift64: deterministic RNG for the stochastic rounding tests
+#[cfg(feature = "rand")]
+struct XorShift(u64);
+
+#[cfg(feature = "rand")]
+impl rand_core::RngCore for XorShift {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        rand_core::impls::fill_bytes_via_next(self, dst)
+    }
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_round_stochastic() {
+    let mut rng = XorShift(0x9E37_79B9_7F4A_7C15);
+    let a: AncDec32 = "0.125".parse().unwrap();
+    let (lo, hi): (AncDec32, AncDec32) = ("0.12".parse().unwrap(), "0.13".parse().unwrap());
+    let mut ups = 0;
+    for _ in 0..4000 {
+        let r = a.round_stochastic(2, &mut rng);
+        assert!(r == lo || r == hi);
+        ups += (r == hi) as u32;
+    }
+    // rounds up half of the time; the bounds are about 4.5 standard deviations
+    assert!((1850..=2150).contains(&ups), "{}", ups);
+    // nothing discarded: never rounds
+    let b: AncDec32 = "0.120".parse().unwrap();
+    assert_eq!(b.round_stochastic(2, &mut rng), b.round(2, RoundMode::Truncate));
+}
+
+// ============ Rounded Arithmetic ============
+#[test]
+fn test_mul_round() {
+    let a: AncDec32 = "1.25".parse().unwrap();
+    let b: AncDec32 = "1.5".parse().unwrap();
+    assert_eq!(a.mul_round(&b, 2, RoundMode::HalfEven).to_string(), "1.88");
+    assert_eq!(a.mul_round(&b, 2, RoundMode::HalfDown).to_string(), "1.87");
+    assert_eq!((-a).mul_round(&b, 1, RoundMode::Floor).to_string(), "-1.9");
+    // exact products keep their scale
+    assert_eq!(b.mul_round(&b, 4, RoundMode::HalfUp).to_string(), "2.25");
+}
+
+#[test]
+fn test_mul_round_half_below_target() {
+    // 0.00005 * 0.00001 = 0.0000000005, exactly half a unit at 9 places
+    let a: AncDec32 = "0.00005".parse().unwrap();
+    let b: AncDec32 = "0.00001".parse().unwrap();
+    assert_eq!(a.mul_round(&b, 9, RoundMode::HalfUp).to_string(), "0.000000001");
+    assert_eq!(a.mul_round(&b, 9, RoundMode::HalfEven), AncDec32::ZERO);
+}
+
+#[test]
+fn test_div_round() {
+    let two = AncDec32::TWO;
+    let three = AncDec32::from(3);
+    assert_eq!(two.div_round(&three, 4, RoundMode::HalfEven).to_string(), "0.6667");
+    assert_eq!(AncDec32::ONE.div_round(&three, 2, RoundMode::Ceil).to_string(), "0.34");
+    assert_eq!((-AncDec32::ONE).div_round(&three, 2, RoundMode::Floor).to_string(), "-0.34");
+    let x: AncDec32 = "2.500000001".parse().unwrap();
+    assert_eq!(x.div_round(&AncDec32::ONE, 0, RoundMode::HalfEven).to_string(), "3");
+}
+
+#[test]
+fn test_checked_mul_div_round() {
+    let big = AncDec32::from(u32::MAX);
+    assert_eq!(big.checked_mul_round(&AncDec32::TWO, 2, RoundMode::HalfUp), None);
+    assert_eq!(big.checked_div_round(&AncDec32::ZERO, 2, RoundMode::HalfUp), None);
+    assert_eq!(
+        AncDec32::ONE.checked_div_round(&AncDec32::TWO, 0, RoundMode::HalfUp),
+        Some(AncDec32::ONE)
+    );
+}
+
+#[test]
+#[cfg(not(feature = "panic-free"))]
+#[should_panic(expected = "division by zero")]
+fn test_div_round_by_zero() {
+    AncDec32::ONE.div_round(&AncDec32::ZERO, 2, RoundMode::HalfUp);
+}
+
+// ============ Integer Division ============
+#[test]
+fn test_divmod() {
+    let a: AncDec32 = "7.5".parse().unwrap();
+    let b = AncDec32::TWO;
+    let (q, r) = a.divmod(&b);
+    assert_eq!(q, AncDec32::from(3));
+    assert_eq!(r.to_string(), "1.5");
+    let (q, r) = (-a).divmod(&b);
+    assert_eq!(q.to_string(), "-3");
+    assert_eq!(r.to_string(), "-1.5");
+    assert_eq!(a.checked_divmod(&AncDec32::ZERO), None);
+}
+
+#[test]
+fn test_div_mod_floor() {
+    let a: AncDec32 = "-7.5".parse().unwrap();
+    let b = AncDec32::TWO;
+    assert_eq!(a.div_floor(&b).to_string(), "-4");
+    assert_eq!(a.mod_floor(&b).to_string(), "0.5");
+    assert_eq!((-a).mod_floor(&-b).to_string(), "-0.5");
+    assert_eq!(b.checked_mod_floor(&AncDec32::ZERO), None);
+}
+
+#[test]
+fn test_div_rem_euclid() {
+    let a: AncDec32 = "-7.5".parse().unwrap();
+    let b = AncDec32::TWO;
+    assert_eq!(a.div_euclid(&b).to_string(), "-4");
+    assert_eq!(a.rem_euclid(&b).to_string(), "0.5");
+    assert_eq!(a.div_euclid(&-b).to_string(), "4");
+    assert_eq!(a.rem_euclid(&-b).to_string(), "0.5");
+    // exact division leaves no adjustment
+    assert_eq!(AncDec32::from(6).rem_euclid(&-b), AncDec32::ZERO);
+}
+
+#[test]
+fn test_int_division_i128() {
+    let a: AncDec32 = "-7.5".parse().unwrap();
+    let b = AncDec32::TWO;
+    assert_eq!(a.divmod_i128(&b), Some((-3, "-1.5".parse().unwrap())));
+    assert_eq!(a.div_floor_i128(&b), Some(-4));
+    assert_eq!(a.div_euclid_i128(&-b), Some(4));
+    assert_eq!(a.div_floor_i128(&AncDec32::ZERO), None);
+}
+
+#[test]
+fn test_rem_large_quotient() {
+    // the quotient overflows, but the remainder is exact
+    let a = AncDec32::from(u32::MAX);
+    let b: AncDec32 = "0.000000007".parse().unwrap();
+    assert_eq!(a.checked_div_floor(&b), None);
+    assert_eq!(a.div_floor_i128(&b), Some(613566756428571428));
+    assert_eq!((a % b).to_string(), "0.000000004");
+    assert_eq!((-a).rem_euclid(&b).to_string(), "0.000000003");
+}
+
+// ============ Minimal-scale Division ============
+#[test]
+fn test_div_min_scale_terminating() {
+    let one = AncDec32::ONE;
+    let q = one.div_min_scale(&AncDec32::TWO, 0, RoundMode::HalfEven);
+    assert_eq!(q.to_string(), "0.5");
+    assert_eq!(q.scale(), 1);
+    let ten: AncDec32 = "10".parse().unwrap();
+    assert_eq!(ten.div_min_scale(&"4".parse().unwrap(), 0, RoundMode::HalfEven).to_string(), "2.5");
+    let six: AncDec32 = "6".parse().unwrap();
+    assert_eq!(six.div_min_scale(&"0.3".parse().unwrap(), 0, RoundMode::HalfEven).to_string(), "20");
+    let eight: AncDec32 = "8".parse().unwrap();
+    assert_eq!(one.div_min_scale(&eight, 2, RoundMode::HalfEven).to_string(), "0.125");
+}
+
+#[test]
+fn test_div_min_scale_non_terminating() {
+    let three: AncDec32 = "3".parse().unwrap();
+    assert_eq!(AncDec32::ONE.div_min_scale(&three, 1, RoundMode::HalfUp).to_string(), "0.3");
+    assert_eq!(AncDec32::TWO.div_min_scale(&three, 2, RoundMode::HalfEven).to_string(), "0.67");
+    assert_eq!((-AncDec32::TWO).div_min_scale(&three, 1, RoundMode::Floor).to_string(), "-0.7");
+    assert_eq!(AncDec32::ONE.checked_div_min_scale(&AncDec32::ZERO, 2, RoundMode::HalfUp), None);
+}
+
+// ============ Exact Arithmetic ============
+#[test]
+fn test_mul_div_exact() {
+    let a: AncDec32 = "0.00001".parse().unwrap();
+    assert_eq!(a.mul_exact(&"0.0001".parse().unwrap()).unwrap().to_string(), "0.000000001");
+    assert_eq!(a.mul_exact(&a), Err(ArithmeticError::Inexact));
+    let q = AncDec32::ONE.div_exact(&"1024".parse().unwrap());
+    assert_eq!(q, Err(ArithmeticError::Inexact));
+    assert_eq!(AncDec32::ONE.div_exact(&"512".parse().unwrap()).unwrap().to_string(), "0.001953125");
+    assert_eq!(AncDec32::TWO.div_exact(&"0.5".parse().unwrap()).unwrap().to_string(), "4");
+    assert_eq!(AncDec32::ONE.div_exact(&AncDec32::ZERO), Err(ArithmeticError::DivisionByZero));
+}
+
+#[test]
+fn test_add_parse_exact() {
+    assert_eq!(AncDec32::MAX.add_exact(&AncDec32::MAX), Err(ArithmeticError::Overflow));
+    assert_eq!(AncDec32::parse_exact("0.1234567891"), Err(ParseError::Inexact));
+    assert_eq!(AncDec32::parse_exact("0.1234567890").unwrap().to_string(), "0.123456789");
+}
+
+// ============ Scale Management ============
+#[test]
+fn test_normalize_trailing_zeros() {
+    let a: AncDec32 = "12.300000000".parse().unwrap();
+    assert_eq!(a.trailing_zeros(), 8);
+    assert_eq!(a.normalize().to_string(), "12.3");
+    assert_eq!(a.normalize().scale(), 1);
+    let b: AncDec32 = "0.000000001".parse().unwrap();
+    assert_eq!(b.trailing_zeros(), 0);
+    assert_eq!(AncDec32::TEN.trailing_zeros(), 0);
+    assert!(AncDec32::TEN.is_integer());
+    assert_eq!(AncDec32::TEN.normalize(), AncDec32::TEN);
+}
+
+#[test]
+fn test_rescale() {
+    let a: AncDec32 = "2.675".parse().unwrap();
+    assert_eq!(a.rescale(2, RoundMode::HalfEven).to_string(), "2.68");
+    assert_eq!(a.rescale(2, RoundMode::Truncate).to_string(), "2.67");
+    assert_eq!(a.rescale(9, RoundMode::Truncate).to_string(), "2.675000000");
+    assert_eq!(a.with_scale(9).unwrap().with_scale(3), Some(a));
+    assert_eq!(a.with_scale(2), None);
+    assert_eq!(a.checked_rescale(10, RoundMode::HalfUp), None);
+}
+
+// ============ Increment Rounding ============
+#[test]
+fn test_round_to_increment() {
+    let nickel: AncDec32 = "0.05".parse().unwrap();
+    let a: AncDec32 = "12.345".parse().unwrap();
+    assert_eq!(a.round_to_increment(&nickel, RoundMode::HalfUp).to_string(), "12.35");
+    assert_eq!(a.round_to_increment(&nickel, RoundMode::Truncate).to_string(), "12.30");
+    let tick: AncDec32 = "0.005".parse().unwrap();
+    let b: AncDec32 = "-1.2349".parse().unwrap();
+    assert_eq!(b.round_to_increment(&tick, RoundMode::Ceil).to_string(), "-1.230");
+    assert_eq!(b.round_to_increment(&tick, RoundMode::Floor).to_string(), "-1.235");
+    // ties to an even multiple of the tick
+    let t1: AncDec32 = "0.0075".parse().unwrap();
+    let t2: AncDec32 = "0.0125".parse().unwrap();
+    assert_eq!(t1.round_to_increment(&tick, RoundMode::HalfEven).to_string(), "0.010");
+    assert_eq!(t2.round_to_increment(&tick, RoundMode::HalfEven).to_string(), "0.010");
+    assert_eq!(t2.round_to_increment(&tick, RoundMode::HalfUp).to_string(), "0.015");
+    let ten: AncDec32 = "10".parse().unwrap();
+    assert_eq!(AncDec32::MAX.checked_round_to_increment(&ten, RoundMode::Up), None);
+    assert_eq!(a.checked_round_to_increment(&AncDec32::ZERO, RoundMode::HalfUp), None);
+}
+
+#[test]
+fn test_is_multiple_of() {
+    let tick: AncDec32 = "0.005".parse().unwrap();
+    assert!("1.235".parse::<AncDec32>().unwrap().is_multiple_of(&tick));
+    assert!(!"1.2351".parse::<AncDec32>().unwrap().is_multiple_of(&tick));
+    assert!("-12".parse::<AncDec32>().unwrap().is_multiple_of(&"1.5".parse().unwrap()));
+    assert!(!AncDec32::ONE.is_multiple_of(&AncDec32::ZERO));
+}
+
+// ============ Significant Digits ============
+#[test]
+fn test_digit_introspection() {
+    let a: AncDec32 = "4294967295.999999999".parse().unwrap();
+    assert_eq!((a.int_digits(), a.leading_frac_zeros(), a.precision()), (10, 0, 19));
+    let b: AncDec32 = "-0.001230".parse().unwrap();
+    assert_eq!((b.int_digits(), b.leading_frac_zeros(), b.precision()), (0, 2, 4));
+    assert_eq!(b.mantissa_exponent(), (-123, -5));
+    assert_eq!(a.mantissa_exponent(), (4294967295999999999, -9));
+    assert_eq!("1200".parse::<AncDec32>().unwrap().mantissa_exponent(), (12, 2));
+}
+
+#[test]
+fn test_round_sig() {
+    let a: AncDec32 = "1234.5678".parse().unwrap();
+    assert_eq!(a.round_sig(2, RoundMode::HalfUp).to_string(), "1200");
+    assert_eq!(a.round_sig(6, RoundMode::HalfUp).to_string(), "1234.57");
+    assert_eq!(a.round_sig(6, RoundMode::Fract).to_string(), "1234.56");
+    let b: AncDec32 = "0.012345".parse().unwrap();
+    assert_eq!(b.round_sig(3, RoundMode::HalfEven).to_string(), "0.0123");
+    assert_eq!((-b).round_sig(1, RoundMode::Floor).to_string(), "-0.02");
+    assert_eq!("0.0996".parse::<AncDec32>().unwrap().round_sig(2, RoundMode::HalfUp).to_string(), "0.10");
+}
+
+// ============ Context ============
+#[test]
+fn test_context_add_mul_div() {
+    let mut ctx = Context::new(4, RoundMode::HalfUp);
+    let a: AncDec32 = "1.00005".parse().unwrap();
+    assert_eq!(ctx.add(&a, &AncDec32::ONE).unwrap().to_string(), "2.0001");
+    assert_eq!(ctx.flags(), Signal::Rounded | Signal::Inexact);
+    ctx.clear_flags();
+    let b: AncDec32 = "0.25".parse().unwrap();
+    assert_eq!(ctx.mul(&b, &b).unwrap().to_string(), "0.0625");
+    assert_eq!(ctx.div(&AncDec32::ONE, &"8".parse().unwrap()).unwrap().to_string(), "0.125");
+    assert!(ctx.flags().is_empty());
+    assert_eq!(ctx.div(&AncDec32::TWO, &"3".parse().unwrap()).unwrap().to_string(), "0.6667");
+    assert!(ctx.flags().contains(Signal::Inexact));
+}
+
+#[test]
+fn test_context_traps() {
+    let mut ctx = Context::default();
+    let big: AncDec32 = "3000000000".parse().unwrap();
+    assert_eq!(ctx.add(&big, &big), Err(Signal::Overflow));
+    assert_eq!(ctx.div(&big, &AncDec32::ZERO), Err(Signal::DivisionByZero));
+    ctx.traps = Signals::EMPTY;
+    assert_eq!(ctx.sub(&-big, &big), Ok(-AncDec32::MAX));
+    assert_eq!(ctx.div(&big, &AncDec32::ZERO), Ok(AncDec32::MAX));
+}
+
+#[test]
+fn test_context_parse_clamped() {
+    let mut ctx = Context::default();
+    let a: AncDec32 = ctx.parse("0.1234567891").unwrap();
+    assert_eq!(a.to_string(), "0.123456789");
+    assert_eq!(ctx.flags(), Signal::Clamped | Signal::Inexact);
+
+    let mut ctx = Context::new(2, RoundMode::HalfEven).with_traps(Signal::Rounded.into());
+    assert_eq!(ctx.parse::<AncDec32>("1.5"), Ok("1.5".parse().unwrap()));
+    assert_eq!(ctx.parse::<AncDec32>("1.500"), Err(ParseError::Trapped(Signal::Rounded)));
+}
+
 // ============ Serde ============
 #[cfg(feature = "serde")]
 #[test]
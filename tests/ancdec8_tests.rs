@@ -1,7 +1,9 @@
 // tests/ancdec8_tests.rs
 #![cfg(feature = "dec8")]
+// Reference-operator and float-literal tests are intentional
+#![allow(clippy::op_ref, clippy::approx_constant)]
 
-use ancdec::{AncDec8, RoundMode};
+use ancdec::{ArithmeticError, AncDec8, Context, ParseError, RoundMode, Signal, Signals};
 
 // ============ Parsing ============
 #[test]
@@ -415,6 +417,17 @@ fn test_round_decimal_places() {
     assert_eq!(a.round(1, RoundMode::HalfUp), "1.3".parse().unwrap());
 }
 
+#[test]
+fn test_round_with_residual() {
+    let a: AncDec8 = "1.27".parse().unwrap();
+    let (r, res) = a.round_with_residual(1, RoundMode::HalfUp);
+    assert_eq!((r.to_string(), res.to_string()), ("1.3".into(), "-0.03".into()));
+    assert_eq!(r + res, a);
+    let (r, res) = (-a).round_with_residual(0, RoundMode::Truncate);
+    assert_eq!((r.to_string(), res.to_string()), ("-1".into(), "-0.27".into()));
+    assert_eq!(a.round_with_residual(2, RoundMode::HalfUp), (a, AncDec8::ZERO));
+}
+
 // ============ Floor/Ceil/Trunc/Fract ============
 #[test]
 fn test_floor() {
@@ -479,6 +492,19 @@ fn test_pow_negative() {
     assert_eq!(a.pow(-1), "0.5".parse().unwrap());
 }
 
+#[test]
+fn test_powi_large_exponent() {
+    let two: AncDec8 = "2".parse().unwrap();
+    assert_eq!(two.powi(3), "8".parse().unwrap());
+    assert_eq!(two.checked_powi(i64::MAX), None);
+    assert_eq!(two.try_powi(i64::MAX), Err(ArithmeticError::Overflow));
+    let half: AncDec8 = "0.5".parse().unwrap();
+    assert_eq!(half.checked_powi(i64::MAX), Some(AncDec8::ZERO));
+    assert_eq!((-AncDec8::ONE).powi(i64::MAX), -AncDec8::ONE);
+    assert_eq!(AncDec8::ONE.powi(i64::MIN), AncDec8::ONE);
+    assert_eq!(AncDec8::ZERO.try_powi(-1), Err(ArithmeticError::DivisionByZero));
+}
+
 // ============ Square Root ============
 #[test]
 fn test_sqrt_four() {
@@ -502,12 +528,104 @@ fn test_sqrt_zero() {
 }
 
 #[test]
+#[cfg(not(feature = "panic-free"))]
 #[should_panic(expected = "square root of negative")]
 fn test_sqrt_negative_panics() {
     let neg: AncDec8 = "-4".parse().unwrap();
     neg.sqrt();
 }
 
+#[test]
+fn test_sqrt_round() {
+    let two = AncDec8::TWO;
+    assert_eq!(two.sqrt_round(2, RoundMode::HalfUp).to_string(), "1.41");
+    assert_eq!(two.sqrt_round(2, RoundMode::Ceil).to_string(), "1.42");
+    assert_eq!(two.sqrt_round(5, RoundMode::HalfUp).to_string(), "1.41");
+    // sqrt(6.25) = 2.5 exactly: a true tie at 0 places
+    let a: AncDec8 = "6.25".parse().unwrap();
+    assert_eq!(a.sqrt_round(0, RoundMode::HalfEven).to_string(), "2");
+    assert_eq!(a.sqrt_round(0, RoundMode::HalfUp).to_string(), "3");
+    assert_eq!(a.sqrt_round(2, RoundMode::Up).to_string(), "2.50");
+    assert_eq!(AncDec8::MAX.sqrt_round(2, RoundMode::HalfUp).to_string(), "16.00");
+    assert_eq!((-two).checked_sqrt_round(2, RoundMode::HalfUp), None);
+}
+
+#[test]
+fn test_cbrt_nth_root() {
+    let a: AncDec8 = "27".parse().unwrap();
+    assert_eq!(a.cbrt().to_string(), "3.0");
+    let b: AncDec8 = "-8".parse().unwrap();
+    assert_eq!(b.cbrt().to_string(), "-2.0");
+    assert_eq!(AncDec8::TWO.nth_root(5).to_string(), "1.1");
+    assert_eq!(AncDec8::ONE.nth_root(4_000_000_000), AncDec8::ONE);
+    assert_eq!(AncDec8::ZERO.nth_root(7), AncDec8::ZERO);
+}
+
+#[test]
+fn test_nth_root_domain() {
+    let neg: AncDec8 = "-16".parse().unwrap();
+    assert_eq!(neg.try_nth_root(4), Err(ArithmeticError::NegativeSqrt));
+    assert_eq!(AncDec8::TWO.try_nth_root(0), Err(ArithmeticError::OutOfDomain));
+    assert_eq!(neg.checked_nth_root(4), None);
+}
+
+#[test]
+#[cfg(not(feature = "panic-free"))]
+#[should_panic(expected = "square root of negative")]
+fn test_even_root_negative_panics() {
+    let neg: AncDec8 = "-16".parse().unwrap();
+    neg.nth_root(4);
+}
+
+// ============ Number Theory ============
+#[test]
+fn test_gcd_lcm() {
+    let a: AncDec8 = "0.25".parse().unwrap();
+    let b: AncDec8 = "0.1".parse().unwrap();
+    assert_eq!(a.gcd(&b).to_string(), "0.05");
+    assert_eq!(a.lcm(&b).to_string(), "0.50");
+    assert_eq!((-a).gcd(&b), a.gcd(&b));
+    assert_eq!(a.gcd(&AncDec8::ZERO), a);
+    assert_eq!(a.lcm(&AncDec8::ZERO), AncDec8::ZERO);
+    let x: AncDec8 = "12".parse().unwrap();
+    let y: AncDec8 = "18".parse().unwrap();
+    assert_eq!(x.gcd(&y).to_string(), "6");
+    assert_eq!(x.lcm(&y).to_string(), "36");
+}
+
+#[test]
+fn test_lcm_overflow() {
+    let a: AncDec8 = "200".parse().unwrap();
+    let b = a - AncDec8::ONE;
+    assert_eq!(a.checked_lcm(&b), None);
+    assert_eq!(a.try_lcm(&b), Err(ArithmeticError::Overflow));
+}
+
+#[test]
+fn test_to_fraction() {
+    let a: AncDec8 = "0.75".parse().unwrap();
+    assert_eq!(a.to_fraction(), (3, 4));
+    let b: AncDec8 = "-2.50".parse().unwrap();
+    assert_eq!(b.to_fraction(), (-5, 2));
+    assert_eq!(AncDec8::from(7u8).to_fraction(), (7, 1));
+    assert_eq!(AncDec8::ZERO.to_fraction(), (0, 1));
+}
+
+#[test]
+fn test_approximate_fraction() {
+    let a: AncDec8 = "2.71".parse().unwrap();
+    assert_eq!(a.approximate_fraction(10), (19, 7));
+    let b: AncDec8 = "-0.67".parse().unwrap();
+    assert_eq!(b.approximate_fraction(10), (-2, 3));
+    // exact when the reduced denominator fits; 0 acts as 1; ties go to the floor's expansion
+    let c: AncDec8 = "0.25".parse().unwrap();
+    assert_eq!(c.approximate_fraction(4), (1, 4));
+    assert_eq!(c.approximate_fraction(0), (0, 1));
+    let d: AncDec8 = "1.5".parse().unwrap();
+    assert_eq!(d.approximate_fraction(1), (1, 1));
+    assert_eq!((-d).approximate_fraction(1), (-2, 1));
+}
+
 // ============ Conversion ============
 #[test]
 fn test_to_f64() {
@@ -583,6 +701,47 @@ fn test_constants() {
     assert_eq!(AncDec8::TEN.int(), 10);
 }
 
+#[test]
+fn test_min_epsilon_constants() {
+    assert_eq!(AncDec8::MIN, -AncDec8::MAX);
+    assert_eq!(AncDec8::MIN.to_string(), "-255.99");
+    assert_eq!(AncDec8::MIN_POSITIVE.to_string(), "0.01");
+    assert_eq!(AncDec8::ONE.next_up() - AncDec8::ONE, AncDec8::EPSILON);
+}
+
+#[test]
+fn test_ulp_next_up_down() {
+    let a: AncDec8 = "1.5".parse().unwrap();
+    assert_eq!(a.ulp().to_string(), "0.1");
+    assert_eq!(AncDec8::TEN.ulp(), AncDec8::ONE);
+    assert_eq!(a.next_up().to_string(), "1.51");
+    assert_eq!(a.next_down().to_string(), "1.49");
+    // next_up/next_down step by EPSILON, not by the value's own ulp
+    assert_eq!(a.next_up() - a, AncDec8::EPSILON);
+    assert_eq!(a - a.next_down(), AncDec8::EPSILON);
+    assert_ne!(a.next_up() - a, a.ulp());
+    assert_eq!(AncDec8::ZERO.next_down(), -AncDec8::MIN_POSITIVE);
+    assert!(AncDec8::MIN_POSITIVE.next_down().is_zero());
+    assert_eq!(AncDec8::MAX.next_down().next_up(), AncDec8::MAX);
+}
+
+#[test]
+#[cfg(not(feature = "panic-free"))]
+#[should_panic]
+fn test_next_up_max_panics() {
+    let _ = AncDec8::MAX.next_up();
+}
+
+#[test]
+fn test_math_constants() {
+    assert_eq!(AncDec8::PI.to_string(), "3.14");
+    assert_eq!(AncDec8::E.to_string(), "2.72");
+    assert_eq!(AncDec8::LN_10.to_string(), "2.30");
+    assert_eq!(AncDec8::SQRT_2, AncDec8::TWO.sqrt_round(2, RoundMode::HalfEven));
+    let half: AncDec8 = "0.5".parse().unwrap();
+    assert_eq!(AncDec8::FRAC_1_SQRT_2, half.sqrt_round(2, RoundMode::HalfEven));
+}
+
 // ============ Edge Cases ============
 #[test]
 fn test_zero_operations() {
@@ -610,6 +769,7 @@ fn test_frac_truncates_at_2() {
 }
 
 #[test]
+#[cfg(not(feature = "panic-free"))]
 #[should_panic]
 fn test_div_by_zero_panics() {
     let a: AncDec8 = "5".parse().unwrap();
@@ -647,6 +807,510 @@ fn test_cross_ops_with_ancdec32() {
     assert_eq!(result, "8.14".parse().unwrap());
 }
 
+// ============ Checked Arithmetic ============
+#[test]
+fn test_checked_div() {
+    let a: AncDec8 = "10".parse().unwrap();
+    let b: AncDec8 = "4".parse().unwrap();
+    assert_eq!(a.checked_div(&b), Some("2.5".parse().unwrap()));
+    assert_eq!(a.checked_div(&AncDec8::ZERO), None);
+    let tiny: AncDec8 = "0.01".parse().unwrap();
+    assert_eq!(AncDec8::MAX.checked_div(&tiny), None);
+}
+
+#[test]
+fn test_checked_rem() {
+    let a: AncDec8 = "10.5".parse().unwrap();
+    let b: AncDec8 = "3".parse().unwrap();
+    assert_eq!(a.checked_rem(&b), Some("1.5".parse().unwrap()));
+    assert_eq!(a.checked_rem(&AncDec8::ZERO), None);
+}
+
+#[test]
+fn test_checked_pow() {
+    assert_eq!(AncDec8::TWO.checked_pow(7), Some(AncDec8::from(128u8)));
+    assert_eq!(AncDec8::TWO.checked_pow(-2), Some("0.25".parse().unwrap()));
+    assert_eq!(AncDec8::TWO.checked_pow(8), None);
+    assert_eq!(AncDec8::ZERO.checked_pow(-1), None);
+}
+
+#[test]
+fn test_checked_sqrt_and_neg() {
+    let a: AncDec8 = "4".parse().unwrap();
+    assert_eq!(a.checked_sqrt(), Some(AncDec8::TWO));
+    assert_eq!((-a).checked_sqrt(), None);
+    assert_eq!(a.checked_neg(), Some(-a));
+}
+
+#[test]
+#[cfg(not(feature = "panic-free"))]
+#[should_panic(expected = "arithmetic overflow")]
+fn test_div_overflow_panics() {
+    let tiny: AncDec8 = "0.01".parse().unwrap();
+    let _ = AncDec8::MAX / tiny;
+}
+
+// ============ Saturating / Overflowing ============
+#[test]
+fn test_saturating_add_sub() {
+    let one = AncDec8::ONE;
+    assert_eq!(AncDec8::MAX.saturating_add(&one), AncDec8::MAX);
+    assert_eq!((-AncDec8::MAX).saturating_add(&-one), -AncDec8::MAX);
+    assert_eq!((-AncDec8::MAX).saturating_sub(&one), -AncDec8::MAX);
+    assert_eq!(AncDec8::MAX.saturating_sub(&-one), AncDec8::MAX);
+    assert_eq!(one.saturating_add(&one), AncDec8::TWO);
+}
+
+#[test]
+fn test_checked_sub_opposite_signs_overflow() {
+    assert_eq!(AncDec8::MAX.checked_sub(&-AncDec8::ONE), None);
+}
+
+#[test]
+fn test_saturating_mul_div() {
+    let tiny: AncDec8 = "0.01".parse().unwrap();
+    assert_eq!(AncDec8::MAX.saturating_mul(&AncDec8::TWO), AncDec8::MAX);
+    assert_eq!(AncDec8::MAX.saturating_mul(&-AncDec8::TWO), -AncDec8::MAX);
+    assert_eq!((-AncDec8::MAX).saturating_div(&tiny), -AncDec8::MAX);
+    assert_eq!(AncDec8::ONE.saturating_div(&AncDec8::ZERO), AncDec8::MAX);
+    assert_eq!(AncDec8::ZERO.saturating_div(&AncDec8::ZERO), AncDec8::ZERO);
+}
+
+#[test]
+fn test_saturating_pow() {
+    assert_eq!(AncDec8::TEN.saturating_pow(3), AncDec8::MAX);
+    assert_eq!((-AncDec8::TEN).saturating_pow(3), -AncDec8::MAX);
+    assert_eq!((-AncDec8::TEN).saturating_pow(4), AncDec8::MAX);
+    assert_eq!(AncDec8::ZERO.saturating_pow(-1), AncDec8::MAX);
+    assert_eq!(AncDec8::TWO.saturating_pow(2), "4".parse().unwrap());
+}
+
+#[test]
+fn test_overflowing_ops() {
+    let two = AncDec8::TWO;
+    assert_eq!(two.overflowing_add(&two), ("4".parse().unwrap(), false));
+    assert_eq!(AncDec8::MAX.overflowing_add(&two), (AncDec8::MAX, true));
+    assert_eq!(AncDec8::MAX.overflowing_sub(&-two), (AncDec8::MAX, true));
+    assert_eq!(AncDec8::MAX.overflowing_mul(&two), (AncDec8::MAX, true));
+    assert_eq!(two.overflowing_div(&AncDec8::ZERO), (AncDec8::MAX, true));
+    assert_eq!(two.overflowing_pow(2), ("4".parse().unwrap(), false));
+    assert_eq!(two.overflowing_pow(-1), ("0.5".parse().unwrap(), false));
+}
+
+// ============ Try Operations ============
+#[test]
+fn test_try_arithmetic() {
+    let a: AncDec8 = "1.5".parse().unwrap();
+    assert_eq!(a.try_add(&a), Ok(AncDec8::from(3u8)));
+    assert_eq!(a.try_sub(&a), Ok(AncDec8::ZERO));
+    assert_eq!(AncDec8::TWO.try_mul(&a), Ok(AncDec8::from(3u8)));
+    assert_eq!(a.try_div(&a), Ok(AncDec8::ONE));
+    assert_eq!(AncDec8::from(7u8).try_rem(&AncDec8::TWO), Ok(AncDec8::ONE));
+}
+
+#[test]
+fn test_try_arithmetic_errors() {
+    assert_eq!(AncDec8::MAX.try_add(&AncDec8::ONE), Err(ArithmeticError::Overflow));
+    assert_eq!((-AncDec8::MAX).try_sub(&AncDec8::ONE), Err(ArithmeticError::Overflow));
+    assert_eq!(AncDec8::MAX.try_mul(&AncDec8::TWO), Err(ArithmeticError::Overflow));
+    assert_eq!(AncDec8::ONE.try_div(&AncDec8::ZERO), Err(ArithmeticError::DivisionByZero));
+    assert_eq!(AncDec8::ONE.try_rem(&AncDec8::ZERO), Err(ArithmeticError::DivisionByZero));
+}
+
+#[test]
+fn test_try_pow_sqrt() {
+    assert_eq!(AncDec8::TWO.try_pow(3), Ok(AncDec8::from(8u8)));
+    assert_eq!(AncDec8::ZERO.try_pow(-1), Err(ArithmeticError::DivisionByZero));
+    assert_eq!(AncDec8::MAX.try_pow(2), Err(ArithmeticError::Overflow));
+    assert_eq!(AncDec8::from(4u8).try_sqrt().unwrap(), AncDec8::TWO);
+    assert_eq!((-AncDec8::ONE).try_sqrt(), Err(ArithmeticError::NegativeSqrt));
+}
+
+#[test]
+fn test_try_new() {
+    assert_eq!(AncDec8::try_new(1, 5, 1, false), Ok(AncDec8::new(1, 5, 1, false)));
+    assert_eq!(AncDec8::try_new(1, 0, 3, false), Err(ArithmeticError::ScaleOverflow));
+    assert_eq!(AncDec8::try_new(1, 10, 1, false), Err(ArithmeticError::ScaleOverflow));
+}
+
+#[test]
+fn test_arithmetic_error_display() {
+    assert_eq!(ArithmeticError::DivisionByZero.to_string(), "division by zero");
+    assert_eq!(ArithmeticError::Overflow.to_string(), "arithmetic overflow");
+}
+
+#[test]
+#[cfg(feature = "panic-free")]
+fn test_panic_free_saturates() {
+    assert_eq!(AncDec8::MAX + AncDec8::ONE, AncDec8::MAX);
+    assert_eq!(AncDec8::MAX * -AncDec8::TWO, -AncDec8::MAX);
+    assert_eq!(AncDec8::ONE / AncDec8::ZERO, AncDec8::MAX);
+    assert_eq!(AncDec8::ONE % AncDec8::ZERO, AncDec8::ZERO);
+    assert_eq!((-AncDec8::ONE).sqrt(), AncDec8::ZERO);
+}
+
+#[test]
+fn test_round_up_half_odd_05up() {
+    let a: AncDec8 = "2.01".parse().unwrap();
+    assert_eq!(a.round(1, RoundMode::Up).to_string(), "2.1");
+    assert_eq!((-a).round(0, RoundMode::Up).to_string(), "-3");
+    let b: AncDec8 = "2.5".parse().unwrap();
+    assert_eq!(b.round(0, RoundMode::HalfOdd).to_string(), "3");
+    assert_eq!("3.5".parse::<AncDec8>().unwrap().round(0, RoundMode::HalfOdd).to_string(), "3");
+    assert_eq!(a.round(1, RoundMode::ZeroFiveUp).to_string(), "2.1");
+    assert_eq!("2.11".parse::<AncDec8>().unwrap().round(1, RoundMode::ZeroFiveUp).to_string(), "2.1");
+    assert_eq!("2.51".parse::<AncDec8>().unwrap().round(1, RoundMode::ZeroFiveUp).to_string(), "2.6");
+}
+
+/// xorshift64: deterministic RNG for the stochastic rounding tests
+#[cfg(feature = "rand")]
+struct XorShift(u64);
+
+#[cfg(feature = "rand")]
+impl rand_core::RngCore for XorShift {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        rand_core::impls::fill_bytes_via_next(self, dst)
+    }
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_round_stochastic() {
+    let mut rng = XorShift(0x9E37_79B9_7F4A_7C15);
+    let a: AncDec8 = "1.25".parse().unwrap();
+    let (lo, hi): (AncDec8, AncDec8) = ("1.2".parse().unwrap(), "1.3".parse().unwrap());
+    let mut ups = 0;
+    for _ in 0..4000 {
+        let r = a.round_stochastic(1, &mut rng);
+        assert!(r == lo || r == hi);
+        ups += (r == hi) as u32;
+    }
+    // rounds up half of the time; the bounds are about 4.5 standard deviations
+    assert!((1850..=2150).contains(&ups), "{}", ups);
+    // nothing discarded: never rounds
+    let b: AncDec8 = "1.20".parse().unwrap();
+    assert_eq!(b.round_stochastic(1, &mut rng), b.round(1, RoundMode::Truncate));
+}
+
+// ============ Rounded Arithmetic ============
+#[test]
+fn test_mul_round() {
+    let a: AncDec8 = "1.25".parse().unwrap();
+    let b: AncDec8 = "1.5".parse().unwrap();
+    assert_eq!(a.mul_round(&b, 2, RoundMode::HalfEven).to_string(), "1.88");
+    assert_eq!(a.mul_round(&b, 2, RoundMode::HalfDown).to_string(), "1.87");
+    assert_eq!((-a).mul_round(&b, 1, RoundMode::Floor).to_string(), "-1.9");
+    // exact products keep their scale
+    assert_eq!(b.mul_round(&b, 2, RoundMode::HalfUp).to_string(), "2.25");
+}
+
+#[test]
+fn test_mul_round_half_below_target() {
+    let a: AncDec8 = "0.05".parse().unwrap();
+    let b: AncDec8 = "0.1".parse().unwrap();
+    assert_eq!(a.mul_round(&b, 2, RoundMode::HalfUp).to_string(), "0.01");
+    assert_eq!(a.mul_round(&b, 2, RoundMode::HalfEven), AncDec8::ZERO);
+}
+
+#[test]
+fn test_div_round() {
+    let two = AncDec8::TWO;
+    let three = AncDec8::from(3u8);
+    assert_eq!(two.div_round(&three, 2, RoundMode::HalfEven).to_string(), "0.67");
+    assert_eq!(AncDec8::ONE.div_round(&three, 1, RoundMode::Ceil).to_string(), "0.4");
+    assert_eq!((-AncDec8::ONE).div_round(&three, 1, RoundMode::Floor).to_string(), "-0.4");
+    let x: AncDec8 = "2.5".parse().unwrap();
+    assert_eq!(x.div_round(&AncDec8::ONE, 0, RoundMode::HalfEven).to_string(), "2");
+}
+
+#[test]
+fn test_checked_mul_div_round() {
+    let big = AncDec8::from(200u8);
+    assert_eq!(big.checked_mul_round(&AncDec8::TWO, 2, RoundMode::HalfUp), None);
+    assert_eq!(big.checked_div_round(&AncDec8::ZERO, 2, RoundMode::HalfUp), None);
+    assert_eq!(
+        AncDec8::ONE.checked_div_round(&AncDec8::TWO, 0, RoundMode::HalfUp),
+        Some(AncDec8::ONE)
+    );
+}
+
+#[test]
+#[cfg(not(feature = "panic-free"))]
+#[should_panic(expected = "division by zero")]
+fn test_div_round_by_zero() {
+    AncDec8::ONE.div_round(&AncDec8::ZERO, 2, RoundMode::HalfUp);
+}
+
+// ============ Integer Division ============
+#[test]
+fn test_divmod() {
+    let a: AncDec8 = "7.5".parse().unwrap();
+    let b = AncDec8::TWO;
+    let (q, r) = a.divmod(&b);
+    assert_eq!(q, AncDec8::from(3u8));
+    assert_eq!(r.to_string(), "1.5");
+    let (q, r) = (-a).divmod(&b);
+    assert_eq!(q.to_string(), "-3");
+    assert_eq!(r.to_string(), "-1.5");
+    assert_eq!(a.checked_divmod(&AncDec8::ZERO), None);
+}
+
+#[test]
+fn test_div_mod_floor() {
+    let a: AncDec8 = "-7.5".parse().unwrap();
+    let b = AncDec8::TWO;
+    assert_eq!(a.div_floor(&b).to_string(), "-4");
+    assert_eq!(a.mod_floor(&b).to_string(), "0.5");
+    assert_eq!((-a).mod_floor(&-b).to_string(), "-0.5");
+    assert_eq!(b.checked_mod_floor(&AncDec8::ZERO), None);
+}
+
+#[test]
+fn test_div_rem_euclid() {
+    let a: AncDec8 = "-7.5".parse().unwrap();
+    let b = AncDec8::TWO;
+    assert_eq!(a.div_euclid(&b).to_string(), "-4");
+    assert_eq!(a.rem_euclid(&b).to_string(), "0.5");
+    assert_eq!(a.div_euclid(&-b).to_string(), "4");
+    assert_eq!(a.rem_euclid(&-b).to_string(), "0.5");
+    // exact division leaves no adjustment
+    assert_eq!(AncDec8::from(6u8).rem_euclid(&-b), AncDec8::ZERO);
+}
+
+#[test]
+fn test_int_division_i128() {
+    let a: AncDec8 = "-7.5".parse().unwrap();
+    let b = AncDec8::TWO;
+    assert_eq!(a.divmod_i128(&b), Some((-3, "-1.5".parse().unwrap())));
+    assert_eq!(a.div_floor_i128(&b), Some(-4));
+    assert_eq!(a.div_euclid_i128(&-b), Some(4));
+    assert_eq!(a.div_floor_i128(&AncDec8::ZERO), None);
+}
+
+#[test]
+fn test_rem_large_quotient() {
+    // the quotient overflows, but the remainder is exact
+    let a: AncDec8 = "255.98".parse().unwrap();
+    let b: AncDec8 = "0.07".parse().unwrap();
+    assert_eq!(a.checked_div_floor(&b), None);
+    assert_eq!(a.div_floor_i128(&b), Some(3656));
+    assert_eq!((a % b).to_string(), "0.06");
+    assert_eq!((-a).rem_euclid(&b).to_string(), "0.01");
+}
+
+// ============ Minimal-scale Division ============
+#[test]
+fn test_div_min_scale_terminating() {
+    let one = AncDec8::ONE;
+    let q = one.div_min_scale(&AncDec8::TWO, 0, RoundMode::HalfEven);
+    assert_eq!(q.to_string(), "0.5");
+    assert_eq!(q.scale(), 1);
+    let ten: AncDec8 = "10".parse().unwrap();
+    assert_eq!(ten.div_min_scale(&"4".parse().unwrap(), 0, RoundMode::HalfEven).to_string(), "2.5");
+    let six: AncDec8 = "6".parse().unwrap();
+    assert_eq!(six.div_min_scale(&"0.3".parse().unwrap(), 0, RoundMode::HalfEven).to_string(), "20");
+    // 1/8 needs 3 places: it does not terminate within 2
+    let eight: AncDec8 = "8".parse().unwrap();
+    assert_eq!(one.div_min_scale(&eight, 2, RoundMode::HalfEven).to_string(), "0.12");
+}
+
+#[test]
+fn test_div_min_scale_non_terminating() {
+    let three: AncDec8 = "3".parse().unwrap();
+    assert_eq!(AncDec8::ONE.div_min_scale(&three, 1, RoundMode::HalfUp).to_string(), "0.3");
+    assert_eq!(AncDec8::TWO.div_min_scale(&three, 2, RoundMode::HalfEven).to_string(), "0.67");
+    assert_eq!((-AncDec8::TWO).div_min_scale(&three, 1, RoundMode::Floor).to_string(), "-0.7");
+    assert_eq!(AncDec8::ONE.checked_div_min_scale(&AncDec8::ZERO, 2, RoundMode::HalfUp), None);
+}
+
+// ============ Exact Arithmetic ============
+#[test]
+fn test_mul_exact() {
+    let a: AncDec8 = "1.5".parse().unwrap();
+    assert_eq!(a.mul_exact(&a).unwrap().to_string(), "2.25");
+    let b: AncDec8 = "0.15".parse().unwrap();
+    assert_eq!(b.mul_exact(&b), Err(ArithmeticError::Inexact));
+    // trailing zeros beyond scale 2 are not lost digits
+    let c: AncDec8 = "0.50".parse().unwrap();
+    assert_eq!(c.mul_exact(&"0.20".parse().unwrap()).unwrap().to_string(), "0.10");
+    assert_eq!(AncDec8::MAX.mul_exact(&AncDec8::TWO), Err(ArithmeticError::Overflow));
+}
+
+#[test]
+fn test_div_exact() {
+    let four: AncDec8 = "4".parse().unwrap();
+    let q = AncDec8::ONE.div_exact(&four).unwrap();
+    assert_eq!((q.to_string(), q.scale()), ("0.25".into(), 2));
+    assert_eq!(four.div_exact(&AncDec8::TWO).unwrap().scale(), 0);
+    assert_eq!(AncDec8::ONE.div_exact(&"8".parse().unwrap()), Err(ArithmeticError::Inexact));
+    assert_eq!(AncDec8::ONE.div_exact(&AncDec8::ZERO), Err(ArithmeticError::DivisionByZero));
+}
+
+#[test]
+fn test_add_sub_parse_exact() {
+    assert_eq!(AncDec8::MAX.add_exact(&AncDec8::ONE), Err(ArithmeticError::Overflow));
+    assert_eq!(AncDec8::ONE.sub_exact(&AncDec8::TWO).unwrap().to_string(), "-1");
+    assert_eq!(AncDec8::parse_exact("1.239"), Err(ParseError::Inexact));
+    assert_eq!(AncDec8::parse_exact("-1.2300").unwrap().to_string(), "-1.23");
+    assert_eq!(AncDec8::parse_exact("256"), Err(ParseError::Overflow));
+}
+
+// ============ Scale Management ============
+#[test]
+fn test_normalize_trailing_zeros() {
+    let a: AncDec8 = "1.50".parse().unwrap();
+    assert_eq!(a.trailing_zeros(), 1);
+    assert_eq!((a.normalize().frac(), a.normalize().scale()), (5, 1));
+    let b: AncDec8 = "-2.00".parse().unwrap();
+    assert_eq!(b.trailing_zeros(), 2);
+    assert!(b.is_integer() && !a.is_integer());
+    assert_eq!(b.normalize().to_string(), "-2");
+    let z: AncDec8 = "-0.00".parse().unwrap();
+    assert_eq!(z.normalize(), AncDec8::ZERO);
+    assert!(!z.normalize().is_neg());
+}
+
+#[test]
+fn test_rescale() {
+    let a: AncDec8 = "1.5".parse().unwrap();
+    assert_eq!(a.rescale(2, RoundMode::HalfUp).to_string(), "1.50");
+    assert_eq!(a.rescale(0, RoundMode::HalfEven).to_string(), "2");
+    assert_eq!(a.rescale(0, RoundMode::Fract).to_string(), "1");
+    assert_eq!(a.checked_rescale(3, RoundMode::HalfUp), None);
+    let big: AncDec8 = "255.5".parse().unwrap();
+    assert_eq!(big.checked_rescale(0, RoundMode::HalfUp), None);
+    assert_eq!(a.with_scale(2).unwrap().scale(), 2);
+    assert_eq!(a.with_scale(0), None);
+    assert_eq!(AncDec8::TWO.rescale(2, RoundMode::Floor).with_scale(0), Some(AncDec8::TWO));
+}
+
+// ============ Increment Rounding ============
+#[test]
+fn test_round_to_increment() {
+    let nickel: AncDec8 = "0.05".parse().unwrap();
+    let a: AncDec8 = "1.23".parse().unwrap();
+    assert_eq!(a.round_to_increment(&nickel, RoundMode::HalfUp).to_string(), "1.25");
+    assert_eq!("1.22".parse::<AncDec8>().unwrap().round_to_increment(&nickel, RoundMode::HalfUp).to_string(), "1.20");
+    assert_eq!((-a).round_to_increment(&nickel, RoundMode::Floor).to_string(), "-1.25");
+    assert_eq!((-a).round_to_increment(&-nickel, RoundMode::Ceil).to_string(), "-1.20");
+    // ties to an even multiple: 1.25 / 0.5 = 2.5 -> 2, 1.75 / 0.5 = 3.5 -> 4
+    let half: AncDec8 = "0.5".parse().unwrap();
+    assert_eq!("1.25".parse::<AncDec8>().unwrap().round_to_increment(&half, RoundMode::HalfEven).to_string(), "1.0");
+    assert_eq!("1.75".parse::<AncDec8>().unwrap().round_to_increment(&half, RoundMode::HalfEven).to_string(), "2.0");
+    let five: AncDec8 = "5".parse().unwrap();
+    assert_eq!("17.5".parse::<AncDec8>().unwrap().round_to_increment(&five, RoundMode::HalfUp).to_string(), "20");
+    let ten: AncDec8 = "10".parse().unwrap();
+    assert_eq!(AncDec8::MAX.checked_round_to_increment(&ten, RoundMode::HalfUp), None);
+    assert_eq!(a.checked_round_to_increment(&AncDec8::ZERO, RoundMode::HalfUp), None);
+}
+
+#[test]
+fn test_is_multiple_of() {
+    let nickel: AncDec8 = "0.05".parse().unwrap();
+    assert!("1.25".parse::<AncDec8>().unwrap().is_multiple_of(&nickel));
+    assert!(!"1.26".parse::<AncDec8>().unwrap().is_multiple_of(&nickel));
+    assert!("-0.75".parse::<AncDec8>().unwrap().is_multiple_of(&"0.25".parse().unwrap()));
+    assert!(AncDec8::ZERO.is_multiple_of(&AncDec8::ZERO));
+    assert!(!AncDec8::ONE.is_multiple_of(&AncDec8::ZERO));
+}
+
+// ============ Significant Digits ============
+#[test]
+fn test_digit_introspection() {
+    let a: AncDec8 = "123.40".parse().unwrap();
+    assert_eq!((a.int_digits(), a.leading_frac_zeros(), a.precision()), (3, 0, 5));
+    let b: AncDec8 = "0.05".parse().unwrap();
+    assert_eq!((b.int_digits(), b.leading_frac_zeros(), b.precision()), (0, 1, 1));
+    assert_eq!((AncDec8::ZERO.precision(), "0.00".parse::<AncDec8>().unwrap().leading_frac_zeros()), (0, 2));
+    assert_eq!(a.mantissa_exponent(), (1234, -1));
+    assert_eq!("-200".parse::<AncDec8>().unwrap().mantissa_exponent(), (-2, 2));
+    assert_eq!(AncDec8::ZERO.mantissa_exponent(), (0, 0));
+}
+
+#[test]
+fn test_round_sig() {
+    let a: AncDec8 = "123.45".parse().unwrap();
+    assert_eq!(a.round_sig(2, RoundMode::HalfUp).to_string(), "120");
+    assert_eq!(a.round_sig(4, RoundMode::HalfEven).to_string(), "123.4");
+    assert_eq!(a.round_sig(9, RoundMode::HalfUp), a);
+    assert_eq!("-0.56".parse::<AncDec8>().unwrap().round_sig(1, RoundMode::Floor).to_string(), "-0.6");
+    // a carry into a new leading digit keeps the requested number of digits
+    assert_eq!("9.96".parse::<AncDec8>().unwrap().round_sig(2, RoundMode::HalfUp).to_string(), "10");
+    assert_eq!("0.96".parse::<AncDec8>().unwrap().round_sig(1, RoundMode::HalfUp).to_string(), "1");
+}
+
+// ============ Context ============
+#[test]
+fn test_context_add_rounds_and_flags() {
+    let mut ctx = Context::new(1, RoundMode::HalfEven);
+    let a: AncDec8 = "1.25".parse().unwrap();
+    let b: AncDec8 = "1.2".parse().unwrap();
+    assert_eq!(ctx.add(&a, &AncDec8::ONE).unwrap().to_string(), "2.2");
+    assert_eq!(ctx.flags(), Signal::Rounded | Signal::Inexact);
+    ctx.clear_flags();
+    assert_eq!(ctx.add(&b, &AncDec8::ONE).unwrap().to_string(), "2.2");
+    assert_eq!(ctx.sub(&b, &AncDec8::ONE).unwrap().to_string(), "0.2");
+    assert!(ctx.flags().is_empty());
+}
+
+#[test]
+fn test_context_mul_div() {
+    let mut ctx = Context::default();
+    let three: AncDec8 = "3".parse().unwrap();
+    let four: AncDec8 = "4".parse().unwrap();
+    let q = ctx.div(&AncDec8::ONE, &four).unwrap();
+    assert_eq!((q.to_string(), q.scale()), ("0.25".into(), 2));
+    assert!(ctx.flags().is_empty());
+    assert_eq!(ctx.div(&AncDec8::TWO, &three).unwrap().to_string(), "0.67");
+    assert_eq!(ctx.flags(), Signal::Rounded | Signal::Inexact);
+
+    let mut ctx = Context::new(1, RoundMode::HalfUp);
+    let x: AncDec8 = "1.5".parse().unwrap();
+    assert_eq!(ctx.mul(&x, &x).unwrap().to_string(), "2.3");
+    assert!(ctx.flags().contains(Signal::Inexact));
+}
+
+#[test]
+fn test_context_traps() {
+    let mut ctx = Context::default();
+    let big: AncDec8 = "200".parse().unwrap();
+    assert_eq!(ctx.add(&big, &big), Err(Signal::Overflow));
+    assert_eq!(ctx.div(&AncDec8::ONE, &AncDec8::ZERO), Err(Signal::DivisionByZero));
+    assert!(ctx.flags().contains(Signal::Overflow) && ctx.flags().contains(Signal::DivisionByZero));
+
+    let mut ctx = Context::default().with_traps(Signals::EMPTY);
+    assert_eq!(ctx.add(&big, &big), Ok(AncDec8::MAX));
+    assert_eq!(ctx.mul(&-big, &big), Ok(-AncDec8::MAX));
+    assert!(ctx.flags().contains(Signal::Overflow));
+    // rounding can carry into the integer part: 255.5 -> 256
+    let mut ctx = Context::new(0, RoundMode::HalfUp);
+    assert_eq!(ctx.round(&"255.5".parse::<AncDec8>().unwrap()), Err(Signal::Overflow));
+}
+
+#[test]
+fn test_context_parse_clamped() {
+    let mut ctx = Context::default();
+    let a: AncDec8 = ctx.parse("1.239").unwrap();
+    assert_eq!(a.to_string(), "1.23");
+    assert_eq!(ctx.flags(), Signal::Clamped | Signal::Inexact);
+    ctx.clear_flags();
+    let _: AncDec8 = ctx.parse("1.230").unwrap();
+    assert_eq!(ctx.flags(), Signals::from(Signal::Clamped));
+
+    let mut ctx = Context::default().with_traps(Signal::Inexact.into());
+    assert_eq!(ctx.parse::<AncDec8>("0.001"), Err(ParseError::Trapped(Signal::Inexact)));
+    assert_eq!(ctx.parse::<AncDec8>("x"), Err(ParseError::NoDigits));
+}
+
 // ============ Serde ============
 #[cfg(feature = "serde")]
 #[test]
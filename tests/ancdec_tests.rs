@@ -1,7 +1,9 @@
 // tests/ancdec_tests.rs
 #![cfg(feature = "dec64")]
+// Reference-operator and float-literal tests are intentional
+#![allow(clippy::op_ref, clippy::approx_constant)]
 
-use ancdec::{AncDec, RoundMode};
+use ancdec::{ArithmeticError, AncDec, Context, ParseError, RoundMode, Signal, Signals};
 
 // ============ Parsing ============
 #[test]
@@ -425,6 +427,18 @@ fn test_round_decimal_places() {
     assert_eq!(a.round(3, RoundMode::HalfUp), "3.142".parse().unwrap());
 }
 
+#[test]
+fn test_round_with_residual() {
+    let a: AncDec = "12345678901234567.8912345678901234567".parse().unwrap();
+    let (r, res) = a.round_with_residual(3, RoundMode::Up);
+    assert_eq!((r.to_string(), res.to_string()), ("12345678901234567.892".into(), "-0.0007654321098765433".into()));
+    assert_eq!(r + res, a);
+    let (r, res) = (-a).round_with_residual(0, RoundMode::Floor);
+    assert_eq!((r.to_string(), res.to_string()), ("-12345678901234568".into(), "0.1087654321098765433".into()));
+    let (r, res) = a.round_with_residual(10, RoundMode::Fract);
+    assert_eq!((r.to_string(), res.to_string()), ("12345678901234567.8912345678".into(), "0.0000000000901234567".into()));
+}
+
 // ============ Floor/Ceil/Trunc/Fract ============
 #[test]
 fn test_floor() {
@@ -489,6 +503,19 @@ fn test_pow_negative() {
     assert_eq!(a.pow(-1), "0.5".parse().unwrap());
 }
 
+#[test]
+fn test_powi_large_exponent() {
+    let two: AncDec = "2".parse().unwrap();
+    assert_eq!(two.powi(3), "8".parse().unwrap());
+    assert_eq!(two.checked_powi(i64::MAX), None);
+    assert_eq!(two.try_powi(i64::MAX), Err(ArithmeticError::Overflow));
+    let half: AncDec = "0.5".parse().unwrap();
+    assert_eq!(half.checked_powi(i64::MAX), Some(AncDec::ZERO));
+    assert_eq!((-AncDec::ONE).powi(i64::MAX), -AncDec::ONE);
+    assert_eq!(AncDec::ONE.powi(i64::MIN), AncDec::ONE);
+    assert_eq!(AncDec::ZERO.try_powi(-1), Err(ArithmeticError::DivisionByZero));
+}
+
 // ============ Square Root ============
 #[test]
 fn test_sqrt_perfect_squares() {
@@ -540,12 +567,312 @@ fn test_sqrt_large() {
 }
 
 #[test]
+#[cfg(not(feature = "panic-free"))]
 #[should_panic(expected = "square root of negative")]
 fn test_sqrt_negative_panics() {
     let neg: AncDec = "-4".parse().unwrap();
     neg.sqrt();
 }
 
+#[test]
+fn test_sqrt_round() {
+    let two: AncDec = "2".parse().unwrap();
+    assert_eq!(two.sqrt_round(19, RoundMode::HalfUp).to_string(), "1.4142135623730950488");
+    assert_eq!(two.sqrt_round(19, RoundMode::Ceil).to_string(), "1.4142135623730950489");
+    let tiny: AncDec = "0.0000000000000000001".parse().unwrap();
+    assert_eq!(tiny.sqrt_round(19, RoundMode::HalfEven).to_string(), "0.0000000003162277660");
+    assert_eq!(AncDec::MAX.sqrt_round(19, RoundMode::HalfEven).to_string(), "4294967296.0000000000000000000");
+    assert_eq!(AncDec::MAX.sqrt_round(19, RoundMode::Floor).to_string(), "4294967295.9999999999999999999");
+    let x: AncDec = "1.5625".parse().unwrap();
+    assert_eq!(x.sqrt_round(1, RoundMode::HalfDown).to_string(), "1.2");
+    assert_eq!(x.sqrt_round(1, RoundMode::HalfUp).to_string(), "1.3");
+}
+
+#[test]
+fn test_cbrt_nth_root() {
+    let a: AncDec = "27".parse().unwrap();
+    assert_eq!(a.cbrt().to_string(), "3.000000000000000000");
+    let b: AncDec = "-8".parse().unwrap();
+    assert_eq!(b.cbrt().to_string(), "-2.000000000000000000");
+    assert_eq!(AncDec::TWO.nth_root(5).to_string(), "1.148698354997035006");
+    assert_eq!(AncDec::ONE.nth_root(4_000_000_000), AncDec::ONE);
+    assert_eq!(AncDec::ZERO.nth_root(7), AncDec::ZERO);
+}
+
+#[test]
+fn test_nth_root_domain() {
+    let neg: AncDec = "-16".parse().unwrap();
+    assert_eq!(neg.try_nth_root(4), Err(ArithmeticError::NegativeSqrt));
+    assert_eq!(AncDec::TWO.try_nth_root(0), Err(ArithmeticError::OutOfDomain));
+    assert_eq!(neg.checked_nth_root(4), None);
+}
+
+#[test]
+#[cfg(not(feature = "panic-free"))]
+#[should_panic(expected = "square root of negative")]
+fn test_even_root_negative_panics() {
+    let neg: AncDec = "-16".parse().unwrap();
+    neg.nth_root(4);
+}
+
+// ============ Exponential and Logarithm ============
+#[test]
+fn test_exp() {
+    assert_eq!(AncDec::ZERO.exp(), AncDec::ONE);
+    assert_eq!(AncDec::ONE.exp().to_string(), "2.7182818284590452354");
+    assert_eq!((-AncDec::ONE).exp().to_string(), "0.3678794411714423216");
+    let big: AncDec = "45".parse().unwrap();
+    assert_eq!(big.checked_exp(), None);
+    assert_eq!(big.try_exp(), Err(ArithmeticError::Overflow));
+    let small: AncDec = "-1000".parse().unwrap();
+    assert_eq!(small.exp(), AncDec::ZERO);
+}
+
+#[test]
+fn test_ln() {
+    assert_eq!(AncDec::ONE.ln(), AncDec::ZERO);
+    let two: AncDec = "2".parse().unwrap();
+    assert_eq!(two.ln().to_string(), "0.6931471805599453094");
+    let half: AncDec = "0.5".parse().unwrap();
+    assert_eq!(half.ln().to_string(), "-0.6931471805599453094");
+    assert_eq!(AncDec::ONE.exp().ln(), AncDec::ONE);
+    assert_eq!(AncDec::ZERO.checked_ln(), None);
+    assert_eq!((-two).try_ln(), Err(ArithmeticError::OutOfDomain));
+}
+
+#[test]
+fn test_log10_log2() {
+    let x: AncDec = "1000".parse().unwrap();
+    assert_eq!(x.log10().to_string(), "3");
+    let x: AncDec = "0.001".parse().unwrap();
+    assert_eq!(x.log10().to_string(), "-3");
+    let two: AncDec = "2".parse().unwrap();
+    assert_eq!(two.log10().to_string(), "0.3010299956639811952");
+    let x: AncDec = "1024".parse().unwrap();
+    assert_eq!(x.log2().to_string(), "10");
+    assert_eq!(AncDec::ZERO.checked_log10(), None);
+    assert_eq!((-two).checked_log2(), None);
+}
+
+#[test]
+fn test_log_base() {
+    let x: AncDec = "8".parse().unwrap();
+    let two: AncDec = "2".parse().unwrap();
+    assert_eq!(x.log(&two).to_string(), "3");
+    let x: AncDec = "100".parse().unwrap();
+    let tenth: AncDec = "0.1".parse().unwrap();
+    assert_eq!(x.log(&tenth).to_string(), "-2");
+    assert_eq!(x.checked_log(&AncDec::ONE), None);
+    assert_eq!(x.try_log(&AncDec::ZERO), Err(ArithmeticError::OutOfDomain));
+    let near_one: AncDec = "1.0000000000000000001".parse().unwrap();
+    assert_eq!(x.try_log(&near_one), Err(ArithmeticError::Overflow));
+}
+
+#[test]
+fn test_powd() {
+    let two: AncDec = "2".parse().unwrap();
+    let half: AncDec = "0.5".parse().unwrap();
+    assert_eq!(two.powd(&half).to_string(), "1.4142135623730950488");
+    let x: AncDec = "4".parse().unwrap();
+    assert_eq!(x.powd(&half), two);
+    assert_eq!(x.powd(&-half), half);
+    // integer exponents keep exact results
+    let three: AncDec = "3".parse().unwrap();
+    assert_eq!((-two).powd(&three), "-8".parse().unwrap());
+    // integer exponents round the exact power once
+    let y: AncDec = "1.0853163".parse().unwrap();
+    let nine: AncDec = "9.0".parse().unwrap();
+    assert_eq!(y.powd(&nine).to_string(), "2.0893294709678545028");
+    assert_eq!(y.powd(&-three).to_string(), "0.7822237956080785541");
+    // 2^-20 ends in a 5 at the 20th place: an exact tie, kept even
+    let e: AncDec = "-20".parse().unwrap();
+    assert_eq!(two.powd(&e).to_string(), "0.0000009536743164062");
+    assert_eq!((-two).checked_powd(&half), None);
+    assert_eq!((-two).try_powd(&half), Err(ArithmeticError::OutOfDomain));
+    assert_eq!(AncDec::ZERO.powd(&half), AncDec::ZERO);
+    assert_eq!(AncDec::ZERO.try_powd(&-half), Err(ArithmeticError::DivisionByZero));
+    let big: AncDec = "64.5".parse().unwrap();
+    assert_eq!(two.try_powd(&big), Err(ArithmeticError::Overflow));
+}
+
+#[test]
+fn test_powd_annualization() {
+    // monthly rate from a 5% annual rate: 1.05^(1/12)
+    let annual: AncDec = "1.05".parse().unwrap();
+    let twelfth = AncDec::ONE / "12".parse::<AncDec>().unwrap();
+    assert_eq!(annual.powd(&twelfth).to_string(), "1.0040741237836483016");
+}
+
+#[test]
+#[cfg(not(feature = "panic-free"))]
+#[should_panic(expected = "argument out of domain")]
+fn test_ln_negative_panics() {
+    (-AncDec::ONE).ln();
+}
+
+// ============ Trigonometric and Hyperbolic ============
+#[test]
+fn test_sin_cos_tan() {
+    assert_eq!(AncDec::ZERO.sin(), AncDec::ZERO);
+    assert_eq!(AncDec::ZERO.cos(), AncDec::ONE);
+    assert_eq!(AncDec::ONE.sin().to_string(), "0.8414709848078965067");
+    assert_eq!((-AncDec::ONE).sin().to_string(), "-0.8414709848078965067");
+    assert_eq!((-AncDec::ONE).cos().to_string(), "0.5403023058681397174");
+    assert_eq!(AncDec::ONE.tan().to_string(), "1.5574077246549022305");
+    // reduced modulo an internal high-precision π
+    let x: AncDec = "1000000".parse().unwrap();
+    assert_eq!(x.cos().to_string(), "0.9367521275331447869");
+    let near_half_pi: AncDec = "1.5707963267948966192".parse().unwrap();
+    assert_eq!(near_half_pi.checked_tan(), None);
+    assert_eq!(near_half_pi.try_tan(), Err(ArithmeticError::Overflow));
+}
+
+#[test]
+fn test_inverse_trig() {
+    assert_eq!(AncDec::ONE.asin().to_string(), "1.5707963267948966192");
+    assert_eq!((-AncDec::ONE).acos().to_string(), "3.1415926535897932385");
+    assert_eq!(AncDec::ONE.acos(), AncDec::ZERO);
+    assert_eq!(AncDec::ONE.atan().to_string(), "0.7853981633974483096");
+    let half: AncDec = "0.5".parse().unwrap();
+    assert_eq!(half.asin().to_string(), "0.5235987755982988731");
+    let x: AncDec = "1.5".parse().unwrap();
+    assert_eq!(x.checked_asin(), None);
+    assert_eq!((-x).try_acos(), Err(ArithmeticError::OutOfDomain));
+}
+
+#[test]
+fn test_atan2() {
+    assert_eq!(AncDec::ONE.atan2(&-AncDec::ONE).to_string(), "2.3561944901923449288");
+    assert_eq!((-AncDec::ONE).atan2(&-AncDec::ONE).to_string(), "-2.3561944901923449288");
+    assert_eq!(AncDec::ZERO.atan2(&-AncDec::ONE).to_string(), "3.1415926535897932385");
+    assert_eq!(AncDec::ONE.atan2(&AncDec::ZERO).to_string(), "1.5707963267948966192");
+    assert_eq!(AncDec::ZERO.atan2(&AncDec::ZERO), AncDec::ZERO);
+}
+
+#[test]
+fn test_hyperbolic() {
+    assert_eq!(AncDec::ZERO.sinh(), AncDec::ZERO);
+    assert_eq!(AncDec::ZERO.cosh(), AncDec::ONE);
+    assert_eq!(AncDec::ONE.sinh().to_string(), "1.1752011936438014569");
+    assert_eq!((-AncDec::ONE).sinh().to_string(), "-1.1752011936438014569");
+    assert_eq!((-AncDec::ONE).cosh().to_string(), "1.5430806348152437785");
+    assert_eq!(AncDec::ONE.tanh().to_string(), "0.7615941559557648881");
+    let big: AncDec = "46".parse().unwrap();
+    assert_eq!(big.tanh(), AncDec::ONE);
+    assert_eq!((-big).tanh(), -AncDec::ONE);
+    assert_eq!(big.checked_cosh(), None);
+    assert_eq!((-big).try_sinh(), Err(ArithmeticError::Overflow));
+}
+
+#[test]
+#[cfg(not(feature = "panic-free"))]
+#[should_panic(expected = "argument out of domain")]
+fn test_asin_out_of_domain_panics() {
+    "2".parse::<AncDec>().unwrap().asin();
+}
+
+// ============ Error Function and Normal Distribution ============
+#[test]
+fn test_erf_erfc() {
+    let one = AncDec::ONE;
+    assert_eq!(one.erf().to_string(), "0.8427007929497148693");
+    assert_eq!((-one).erf().to_string(), "-0.8427007929497148693");
+    assert_eq!(one.erfc().to_string(), "0.1572992070502851307");
+    let x: AncDec = "-3".parse().unwrap();
+    assert_eq!(x.erfc().to_string(), "1.9999779095030014146");
+    assert_eq!(AncDec::ZERO.erf(), AncDec::ZERO);
+    assert_eq!(AncDec::ZERO.erfc(), AncDec::ONE);
+    // erfc(9) ~ 4.1e-37 rounds to zero at 19 places; erf saturates at 1
+    assert_eq!(AncDec::from(9).erfc(), AncDec::ZERO);
+    assert_eq!(AncDec::MAX.erf(), AncDec::ONE);
+}
+
+#[test]
+fn test_norm_cdf() {
+    let x: AncDec = "1.96".parse().unwrap();
+    assert_eq!(x.norm_cdf().to_string(), "0.9750021048517795659");
+    assert_eq!(AncDec::ZERO.norm_cdf().to_string(), "0.5");
+    assert_eq!(AncDec::MIN.norm_cdf(), AncDec::ZERO);
+    assert_eq!(AncDec::MAX.norm_cdf(), AncDec::ONE);
+}
+
+#[test]
+fn test_norm_inv_cdf() {
+    let p: AncDec = "0.975".parse().unwrap();
+    assert_eq!(p.norm_inv_cdf().to_string(), "1.9599639845400542355");
+    assert_eq!(p.norm_inv_cdf().norm_cdf(), p);
+    assert_eq!((AncDec::ONE - p).norm_inv_cdf(), -p.norm_inv_cdf());
+    assert_eq!("0.5".parse::<AncDec>().unwrap().norm_inv_cdf(), AncDec::ZERO);
+    assert_eq!(AncDec::MIN_POSITIVE.norm_inv_cdf().to_string(), "-9.0132711531266742813");
+    assert_eq!(AncDec::ZERO.try_norm_inv_cdf(), Err(ArithmeticError::OutOfDomain));
+    assert_eq!(AncDec::ONE.checked_norm_inv_cdf(), None);
+}
+
+#[test]
+#[cfg(not(feature = "panic-free"))]
+#[should_panic(expected = "argument out of domain")]
+fn test_norm_inv_cdf_out_of_domain_panics() {
+    AncDec::TWO.norm_inv_cdf();
+}
+
+// ============ Number Theory ============
+#[test]
+fn test_gcd_lcm() {
+    let a: AncDec = "0.25".parse().unwrap();
+    let b: AncDec = "0.1".parse().unwrap();
+    assert_eq!(a.gcd(&b).to_string(), "0.05");
+    assert_eq!(a.lcm(&b).to_string(), "0.50");
+    assert_eq!((-a).gcd(&b), a.gcd(&b));
+    assert_eq!(a.gcd(&AncDec::ZERO), a);
+    assert_eq!(a.lcm(&AncDec::ZERO), AncDec::ZERO);
+    let x: AncDec = "12".parse().unwrap();
+    let y: AncDec = "18".parse().unwrap();
+    assert_eq!(x.gcd(&y).to_string(), "6");
+    assert_eq!(x.lcm(&y).to_string(), "36");
+}
+
+#[test]
+fn test_lcm_overflow() {
+    let a: AncDec = "18000000000000000000".parse().unwrap();
+    let b = a - AncDec::ONE;
+    assert_eq!(a.checked_lcm(&b), None);
+    assert_eq!(a.try_lcm(&b), Err(ArithmeticError::Overflow));
+}
+
+#[test]
+fn test_to_fraction() {
+    let a: AncDec = "0.375".parse().unwrap();
+    assert_eq!(a.to_fraction(), (3, 8));
+    let b: AncDec = "-2.50".parse().unwrap();
+    assert_eq!(b.to_fraction(), (-5, 2));
+    assert_eq!(AncDec::from(7).to_fraction(), (7, 1));
+    assert_eq!(AncDec::ZERO.to_fraction(), (0, 1));
+}
+
+#[test]
+fn test_approximate_fraction() {
+    let a: AncDec = "101.53125".parse().unwrap();
+    assert_eq!(a.approximate_fraction(32), (3249, 32));
+    let b: AncDec = "-2.718281828".parse().unwrap();
+    assert_eq!(b.approximate_fraction(1000), (-1457, 536));
+    let d: AncDec = "1.5".parse().unwrap();
+    assert_eq!(d.approximate_fraction(1), (1, 1));
+    assert_eq!((-d).approximate_fraction(1), (-2, 1));
+    let e: AncDec = "-332285.5".parse().unwrap();
+    assert_eq!(e.approximate_fraction(1), (-332286, 1));
+    let f: AncDec = "-0.5".parse().unwrap();
+    assert_eq!(f.approximate_fraction(1), (-1, 1));
+}
+
+#[test]
+fn test_fraction_overflow() {
+    // numerator 184467440737095516150000000000000000001 > i128::MAX
+    let a: AncDec = "18446744073709551615.0000000000000000001".parse().unwrap();
+    assert_eq!(a.try_to_fraction(), Err(ArithmeticError::Overflow));
+    assert_eq!(a.try_approximate_fraction(10), Ok((18446744073709551615, 1)));
+    assert_eq!(a.try_approximate_fraction(u128::MAX), Err(ArithmeticError::Overflow));
+}
+
 // ============ Conversion ============
 #[test]
 fn test_to_f64() {
@@ -612,6 +939,582 @@ fn test_product() {
     assert_eq!(prod, "24".parse().unwrap());
 }
 
+// ============ Checked Arithmetic ============
+#[test]
+fn test_checked_div() {
+    let a: AncDec = "10".parse().unwrap();
+    let b: AncDec = "4".parse().unwrap();
+    assert_eq!(a.checked_div(&b), Some("2.5".parse().unwrap()));
+    assert_eq!(a.checked_div(&AncDec::ZERO), None);
+    // quotient integer part exceeds u64
+    let tiny: AncDec = "0.0000000000000000001".parse().unwrap();
+    assert_eq!(AncDec::MAX.checked_div(&tiny), None);
+}
+
+#[test]
+fn test_checked_rem() {
+    let a: AncDec = "10.5".parse().unwrap();
+    let b: AncDec = "3".parse().unwrap();
+    assert_eq!(a.checked_rem(&b), Some("1.5".parse().unwrap()));
+    assert_eq!(a.checked_rem(&AncDec::ZERO), None);
+}
+
+#[test]
+fn test_checked_pow() {
+    let a: AncDec = "1.5".parse().unwrap();
+    assert_eq!(a.checked_pow(2), Some("2.25".parse().unwrap()));
+    assert_eq!(AncDec::TWO.checked_pow(-2), Some("0.25".parse().unwrap()));
+    assert_eq!(AncDec::TWO.checked_pow(64), None);
+    assert_eq!(AncDec::ZERO.checked_pow(-1), None);
+    // 2^63 fits even though squaring the base once more would not
+    assert_eq!(AncDec::TWO.checked_pow(63), Some(AncDec::from(1u64 << 63)));
+}
+
+#[test]
+fn test_checked_sqrt_and_neg() {
+    let a: AncDec = "4".parse().unwrap();
+    assert_eq!(a.checked_sqrt(), Some(AncDec::TWO));
+    assert_eq!((-a).checked_sqrt(), None);
+    assert_eq!("-0".parse::<AncDec>().unwrap().checked_sqrt(), Some(AncDec::ZERO));
+    assert_eq!(a.checked_neg(), Some(-a));
+}
+
+#[test]
+fn test_checked_mul_wide_overflow() {
+    let big: AncDec = "18446744073709551615.9999999999999999999".parse().unwrap();
+    assert_eq!(big.checked_mul(&big), None);
+}
+
+#[test]
+#[cfg(not(feature = "panic-free"))]
+#[should_panic(expected = "arithmetic overflow")]
+fn test_div_overflow_panics() {
+    let tiny: AncDec = "0.0000000000000000001".parse().unwrap();
+    let _ = AncDec::MAX / tiny;
+}
+
+// ============ Saturating / Overflowing ============
+#[test]
+fn test_saturating_add_sub() {
+    let one = AncDec::ONE;
+    assert_eq!(AncDec::MAX.saturating_add(&one), AncDec::MAX);
+    assert_eq!((-AncDec::MAX).saturating_add(&-one), -AncDec::MAX);
+    assert_eq!((-AncDec::MAX).saturating_sub(&one), -AncDec::MAX);
+    assert_eq!(AncDec::MAX.saturating_sub(&-one), AncDec::MAX);
+    assert_eq!(one.saturating_add(&one), AncDec::TWO);
+}
+
+#[test]
+fn test_checked_sub_opposite_signs_overflow() {
+    assert_eq!(AncDec::MAX.checked_sub(&-AncDec::ONE), None);
+}
+
+#[test]
+fn test_saturating_mul_div() {
+    let tiny: AncDec = "0.0000000000000000001".parse().unwrap();
+    assert_eq!(AncDec::MAX.saturating_mul(&AncDec::TWO), AncDec::MAX);
+    assert_eq!(AncDec::MAX.saturating_mul(&-AncDec::TWO), -AncDec::MAX);
+    assert_eq!((-AncDec::MAX).saturating_div(&tiny), -AncDec::MAX);
+    assert_eq!(AncDec::ONE.saturating_div(&AncDec::ZERO), AncDec::MAX);
+    assert_eq!(AncDec::ZERO.saturating_div(&AncDec::ZERO), AncDec::ZERO);
+}
+
+#[test]
+fn test_saturating_pow() {
+    assert_eq!(AncDec::TEN.saturating_pow(21), AncDec::MAX);
+    assert_eq!((-AncDec::TEN).saturating_pow(21), -AncDec::MAX);
+    assert_eq!((-AncDec::TEN).saturating_pow(22), AncDec::MAX);
+    assert_eq!(AncDec::ZERO.saturating_pow(-1), AncDec::MAX);
+    assert_eq!(AncDec::TWO.saturating_pow(2), "4".parse().unwrap());
+}
+
+#[test]
+fn test_overflowing_ops() {
+    let two = AncDec::TWO;
+    assert_eq!(two.overflowing_add(&two), ("4".parse().unwrap(), false));
+    assert_eq!(AncDec::MAX.overflowing_add(&two), (AncDec::MAX, true));
+    assert_eq!(AncDec::MAX.overflowing_sub(&-two), (AncDec::MAX, true));
+    assert_eq!(AncDec::MAX.overflowing_mul(&two), (AncDec::MAX, true));
+    assert_eq!(two.overflowing_div(&AncDec::ZERO), (AncDec::MAX, true));
+    assert_eq!(two.overflowing_pow(2), ("4".parse().unwrap(), false));
+    assert_eq!(two.overflowing_pow(-1), ("0.5".parse().unwrap(), false));
+}
+
+// ============ Try Operations ============
+#[test]
+fn test_try_arithmetic() {
+    let a: AncDec = "1.5".parse().unwrap();
+    assert_eq!(a.try_add(&a), Ok(AncDec::from(3)));
+    assert_eq!(a.try_sub(&a), Ok(AncDec::ZERO));
+    assert_eq!(AncDec::TWO.try_mul(&a), Ok(AncDec::from(3)));
+    assert_eq!(a.try_div(&a), Ok(AncDec::ONE));
+    assert_eq!(AncDec::from(7).try_rem(&AncDec::TWO), Ok(AncDec::ONE));
+}
+
+#[test]
+fn test_try_arithmetic_errors() {
+    assert_eq!(AncDec::MAX.try_add(&AncDec::ONE), Err(ArithmeticError::Overflow));
+    assert_eq!((-AncDec::MAX).try_sub(&AncDec::ONE), Err(ArithmeticError::Overflow));
+    assert_eq!(AncDec::MAX.try_mul(&AncDec::TWO), Err(ArithmeticError::Overflow));
+    assert_eq!(AncDec::ONE.try_div(&AncDec::ZERO), Err(ArithmeticError::DivisionByZero));
+    assert_eq!(AncDec::ONE.try_rem(&AncDec::ZERO), Err(ArithmeticError::DivisionByZero));
+}
+
+#[test]
+fn test_try_pow_sqrt() {
+    assert_eq!(AncDec::TWO.try_pow(3), Ok(AncDec::from(8)));
+    assert_eq!(AncDec::ZERO.try_pow(-1), Err(ArithmeticError::DivisionByZero));
+    assert_eq!(AncDec::MAX.try_pow(2), Err(ArithmeticError::Overflow));
+    assert_eq!(AncDec::from(4).try_sqrt().unwrap(), AncDec::TWO);
+    assert_eq!((-AncDec::ONE).try_sqrt(), Err(ArithmeticError::NegativeSqrt));
+}
+
+#[test]
+fn test_try_to_i64() {
+    assert_eq!(AncDec::from(-42i64).try_to_i64(), Ok(-42));
+    assert_eq!(AncDec::from(i64::MIN).try_to_i64(), Ok(i64::MIN));
+    assert_eq!(AncDec::from(u64::MAX).try_to_i64(), Err(ArithmeticError::Overflow));
+}
+
+#[test]
+fn test_arithmetic_error_display() {
+    assert_eq!(ArithmeticError::DivisionByZero.to_string(), "division by zero");
+    assert_eq!(ArithmeticError::Overflow.to_string(), "arithmetic overflow");
+}
+
+#[test]
+#[cfg(feature = "panic-free")]
+fn test_panic_free_saturates() {
+    assert_eq!(AncDec::MAX + AncDec::ONE, AncDec::MAX);
+    assert_eq!(AncDec::MAX * -AncDec::TWO, -AncDec::MAX);
+    assert_eq!(AncDec::ONE / AncDec::ZERO, AncDec::MAX);
+    assert_eq!(AncDec::ONE % AncDec::ZERO, AncDec::ZERO);
+    assert_eq!((-AncDec::ONE).sqrt(), AncDec::ZERO);
+}
+
+#[test]
+#[cfg(not(feature = "panic-free"))]
+#[should_panic(expected = "scale overflow")]
+fn test_hand_built_scale_overflow_panics() {
+    let a = AncDec { int: 1, frac: 5, scale: 25, neg: false };
+    let _ = a + AncDec::ONE;
+}
+
+#[test]
+#[cfg(feature = "panic-free")]
+fn test_hand_built_scale_overflow_does_not_panic() {
+    // the result is unspecified; only the absence of a panic is guaranteed
+    let a = AncDec { int: 1, frac: 5, scale: 25, neg: false };
+    let _ = (a + AncDec::ONE, a * a, a / AncDec::TWO, a.round(3, RoundMode::HalfEven), a.to_string());
+}
+
+#[test]
+fn test_round_up_half_odd_05up() {
+    let a: AncDec = "-0.0000000000000000001".parse().unwrap();
+    assert_eq!(a.round(18, RoundMode::Up).to_string(), "-0.000000000000000001");
+    let b: AncDec = "4.45".parse().unwrap();
+    assert_eq!(b.round(1, RoundMode::HalfOdd).to_string(), "4.5");
+    assert_eq!(b.round(1, RoundMode::HalfEven).to_string(), "4.4");
+    let c: AncDec = "1.051".parse().unwrap();
+    assert_eq!(c.round(2, RoundMode::ZeroFiveUp).to_string(), "1.06");
+    assert_eq!(c.round(1, RoundMode::ZeroFiveUp).to_string(), "1.1");
+    let d: AncDec = "1.061".parse().unwrap();
+    assert_eq!(d.round(2, RoundMode::ZeroFiveUp).to_string(), "1.06");
+    assert_eq!(AncDec::ONE.div_round(&AncDec::from(3i64), 2, RoundMode::Up).to_string(), "0.34");
+}
+
+#[test]
+fn test_round_mode_from_str_display() {
+    let modes = [
+        RoundMode::Floor, RoundMode::Ceil, RoundMode::Truncate, RoundMode::Up, RoundMode::HalfUp,
+        RoundMode::HalfDown, RoundMode::HalfEven, RoundMode::HalfOdd, RoundMode::ZeroFiveUp,
+        RoundMode::Fract,
+    ];
+    for m in modes {
+        assert_eq!(m.to_string().parse::<RoundMode>(), Ok(m));
+    }
+    assert_eq!(RoundMode::HalfEven.to_string(), "HalfEven");
+    assert_eq!("half_even".parse(), Ok(RoundMode::HalfEven));
+    assert_eq!("ROUND_HALF_EVEN".parse(), Ok(RoundMode::HalfEven));
+    assert_eq!("ROUND_05UP".parse(), Ok(RoundMode::ZeroFiveUp));
+    assert_eq!("CEILING".parse(), Ok(RoundMode::Ceil));
+    assert_eq!("DOWN".parse(), Ok(RoundMode::Truncate));
+    assert_eq!("up".parse(), Ok(RoundMode::Up));
+    assert_eq!("nearest".parse::<RoundMode>(), Err(ancdec::ParseRoundModeError));
+    assert!("a_very_long_rounding_mode_name".parse::<RoundMode>().is_err());
+}
+
+/// xorshift64: deterministic RNG for the stochastic rounding tests
+#[cfg(feature = "rand")]
+struct XorShift(u64);
+
+#[cfg(feature = "rand")]
+impl rand_core::RngCore for XorShift {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        rand_core::impls::fill_bytes_via_next(self, dst)
+    }
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_round_stochastic() {
+    let mut rng = XorShift(0x9E37_79B9_7F4A_7C15);
+    let a: AncDec = "-7.75".parse().unwrap();
+    let (lo, hi): (AncDec, AncDec) = ("-7".parse().unwrap(), "-8".parse().unwrap());
+    let mut ups = 0;
+    for _ in 0..4000 {
+        let r = a.round_stochastic(0, &mut rng);
+        assert!(r == lo || r == hi);
+        ups += (r == hi) as u32;
+    }
+    // rounds up three quarters of the time; the bounds are about 4.5 standard deviations
+    assert!((2880..=3120).contains(&ups), "{}", ups);
+    // nothing discarded: never rounds
+    let b: AncDec = "-7.0".parse().unwrap();
+    assert_eq!(b.round_stochastic(0, &mut rng), b.round(0, RoundMode::Truncate));
+}
+
+// ============ Rounded Arithmetic ============
+#[test]
+fn test_mul_round() {
+    let a: AncDec = "1.25".parse().unwrap();
+    let b: AncDec = "1.5".parse().unwrap();
+    assert_eq!(a.mul_round(&b, 2, RoundMode::HalfEven).to_string(), "1.88");
+    assert_eq!(a.mul_round(&b, 2, RoundMode::HalfDown).to_string(), "1.87");
+    assert_eq!((-a).mul_round(&b, 1, RoundMode::Floor).to_string(), "-1.9");
+    // exact products keep their scale
+    assert_eq!(b.mul_round(&b, 4, RoundMode::HalfUp).to_string(), "2.25");
+}
+
+#[test]
+fn test_mul_round_half_below_target() {
+    // 5e-10 * 1e-10 = 5e-20, exactly half a unit at 19 places
+    let a: AncDec = "0.0000000005".parse().unwrap();
+    let b: AncDec = "0.0000000001".parse().unwrap();
+    assert_eq!(a.mul_round(&b, 19, RoundMode::HalfUp).to_string(), "0.0000000000000000001");
+    assert_eq!(a.mul_round(&b, 19, RoundMode::HalfEven), AncDec::ZERO);
+}
+
+#[test]
+fn test_div_round() {
+    let two = AncDec::TWO;
+    let three = AncDec::from(3);
+    assert_eq!(two.div_round(&three, 4, RoundMode::HalfEven).to_string(), "0.6667");
+    assert_eq!(AncDec::ONE.div_round(&three, 2, RoundMode::Ceil).to_string(), "0.34");
+    assert_eq!((-AncDec::ONE).div_round(&three, 2, RoundMode::Floor).to_string(), "-0.34");
+    let x: AncDec = "2.5000000000000000001".parse().unwrap();
+    assert_eq!(x.div_round(&AncDec::ONE, 0, RoundMode::HalfEven).to_string(), "3");
+    // large operands take the u256 path
+    let big = AncDec::from(u64::MAX);
+    assert_eq!(big.div_round(&three, 0, RoundMode::HalfUp).to_string(), "6148914691236517205");
+}
+
+#[test]
+fn test_checked_mul_div_round() {
+    assert_eq!(AncDec::MAX.checked_mul_round(&AncDec::TWO, 2, RoundMode::HalfUp), None);
+    assert_eq!(AncDec::MAX.checked_div_round(&AncDec::ZERO, 2, RoundMode::HalfUp), None);
+    assert_eq!(
+        AncDec::ONE.checked_div_round(&AncDec::TWO, 0, RoundMode::HalfUp),
+        Some(AncDec::ONE)
+    );
+}
+
+#[test]
+#[cfg(not(feature = "panic-free"))]
+#[should_panic(expected = "division by zero")]
+fn test_div_round_by_zero() {
+    AncDec::ONE.div_round(&AncDec::ZERO, 2, RoundMode::HalfUp);
+}
+
+// ============ Fused Multiply-Divide ============
+#[test]
+fn test_mul_div() {
+    let price: AncDec = "19.99".parse().unwrap();
+    let qty = AncDec::from(3);
+    let fx: AncDec = "1.2345".parse().unwrap();
+    assert_eq!(
+        price.mul_div(&qty, &fx, RoundMode::HalfEven).unwrap().to_string(),
+        "48.5783718104495747266"
+    );
+    let two = AncDec::TWO;
+    let three = AncDec::from(3);
+    assert_eq!(
+        two.mul_div(&AncDec::ONE, &three, RoundMode::Floor).unwrap().to_string(),
+        "0.6666666666666666666"
+    );
+    assert_eq!(
+        (-two).mul_div(&AncDec::ONE, &three, RoundMode::Floor).unwrap().to_string(),
+        "-0.6666666666666666667"
+    );
+}
+
+#[test]
+fn test_mul_div_wide_intermediate() {
+    // MAX * MAX overflows, but the quotient fits
+    assert_eq!(AncDec::MAX.mul_div(&AncDec::MAX, &AncDec::MAX, RoundMode::HalfUp), Some(AncDec::MAX));
+    assert_eq!(AncDec::MAX.mul_div(&AncDec::TWO, &AncDec::ONE, RoundMode::HalfUp), None);
+    assert_eq!(AncDec::ONE.mul_div(&AncDec::ONE, &AncDec::ZERO, RoundMode::HalfUp), None);
+}
+
+#[test]
+fn test_mul_div_rounds_once() {
+    // product has 20 places, so the quotient is reduced by a further 10
+    let a: AncDec = "0.0000000000000000045".parse().unwrap();
+    let b: AncDec = "0.1".parse().unwrap();
+    let three = AncDec::from(3);
+    // 1.5e-19: exactly half
+    assert_eq!(a.mul_div(&b, &three, RoundMode::HalfDown).unwrap().to_string(), "0.0000000000000000001");
+    // 1.5333e-19: the remainder of the division by 3 tips it above half
+    let a: AncDec = "0.0000000000000000046".parse().unwrap();
+    assert_eq!(a.mul_div(&b, &three, RoundMode::HalfDown).unwrap().to_string(), "0.0000000000000000002");
+}
+
+// ============ Integer Division ============
+#[test]
+fn test_divmod() {
+    let a: AncDec = "7.5".parse().unwrap();
+    let b = AncDec::TWO;
+    let (q, r) = a.divmod(&b);
+    assert_eq!(q, AncDec::from(3));
+    assert_eq!(r.to_string(), "1.5");
+    let (q, r) = (-a).divmod(&b);
+    assert_eq!(q.to_string(), "-3");
+    assert_eq!(r.to_string(), "-1.5");
+    assert_eq!(a.checked_divmod(&AncDec::ZERO), None);
+}
+
+#[test]
+fn test_div_mod_floor() {
+    let a: AncDec = "-7.5".parse().unwrap();
+    let b = AncDec::TWO;
+    assert_eq!(a.div_floor(&b).to_string(), "-4");
+    assert_eq!(a.mod_floor(&b).to_string(), "0.5");
+    assert_eq!((-a).mod_floor(&-b).to_string(), "-0.5");
+    assert_eq!(b.checked_mod_floor(&AncDec::ZERO), None);
+}
+
+#[test]
+fn test_div_rem_euclid() {
+    let a: AncDec = "-7.5".parse().unwrap();
+    let b = AncDec::TWO;
+    assert_eq!(a.div_euclid(&b).to_string(), "-4");
+    assert_eq!(a.rem_euclid(&b).to_string(), "0.5");
+    assert_eq!(a.div_euclid(&-b).to_string(), "4");
+    assert_eq!(a.rem_euclid(&-b).to_string(), "0.5");
+    // exact division leaves no adjustment
+    assert_eq!(AncDec::from(6).rem_euclid(&-b), AncDec::ZERO);
+}
+
+#[test]
+fn test_int_division_i128() {
+    let a: AncDec = "-7.5".parse().unwrap();
+    let b = AncDec::TWO;
+    assert_eq!(a.divmod_i128(&b), Some((-3, "-1.5".parse().unwrap())));
+    assert_eq!(a.div_floor_i128(&b), Some(-4));
+    assert_eq!(a.div_euclid_i128(&-b), Some(4));
+    assert_eq!(a.div_floor_i128(&AncDec::ZERO), None);
+}
+
+#[test]
+fn test_rem_large_quotient() {
+    // the quotient overflows, but the remainder is exact
+    let a = AncDec::from(u64::MAX);
+    let b: AncDec = "0.0000000000000000007".parse().unwrap();
+    assert_eq!(a.checked_div_floor(&b), None);
+    assert_eq!(a.div_floor_i128(&b), Some(26352491533870788021428571428571428571));
+    assert_eq!((a % b).to_string(), "0.0000000000000000003");
+    assert_eq!((-a).rem_euclid(&b).to_string(), "0.0000000000000000004");
+    // beyond i128 range
+    let tiny: AncDec = "0.0000000000000000001".parse().unwrap();
+    assert_eq!(AncDec::MAX.div_floor_i128(&tiny), None);
+}
+
+// ============ Minimal-scale Division ============
+#[test]
+fn test_div_min_scale_terminating() {
+    let one = AncDec::ONE;
+    let q = one.div_min_scale(&AncDec::TWO, 0, RoundMode::HalfEven);
+    assert_eq!(q.to_string(), "0.5");
+    assert_eq!(q.scale, 1);
+    let ten: AncDec = "10".parse().unwrap();
+    assert_eq!(ten.div_min_scale(&"4".parse().unwrap(), 0, RoundMode::HalfEven).to_string(), "2.5");
+    let six: AncDec = "6".parse().unwrap();
+    assert_eq!(six.div_min_scale(&"0.3".parse().unwrap(), 0, RoundMode::HalfEven).to_string(), "20");
+    let eight: AncDec = "8".parse().unwrap();
+    assert_eq!(one.div_min_scale(&eight, 2, RoundMode::HalfEven).to_string(), "0.125");
+}
+
+#[test]
+fn test_div_min_scale_non_terminating() {
+    let three: AncDec = "3".parse().unwrap();
+    assert_eq!(AncDec::ONE.div_min_scale(&three, 1, RoundMode::HalfUp).to_string(), "0.3");
+    assert_eq!(AncDec::TWO.div_min_scale(&three, 2, RoundMode::HalfEven).to_string(), "0.67");
+    assert_eq!((-AncDec::TWO).div_min_scale(&three, 1, RoundMode::Floor).to_string(), "-0.7");
+    assert_eq!(AncDec::ONE.checked_div_min_scale(&AncDec::ZERO, 2, RoundMode::HalfUp), None);
+}
+
+// ============ Exact Arithmetic ============
+#[test]
+fn test_mul_div_exact() {
+    let a: AncDec = "0.0000000001".parse().unwrap();
+    assert_eq!(a.mul_exact(&"0.000000001".parse().unwrap()).unwrap().scale, 19);
+    assert_eq!(a.mul_exact(&a), Err(ArithmeticError::Inexact));
+    let price: AncDec = "19.99".parse().unwrap();
+    assert_eq!(price.mul_exact(&"0.0825".parse().unwrap()).unwrap().to_string(), "1.649175");
+    assert_eq!(price.div_exact(&AncDec::from(4i64)).unwrap().to_string(), "4.9975");
+    assert_eq!(price.div_exact(&AncDec::from(3i64)), Err(ArithmeticError::Inexact));
+    assert_eq!(AncDec::MAX.div_exact(&"0.5".parse().unwrap()), Err(ArithmeticError::Overflow));
+}
+
+#[test]
+fn test_add_parse_exact() {
+    assert_eq!(AncDec::MAX.add_exact(&AncDec::ONE), Err(ArithmeticError::Overflow));
+    assert_eq!((-AncDec::MAX).sub_exact(&AncDec::ONE), Err(ArithmeticError::Overflow));
+    assert_eq!(AncDec::parse_exact("1.00000000000000000001"), Err(ParseError::Inexact));
+    assert_eq!(AncDec::parse_exact("1.00000000000000000000").unwrap(), AncDec::ONE);
+    assert_eq!(ParseError::Inexact.to_string(), "nonzero digits beyond the maximum scale");
+}
+
+// ============ Scale Management ============
+#[test]
+fn test_normalize_trailing_zeros() {
+    let q = AncDec::TWO.div(&AncDec::ONE);
+    let n = q.normalize();
+    assert_eq!((n.int, n.frac, n.scale), (2, 0, 0));
+    assert_eq!(q.trailing_zeros(), q.scale);
+    let a: AncDec = "0.1000000000000000000".parse().unwrap();
+    assert_eq!(a.trailing_zeros(), 18);
+    assert_eq!(a.normalize().scale, 1);
+    let b: AncDec = "7.0000000000000000001".parse().unwrap();
+    assert_eq!(b.trailing_zeros(), 0);
+    assert!(!b.is_integer());
+    assert_eq!(b.normalize(), b);
+}
+
+#[test]
+fn test_rescale() {
+    let a: AncDec = "-1.25".parse().unwrap();
+    assert_eq!(a.rescale(1, RoundMode::HalfEven).to_string(), "-1.2");
+    assert_eq!(a.rescale(1, RoundMode::HalfUp).to_string(), "-1.3");
+    assert_eq!(a.rescale(1, RoundMode::Floor).to_string(), "-1.3");
+    assert_eq!(a.rescale(19, RoundMode::Floor).scale, 19);
+    assert_eq!(a.with_scale(4).unwrap().to_string(), "-1.2500");
+    assert_eq!(AncDec::MAX.checked_rescale(0, RoundMode::Ceil), None);
+    assert_eq!(AncDec::ONE.checked_rescale(20, RoundMode::Ceil), None);
+}
+
+// ============ Increment Rounding ============
+#[test]
+fn test_round_to_increment() {
+    let quarter: AncDec = "0.25".parse().unwrap();
+    let a: AncDec = "123456789.1234567891".parse().unwrap();
+    assert_eq!(a.round_to_increment(&quarter, RoundMode::HalfUp).to_string(), "123456789.00");
+    assert_eq!(a.round_to_increment(&quarter, RoundMode::Up).to_string(), "123456789.25");
+    let thousand: AncDec = "1000".parse().unwrap();
+    assert_eq!((-a).round_to_increment(&thousand, RoundMode::HalfEven).to_string(), "-123457000");
+    // 0.375 / 0.25 = 1.5 and 0.625 / 0.25 = 2.5 both tie to 0.50
+    let t1: AncDec = "0.375".parse().unwrap();
+    let t2: AncDec = "0.625".parse().unwrap();
+    assert_eq!(t1.round_to_increment(&quarter, RoundMode::HalfEven).to_string(), "0.50");
+    assert_eq!(t2.round_to_increment(&quarter, RoundMode::HalfEven).to_string(), "0.50");
+    assert_eq!(t2.round_to_increment(&quarter, RoundMode::HalfOdd).to_string(), "0.75");
+    let ten: AncDec = "10".parse().unwrap();
+    assert_eq!(AncDec::MAX.checked_round_to_increment(&ten, RoundMode::Up), None);
+    assert_eq!(a.checked_round_to_increment(&AncDec::ZERO, RoundMode::HalfUp), None);
+}
+
+#[test]
+fn test_is_multiple_of() {
+    let quarter: AncDec = "0.25".parse().unwrap();
+    assert!("-1234567.75".parse::<AncDec>().unwrap().is_multiple_of(&quarter));
+    assert!(!"0.2500000000000000001".parse::<AncDec>().unwrap().is_multiple_of(&quarter));
+    assert!(AncDec::ZERO.is_multiple_of(&AncDec::ZERO));
+}
+
+// ============ Significant Digits ============
+#[test]
+fn test_digit_introspection() {
+    let a: AncDec = "18446744073709551615.5".parse().unwrap();
+    assert_eq!((a.int_digits(), a.leading_frac_zeros(), a.precision()), (20, 0, 21));
+    let b: AncDec = "0.0000000000000000012".parse().unwrap();
+    assert_eq!((b.int_digits(), b.leading_frac_zeros(), b.precision()), (0, 17, 2));
+    assert_eq!(b.mantissa_exponent(), (12, -19));
+    assert_eq!(a.mantissa_exponent(), (184467440737095516155, -1));
+    // 39 significant digits do not fit i128
+    let max: AncDec = "-18446744073709551615.9999999999999999999".parse().unwrap();
+    assert_eq!(max.try_mantissa_exponent(), Err(ArithmeticError::Overflow));
+}
+
+#[test]
+fn test_round_sig() {
+    let a: AncDec = "98765432109876.54321".parse().unwrap();
+    assert_eq!(a.round_sig(3, RoundMode::HalfUp).to_string(), "98800000000000");
+    assert_eq!(a.round_sig(16, RoundMode::HalfEven).to_string(), "98765432109876.54");
+    assert_eq!((-a).round_sig(1, RoundMode::Ceil).to_string(), "-90000000000000");
+    let b: AncDec = "0.00000000000000123456".parse().unwrap();
+    assert_eq!(b.round_sig(2, RoundMode::Up).to_string(), "0.0000000000000013");
+    assert_eq!("99.96".parse::<AncDec>().unwrap().round_sig(3, RoundMode::HalfUp).to_string(), "100");
+}
+
+// ============ Context ============
+#[test]
+fn test_context_add_mul_div() {
+    let mut ctx = Context::new(2, RoundMode::HalfEven);
+    let price: AncDec = "19.99".parse().unwrap();
+    let rate: AncDec = "0.0825".parse().unwrap();
+    assert_eq!(ctx.mul(&price, &rate).unwrap().to_string(), "1.65");
+    assert_eq!(ctx.flags(), Signal::Rounded | Signal::Inexact);
+    ctx.clear_flags();
+    assert_eq!(ctx.add(&price, &price).unwrap().to_string(), "39.98");
+    assert_eq!(ctx.div(&price, &AncDec::TWO).unwrap().to_string(), "10.00");
+    assert_eq!(ctx.flags(), Signal::Rounded | Signal::Inexact);
+
+    let mut ctx = Context::default();
+    let q = ctx.div(&AncDec::ONE, &AncDec::from(4i64)).unwrap();
+    assert_eq!((q.to_string(), q.scale), ("0.25".into(), 2));
+    assert!(ctx.flags().is_empty());
+    assert_eq!(ctx.div(&AncDec::ONE, &AncDec::from(3i64)).unwrap().to_string(), "0.3333333333333333333");
+}
+
+#[test]
+fn test_context_traps() {
+    let mut ctx = Context::default();
+    let big: AncDec = "10000000000000000000".parse().unwrap();
+    assert_eq!(ctx.add(&big, &big), Err(Signal::Overflow));
+    assert_eq!(ctx.mul(&big, &AncDec::TWO), Err(Signal::Overflow));
+    assert_eq!(ctx.div(&AncDec::ZERO, &AncDec::ZERO), Err(Signal::DivisionByZero));
+    ctx.traps = Signals::EMPTY;
+    assert_eq!(ctx.div(&AncDec::ZERO, &AncDec::ZERO), Ok(AncDec::ZERO));
+    assert_eq!(ctx.mul(&-big, &AncDec::TWO), Ok(-AncDec::MAX));
+    assert_eq!(ctx.flags(), Signal::Overflow | Signal::DivisionByZero | Signal::Inexact | Signal::Rounded);
+    assert_eq!(format!("{:?}", ctx.flags()), "Signals { DivisionByZero, Overflow, Inexact, Rounded }");
+    assert_eq!(format!("{:?}", Signals::EMPTY), "Signals {}");
+}
+
+#[test]
+fn test_context_parse_clamped() {
+    let mut ctx = Context::default();
+    let a: AncDec = ctx.parse("0.12345678901234567890").unwrap();
+    assert_eq!(a.scale, 19);
+    assert_eq!(ctx.flags(), Signals::from(Signal::Clamped));
+    let _: AncDec = ctx.parse("0.12345678901234567891").unwrap();
+    assert!(ctx.flags().contains(Signal::Inexact));
+
+    let mut ctx = Context::new(0, RoundMode::Floor).with_traps(Signal::Inexact.into());
+    assert_eq!(ctx.parse::<AncDec>("-7"), Ok(AncDec::from(-7i64)));
+    assert_eq!(ctx.parse::<AncDec>("-7.5"), Err(ParseError::Trapped(Signal::Inexact)));
+    assert_eq!(ParseError::Trapped(Signal::Inexact).to_string(), "trapped signal: inexact");
+}
+
 // ============ Serde (only with feature) ============
 #[cfg(feature = "serde")]
 #[test]
@@ -640,6 +1543,15 @@ fn test_serde_invalid() {
     assert!(result.is_err());
 }
 
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_round_mode() {
+    let json = serde_json::to_string(&RoundMode::ZeroFiveUp).unwrap();
+    assert_eq!(json, "\"ZeroFiveUp\"");
+    assert_eq!(serde_json::from_str::<RoundMode>("\"ROUND_HALF_UP\"").unwrap(), RoundMode::HalfUp);
+    assert!(serde_json::from_str::<RoundMode>("\"sideways\"").is_err());
+}
+
 // ============ Constants ============
 #[test]
 fn test_constants() {
@@ -649,6 +1561,40 @@ fn test_constants() {
     assert_eq!(AncDec::TEN.int, 10);
 }
 
+#[test]
+fn test_min_epsilon_constants() {
+    assert_eq!(AncDec::MIN, -AncDec::MAX);
+    assert_eq!(AncDec::MIN.to_string(), "-18446744073709551615.9999999999999999999");
+    assert_eq!(AncDec::MIN_POSITIVE.to_string(), "0.0000000000000000001");
+    assert_eq!(AncDec::EPSILON, AncDec::MIN_POSITIVE);
+}
+
+#[test]
+fn test_ulp_next_up_down() {
+    let a: AncDec = "100".parse().unwrap();
+    assert_eq!(a.ulp(), AncDec::ONE);
+    assert_eq!(a.next_up().to_string(), "100.0000000000000000001");
+    assert_eq!(a.next_down().to_string(), "99.9999999999999999999");
+    // next_up/next_down step by EPSILON, not by the value's own ulp
+    assert_eq!(a.next_up() - a, AncDec::EPSILON);
+    assert_eq!(a - a.next_down(), AncDec::EPSILON);
+    assert_ne!(a.next_up() - a, a.ulp());
+    assert_eq!(AncDec::MIN_POSITIVE.ulp(), AncDec::MIN_POSITIVE);
+    assert_eq!(AncDec::ZERO.next_up(), AncDec::MIN_POSITIVE);
+}
+
+#[test]
+fn test_math_constants() {
+    assert_eq!(AncDec::PI.to_string(), "3.1415926535897932385");
+    assert_eq!(AncDec::PI, (-AncDec::ONE).acos());
+    assert_eq!(AncDec::E, AncDec::ONE.exp());
+    assert_eq!(AncDec::LN_2, AncDec::TWO.ln());
+    assert_eq!(AncDec::LN_10.to_string(), "2.3025850929940456840");
+    assert_eq!(AncDec::SQRT_2, AncDec::TWO.sqrt_round(19, RoundMode::HalfEven));
+    let half: AncDec = "0.5".parse().unwrap();
+    assert_eq!(AncDec::FRAC_1_SQRT_2, half.sqrt_round(19, RoundMode::HalfEven));
+}
+
 // ============ Edge Cases ============
 #[test]
 fn test_zero_operations() {
@@ -675,6 +1621,7 @@ fn test_high_precision() {
 }
 
 #[test]
+#[cfg(not(feature = "panic-free"))]
 #[should_panic]
 fn test_div_by_zero_panics() {
     let a: AncDec = "5".parse().unwrap();
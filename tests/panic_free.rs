@@ -0,0 +1,26 @@
+// tests/panic_free.rs
+#![cfg(all(feature = "panic-free", target_os = "linux"))]
+
+use std::process::Command;
+
+/// Builds `tests/panic_free`, a `no_std` cdylib calling every public API whose panic handler
+/// references an undefined symbol, and links it with `--no-undefined`: the link only succeeds
+/// if the optimizer removed every panic path.
+#[test]
+fn test_panic_free_build_links_without_panic_handler() {
+    let root = env!("CARGO_MANIFEST_DIR");
+    let output = Command::new(env!("CARGO"))
+        .args(["build", "--release", "--manifest-path"])
+        .arg(format!("{root}/tests/panic_free/Cargo.toml"))
+        .arg("--target-dir")
+        .arg(format!("{root}/target/panic_free"))
+        .env("RUSTFLAGS", "-C link-arg=-Wl,--no-undefined")
+        .env_remove("CARGO_ENCODED_RUSTFLAGS")
+        .output()
+        .expect("failed to run cargo");
+    assert!(
+        output.status.success(),
+        "panic path reachable with `panic-free`:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
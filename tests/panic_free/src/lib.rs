@@ -0,0 +1,297 @@
+//! Calls every public API of the four types (plus `Context`, `RoundMode` and the error types)
+//! from a `no_std` cdylib whose panic handler references an undefined symbol. Built by
+//! `tests/panic_free.rs` with `--no-undefined`: if any panic path survives optimization the
+//! symbol stays referenced and linking fails.
+//!
+//! The one documented exception, `TryFrom<f32/f64>` (it goes through `core`'s float
+//! formatter), is left out.
+
+#![no_std]
+// every result goes through `black_box`, which is all that matters here
+#![allow(unused_must_use)]
+
+use ancdec::*;
+use core::fmt::Write;
+use core::hint::black_box;
+use core::str::FromStr;
+
+extern "C" {
+    fn __ancdec_panic_is_reachable() -> !;
+}
+
+#[panic_handler]
+fn panic(_: &core::panic::PanicInfo) -> ! {
+    unsafe { __ancdec_panic_is_reachable() }
+}
+
+// memcpy / memset / memcmp
+#[link(name = "c")]
+extern "C" {}
+
+/// Formatting target that drops whatever does not fit
+struct Sink {
+    buf: [u8; 256],
+    pos: usize,
+}
+
+impl Write for Sink {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        for &b in s.as_bytes() {
+            if let Some(slot) = self.buf.get_mut(self.pos) {
+                *slot = b;
+                self.pos += 1;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Deterministic RNG for `round_stochastic`
+struct Rng(u64);
+
+impl rand_core::RngCore for Rng {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1);
+        self.0
+    }
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        for b in dst {
+            *b = self.next_u32() as u8;
+        }
+    }
+}
+
+/// # Safety
+/// `s` must point to `len` readable bytes.
+unsafe fn input<'a>(s: *const u8, len: usize) -> &'a str {
+    core::str::from_utf8(core::slice::from_raw_parts(s, len)).unwrap_or("")
+}
+
+fn format(v: &impl core::fmt::Display) {
+    let mut sink = Sink { buf: [0; 256], pos: 0 };
+    let _ = write!(sink, "{}", v);
+    let _ = write!(sink, "{:>40.3}", v);
+    black_box(&sink.buf);
+}
+
+/// The API every type shares
+macro_rules! check_common {
+    ($name:ident, $T:ty) => {
+        /// # Safety
+        /// `s` must point to `len` readable bytes.
+        #[no_mangle]
+        pub unsafe extern "C" fn $name(a: &$T, b: &$T, c: &$T, k: i64, s: *const u8, len: usize) {
+            let (a, b, c) = (*a, *b, *c);
+            let s = input(s, len);
+            let (n, p, m) = (k as u32, k as i32, k as u8);
+            let mode = black_box(RoundMode::HalfEven);
+
+            // construction, parsing and formatting
+            black_box(<$T>::parse(s));
+            black_box(<$T>::parse(k));
+            black_box(<$T>::parse_exact(s));
+            black_box(<$T>::from_str(s));
+            black_box(<$T>::try_from(s));
+            black_box(s.parse::<$T>());
+            format(&a);
+            black_box(<$T>::default());
+
+            // accessors and introspection
+            black_box((a.is_zero(), a.is_positive(), a.is_negative(), a.is_integer()));
+            black_box((a.precision(), a.int_digits(), a.leading_frac_zeros(), a.trailing_zeros()));
+            black_box(a.mantissa_exponent());
+            black_box(a.is_multiple_of(&b));
+
+            // conversion
+            black_box((a.to_f64(), a.to_i64(), a.to_i128()));
+            black_box(a.to_fraction());
+            black_box(a.approximate_fraction(k as u128));
+
+            // arithmetic
+            black_box((a.add(&b), a.sub(&b), a.mul(&b), a.div(&b), a.rem(&b)));
+            black_box((a + b, a - b, a * b, a / b, a % b, -a));
+            black_box((&a + &b, &a - &b, &a * &b, &a / &b, &a % &b, -&a));
+            black_box((a + &b, a - &b, a * &b, a / &b, a % &b));
+            black_box((&a + b, &a - b, &a * b, &a / b, &a % b));
+            let mut x = a;
+            x += b;
+            x -= c;
+            x *= b;
+            x /= c;
+            x %= b;
+            black_box(x);
+            black_box((a.checked_add(&b), a.checked_sub(&b), a.checked_mul(&b)));
+            black_box((a.checked_div(&b), a.checked_rem(&b), a.checked_neg()));
+            black_box((a.try_add(&b), a.try_sub(&b), a.try_mul(&b), a.try_div(&b), a.try_rem(&b)));
+            black_box((a.saturating_add(&b), a.saturating_sub(&b), a.saturating_mul(&b)));
+            black_box((a.saturating_div(&b), a.saturating_pow(p)));
+            black_box((a.overflowing_add(&b), a.overflowing_sub(&b), a.overflowing_mul(&b)));
+            black_box((a.overflowing_div(&b), a.overflowing_pow(p)));
+            black_box((a.add_exact(&b), a.sub_exact(&b), a.mul_exact(&b), a.div_exact(&b)));
+            black_box((a.mul_round(&b, m, mode), a.checked_mul_round(&b, m, mode)));
+            black_box((a.div_round(&b, m, mode), a.checked_div_round(&b, m, mode)));
+            black_box((a.div_min_scale(&b, m, mode), a.checked_div_min_scale(&b, m, mode)));
+            black_box((a.divmod(&b), a.checked_divmod(&b), a.divmod_i128(&b)));
+            black_box((a.div_floor(&b), a.checked_div_floor(&b), a.div_floor_i128(&b)));
+            black_box((a.mod_floor(&b), a.checked_mod_floor(&b)));
+            black_box((a.div_euclid(&b), a.checked_div_euclid(&b), a.div_euclid_i128(&b)));
+            black_box((a.rem_euclid(&b), a.checked_rem_euclid(&b)));
+            black_box((a.abs(), a.signum(), a.min(b), a.max(b), a.clamp(b, c)));
+            black_box([a, b, c].iter().sum::<$T>());
+            black_box([a, b, c].into_iter().sum::<$T>());
+            black_box([a, b, c].iter().product::<$T>());
+            black_box([a, b, c].into_iter().product::<$T>());
+
+            // powers and roots
+            black_box((a.pow(p), a.checked_pow(p), a.try_pow(p)));
+            black_box((a.powi(k), a.checked_powi(k), a.try_powi(k)));
+            black_box((a.sqrt(), a.checked_sqrt(), a.try_sqrt()));
+            black_box((a.sqrt_round(m, mode), a.checked_sqrt_round(m, mode)));
+            black_box((a.cbrt(), a.nth_root(n), a.checked_nth_root(n), a.try_nth_root(n)));
+
+            // number theory
+            black_box((a.gcd(&b), a.lcm(&b), a.checked_lcm(&b), a.try_lcm(&b)));
+
+            // rounding and scale
+            black_box((a.round(m, mode), a.floor(), a.ceil(), a.trunc(), a.fract()));
+            black_box((a.round_sig(m, mode), a.round_with_residual(m, mode)));
+            black_box((a.round_to_increment(&b, mode), a.checked_round_to_increment(&b, mode)));
+            black_box(a.round_stochastic(m, &mut Rng(k as u64)));
+            black_box((a.normalize(), a.rescale(m, mode), a.checked_rescale(m, mode)));
+            black_box(a.with_scale(m));
+            black_box((a.ulp(), a.next_up(), a.next_down()));
+
+            // comparison and hashing
+            black_box((a == b, a < b, a.cmp(&b), a.partial_cmp(&b)));
+            core::hash::Hash::hash(&a, &mut Hasher(0));
+
+            // context
+            let mut ctx = Context::new(m, mode).with_traps(Signals::default().with(Signal::Inexact));
+            black_box((ctx.add(&a, &b), ctx.sub(&a, &b), ctx.mul(&a, &b), ctx.div(&a, &b)));
+            black_box((ctx.round(&a), ctx.parse::<$T>(s), ctx.flags()));
+            ctx.clear_flags();
+            black_box(ctx);
+        }
+    };
+}
+
+/// Exercises `Hash` without pulling in a real hasher
+struct Hasher(u64);
+
+impl core::hash::Hasher for Hasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 = black_box(self.0.rotate_left(5) ^ b as u64);
+        }
+    }
+}
+
+/// Checked construction and accessors (AncDec8, AncDec32, AncDec128; AncDec has `pub` fields)
+macro_rules! check_fields {
+    ($name:ident, $T:ty, $I:ty) => {
+        #[no_mangle]
+        pub extern "C" fn $name(a: &$T, k: i64) {
+            let m = k as u8;
+            black_box(<$T>::new(k as $I, k as $I, m, k < 0));
+            black_box(<$T>::try_new(k as $I, k as $I, m, k < 0));
+            black_box((a.int(), a.frac(), a.scale(), a.is_neg()));
+        }
+    };
+}
+
+/// Trig (AncDec32, AncDec, AncDec128)
+macro_rules! check_trig {
+    ($name:ident, $T:ty) => {
+        #[no_mangle]
+        pub extern "C" fn $name(a: &$T, b: &$T) {
+            let (a, b) = (*a, *b);
+            black_box((a.sin(), a.cos(), a.tan(), a.checked_tan(), a.try_tan()));
+            black_box((a.asin(), a.checked_asin(), a.try_asin()));
+            black_box((a.acos(), a.checked_acos(), a.try_acos()));
+            black_box((a.atan(), a.atan2(&b)));
+            black_box((a.sinh(), a.checked_sinh(), a.try_sinh()));
+            black_box((a.cosh(), a.checked_cosh(), a.try_cosh(), a.tanh()));
+        }
+    };
+}
+
+/// Logarithms, powd, the error function and the fallible fraction / integer conversions
+/// (AncDec, AncDec128)
+macro_rules! check_wide {
+    ($name:ident, $T:ty) => {
+        #[no_mangle]
+        pub extern "C" fn $name(a: &$T, b: &$T, c: &$T, k: i64) {
+            let (a, b, c) = (*a, *b, *c);
+            let mode = black_box(RoundMode::HalfEven);
+            black_box((a.exp(), a.checked_exp(), a.try_exp()));
+            black_box((a.ln(), a.checked_ln(), a.try_ln()));
+            black_box((a.log10(), a.checked_log10(), a.try_log10()));
+            black_box((a.log2(), a.checked_log2(), a.try_log2()));
+            black_box((a.log(&b), a.checked_log(&b), a.try_log(&b)));
+            black_box((a.powd(&b), a.checked_powd(&b), a.try_powd(&b)));
+            black_box((a.erf(), a.erfc(), a.norm_cdf()));
+            black_box((a.norm_inv_cdf(), a.checked_norm_inv_cdf(), a.try_norm_inv_cdf()));
+            black_box(a.mul_div(&b, &c, mode));
+            black_box((a.try_to_i64(), a.try_mantissa_exponent()));
+            black_box((a.try_to_fraction(), a.try_approximate_fraction(k as u128)));
+        }
+    };
+}
+
+check_common!(check_ancdec8, AncDec8);
+check_common!(check_ancdec32, AncDec32);
+check_common!(check_ancdec, AncDec);
+check_common!(check_ancdec128, AncDec128);
+check_fields!(check_fields_ancdec8, AncDec8, u8);
+check_fields!(check_fields_ancdec32, AncDec32, u32);
+check_fields!(check_fields_ancdec128, AncDec128, u128);
+check_trig!(check_trig_ancdec32, AncDec32);
+check_trig!(check_trig_ancdec, AncDec);
+check_trig!(check_trig_ancdec128, AncDec128);
+check_wide!(check_wide_ancdec, AncDec);
+check_wide!(check_wide_ancdec128, AncDec128);
+
+/// Type-specific constructors and conversions
+#[no_mangle]
+pub extern "C" fn check_conversions(k: i64, a8: &AncDec8, a32: &AncDec32, a64: &AncDec, a128: &AncDec128) {
+    black_box((AncDec8::from(k as i8), AncDec8::from(k as u8)));
+    black_box((AncDec32::from(k as i32), AncDec32::from(k as u32)));
+    black_box((AncDec::from(k), AncDec::from(k as u64), AncDec::from(k as i128), AncDec::from(k as u128)));
+    black_box((AncDec128::from(k as i128), AncDec128::from(k as u128)));
+    black_box((*a8 + 3u8, *a32 * 3u32, *a64 / k, *a128 - k as u128, k as i128 * *a128));
+    black_box((AncDec32::from(*a8), AncDec::from(*a8), AncDec128::from(*a8)));
+    black_box((AncDec::from(*a32), AncDec128::from(*a32), AncDec128::from(*a64)));
+    black_box((*a8 + *a32, *a32 * *a64, *a64 / *a128, *a128 - *a8));
+    black_box((a128.try_to_i128(), AncDec128::factorial(k as u32), AncDec128::binomial(k as u32, 7)));
+    black_box((AncDec128::checked_factorial(k as u32), AncDec128::try_factorial(k as u32)));
+    black_box((AncDec128::checked_binomial(k as u32, 7), AncDec128::try_binomial(k as u32, 7)));
+}
+
+/// `RoundMode`, `Signal` and the error types
+///
+/// # Safety
+/// `s` must point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn check_shared(s: *const u8, len: usize) {
+    let s = input(s, len);
+    match RoundMode::from_str(s) {
+        Ok(mode) => format(&mode),
+        Err(e) => format(&e),
+    }
+    format(&black_box(Signal::Rounded));
+    format(&black_box(ArithmeticError::Overflow));
+    if let Err(e) = AncDec::parse_exact(s) {
+        format(&e);
+    }
+    let mut signals = Signals::from(Signal::Clamped) | Signal::Inexact;
+    signals.insert(black_box(Signal::Overflow));
+    signals.remove(black_box(Signal::Inexact));
+    signals |= Signals::default();
+    black_box((signals.contains(Signal::Clamped), signals.is_empty(), signals & signals));
+}